@@ -0,0 +1,51 @@
+use crate::errors::ErrorCode;
+use crate::events::ProtocolFeeCharged;
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, TransferChecked};
+
+/// Tokens the employer pays into a stream: creation deposits, top-ups,
+/// employer-funded bonuses and milestone escrow.
+pub struct EmployerDeposit<'a, 'info> {
+    pub mint: &'a Account<'info, Mint>,
+    pub from: &'a Account<'info, TokenAccount>,
+    pub authority: &'a Signer<'info>,
+    pub token_program: &'a Program<'info, Token>,
+}
+
+impl<'info> EmployerDeposit<'_, 'info> {
+    pub fn transfer(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        let cpi_accounts = TransferChecked {
+            mint: self.mint.to_account_info(),
+            from: self.from.to_account_info(),
+            to,
+            authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token::transfer_checked(cpi_ctx, amount, self.mint.decimals)
+    }
+}
+
+/// Sends the protocol fee on a deposit of `amount` to the mint's fee vault and
+/// returns it. The fee comes out of the deposit and never reaches the stream
+/// vault, so callers credit the stream with `amount` less the fee.
+pub fn charge_deposit_fee<'info>(
+    config: &ProgramConfig,
+    fee_vault: Option<&Account<'info, TokenAccount>>,
+    deposit: &EmployerDeposit<'_, 'info>,
+    stream: Pubkey,
+    amount: u64,
+) -> Result<u64> {
+    let protocol_fee = config.fee_schedule.deposit_fee(amount)?;
+    if protocol_fee > 0 {
+        let fee_vault = fee_vault.ok_or(ErrorCode::MissingFeeVault)?;
+        deposit.transfer(fee_vault.to_account_info(), protocol_fee)?;
+
+        emit!(ProtocolFeeCharged {
+            stream,
+            mint: deposit.mint.key(),
+            amount: protocol_fee,
+        });
+    }
+    Ok(protocol_fee)
+}
@@ -0,0 +1,223 @@
+use crate::errors::ErrorCode;
+use crate::instruction;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::Discriminator;
+
+/// Employer-side instructions that a PDA employer (such as an organization)
+/// may sign for through a cross-program invocation back into this program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmployerInstruction {
+    CreateStream,
+    TopUpStream,
+    UpdateInactivityThreshold,
+    AnnounceEmergencyWithdraw,
+    ExecuteEmergencyWithdraw,
+    CloseStream,
+    ProposeEmployerTransfer,
+    AcceptEmployerTransfer,
+    InitiateEmployeeRecovery,
+    CompleteEmployeeRecovery,
+    CancelEmployeeRecovery,
+    SetWithholdingRule,
+    SetDeductions,
+    SetGarnishmentIssuer,
+    SetAdvanceLimit,
+    SetAccrualMode,
+    SetTimesheetApprover,
+    SubmitTimesheet,
+    SetMaxShiftLength,
+    VoidShift,
+    SetWeeklySchedule,
+    SetPayMultipliers,
+    SetStreamKind,
+    AwardBonus,
+    FundMilestone,
+    ApproveMilestone,
+    RejectMilestone,
+    CancelMilestone,
+    ApproveExpenseClaim,
+    RejectExpenseClaim,
+    WithdrawEmployerTreasury,
+}
+
+impl EmployerInstruction {
+    pub fn from_instruction_data(data: &[u8]) -> Result<Self> {
+        let known_instructions: [(&[u8], Self); 31] = [
+            (instruction::CreateStream::DISCRIMINATOR, Self::CreateStream),
+            (instruction::TopUpStream::DISCRIMINATOR, Self::TopUpStream),
+            (
+                instruction::UpdateInactivityThreshold::DISCRIMINATOR,
+                Self::UpdateInactivityThreshold,
+            ),
+            (
+                instruction::AnnounceEmergencyWithdraw::DISCRIMINATOR,
+                Self::AnnounceEmergencyWithdraw,
+            ),
+            (
+                instruction::ExecuteEmergencyWithdraw::DISCRIMINATOR,
+                Self::ExecuteEmergencyWithdraw,
+            ),
+            (instruction::CloseStream::DISCRIMINATOR, Self::CloseStream),
+            (
+                instruction::ProposeEmployerTransfer::DISCRIMINATOR,
+                Self::ProposeEmployerTransfer,
+            ),
+            (
+                instruction::AcceptEmployerTransfer::DISCRIMINATOR,
+                Self::AcceptEmployerTransfer,
+            ),
+            (
+                instruction::InitiateEmployeeRecovery::DISCRIMINATOR,
+                Self::InitiateEmployeeRecovery,
+            ),
+            (
+                instruction::CompleteEmployeeRecovery::DISCRIMINATOR,
+                Self::CompleteEmployeeRecovery,
+            ),
+            (
+                instruction::CancelEmployeeRecovery::DISCRIMINATOR,
+                Self::CancelEmployeeRecovery,
+            ),
+            (
+                instruction::SetWithholdingRule::DISCRIMINATOR,
+                Self::SetWithholdingRule,
+            ),
+            (
+                instruction::SetDeductions::DISCRIMINATOR,
+                Self::SetDeductions,
+            ),
+            (
+                instruction::SetGarnishmentIssuer::DISCRIMINATOR,
+                Self::SetGarnishmentIssuer,
+            ),
+            (
+                instruction::SetAdvanceLimit::DISCRIMINATOR,
+                Self::SetAdvanceLimit,
+            ),
+            (
+                instruction::SetAccrualMode::DISCRIMINATOR,
+                Self::SetAccrualMode,
+            ),
+            (
+                instruction::SetTimesheetApprover::DISCRIMINATOR,
+                Self::SetTimesheetApprover,
+            ),
+            (
+                instruction::SubmitTimesheet::DISCRIMINATOR,
+                Self::SubmitTimesheet,
+            ),
+            (
+                instruction::SetMaxShiftLength::DISCRIMINATOR,
+                Self::SetMaxShiftLength,
+            ),
+            (instruction::VoidShift::DISCRIMINATOR, Self::VoidShift),
+            (
+                instruction::SetWeeklySchedule::DISCRIMINATOR,
+                Self::SetWeeklySchedule,
+            ),
+            (
+                instruction::SetPayMultipliers::DISCRIMINATOR,
+                Self::SetPayMultipliers,
+            ),
+            (
+                instruction::SetStreamKind::DISCRIMINATOR,
+                Self::SetStreamKind,
+            ),
+            (instruction::AwardBonus::DISCRIMINATOR, Self::AwardBonus),
+            (
+                instruction::FundMilestone::DISCRIMINATOR,
+                Self::FundMilestone,
+            ),
+            (
+                instruction::ApproveMilestone::DISCRIMINATOR,
+                Self::ApproveMilestone,
+            ),
+            (
+                instruction::RejectMilestone::DISCRIMINATOR,
+                Self::RejectMilestone,
+            ),
+            (
+                instruction::CancelMilestone::DISCRIMINATOR,
+                Self::CancelMilestone,
+            ),
+            (
+                instruction::ApproveExpenseClaim::DISCRIMINATOR,
+                Self::ApproveExpenseClaim,
+            ),
+            (
+                instruction::RejectExpenseClaim::DISCRIMINATOR,
+                Self::RejectExpenseClaim,
+            ),
+            (
+                instruction::WithdrawEmployerTreasury::DISCRIMINATOR,
+                Self::WithdrawEmployerTreasury,
+            ),
+        ];
+
+        known_instructions
+            .into_iter()
+            .find(|(discriminator, _)| data.starts_with(discriminator))
+            .map(|(_, employer_instruction)| employer_instruction)
+            .ok_or(ErrorCode::UnsupportedEmployerInstruction.into())
+    }
+}
+
+/// Re-invokes this program with `employer_authority` signing through its PDA
+/// seeds. Every other signer or writable flag is forwarded unchanged from the
+/// outer transaction, so the inner instruction enforces its own constraints.
+pub fn invoke_as_employer<'info>(
+    cascade_program: &AccountInfo<'info>,
+    employer_authority: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+    data: Vec<u8>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let account_metas = accounts
+        .iter()
+        .map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_signer: account.is_signer || account.key() == *employer_authority,
+            is_writable: account.is_writable,
+        })
+        .collect();
+    let employer_instruction = Instruction {
+        program_id: crate::ID,
+        accounts: account_metas,
+        data,
+    };
+
+    let mut account_infos = accounts.to_vec();
+    account_infos.push(cascade_program.clone());
+    invoke_signed(&employer_instruction, &account_infos, signer_seeds)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EmployerInstruction;
+    use crate::instruction;
+    use anchor_lang::InstructionData;
+
+    #[test]
+    fn recognizes_employer_instructions_by_discriminator() {
+        let data = instruction::TopUpStream {
+            additional_amount: 5,
+        }
+        .data();
+
+        assert_eq!(
+            EmployerInstruction::from_instruction_data(&data).expect("top up is supported"),
+            EmployerInstruction::TopUpStream
+        );
+    }
+
+    #[test]
+    fn rejects_non_employer_instructions() {
+        let data = instruction::Withdraw { amount: 5 }.data();
+
+        assert!(EmployerInstruction::from_instruction_data(&data).is_err());
+        assert!(EmployerInstruction::from_instruction_data(&[]).is_err());
+    }
+}
@@ -40,4 +40,195 @@ pub enum ErrorCode {
 
     #[msg("Vault balance is lower than stream accounting expects")]
     VaultBalanceInvariantViolated,
+
+    #[msg("Only the config admin can perform this action")]
+    UnauthorizedAdmin,
+
+    #[msg("Mint is not on the program allowlist")]
+    MintNotAllowed,
+
+    #[msg("Mint is already on the program allowlist")]
+    MintAlreadyAllowed,
+
+    #[msg("Mint allowlist is full")]
+    MintAllowlistFull,
+
+    #[msg("Only the config guardian can perform this action")]
+    UnauthorizedGuardian,
+
+    #[msg("This operation is paused by the guardian")]
+    OperationPaused,
+
+    #[msg("Unknown pause flags")]
+    InvalidPauseFlags,
+
+    #[msg("Inactivity threshold is outside the allowed range")]
+    InvalidInactivityThreshold,
+
+    #[msg("An emergency withdrawal has already been announced")]
+    EmergencyWithdrawAlreadyAnnounced,
+
+    #[msg("No emergency withdrawal has been announced")]
+    EmergencyWithdrawNotAnnounced,
+
+    #[msg("Emergency withdrawal notice period has not elapsed yet")]
+    EmergencyWithdrawNoticeActive,
+
+    #[msg("Member lacks the organization role required for this action")]
+    MissingOrganizationRole,
+
+    #[msg("Unknown organization role flags")]
+    InvalidOrganizationRoles,
+
+    #[msg("An organization must keep at least one admin")]
+    LastOrganizationAdmin,
+
+    #[msg("Instruction cannot be executed on behalf of an employer")]
+    UnsupportedEmployerInstruction,
+
+    #[msg("Multisig signers must be unique and within the supported count")]
+    InvalidMultisigSigners,
+
+    #[msg("Multisig threshold must be between one and the number of signers")]
+    InvalidMultisigThreshold,
+
+    #[msg("Signer is not a member of this multisig")]
+    UnauthorizedMultisigSigner,
+
+    #[msg("Proposal instruction data or account list is too large")]
+    ProposalTooLarge,
+
+    #[msg("Signer has already approved this proposal")]
+    ProposalAlreadyApproved,
+
+    #[msg("Proposal has not reached the approval threshold")]
+    ProposalNotApproved,
+
+    #[msg("Provided accounts do not match the approved proposal")]
+    ProposalAccountsMismatch,
+
+    #[msg("Signer is not the pending employer for this stream")]
+    UnauthorizedPendingEmployer,
+
+    #[msg("An employee recovery is already pending")]
+    EmployeeRecoveryAlreadyPending,
+
+    #[msg("No employee recovery is pending")]
+    EmployeeRecoveryNotPending,
+
+    #[msg("Employee recovery time lock has not elapsed yet")]
+    EmployeeRecoveryTimeLockActive,
+
+    #[msg("New employee must differ from the current employee")]
+    InvalidNewEmployee,
+
+    #[msg("Session key expiry must be in the future and within the maximum duration")]
+    InvalidSessionKeyExpiry,
+
+    #[msg("Stream is waiting for the employee to accept it")]
+    StreamAwaitingAcceptance,
+
+    #[msg("Stream does not require acceptance")]
+    StreamAlreadyAccepted,
+
+    #[msg("Payout splits must be unique, non-zero and total at most 10000 basis points")]
+    InvalidPayoutSplits,
+
+    #[msg("A payout destination token account was not provided")]
+    MissingPayoutAccount,
+
+    #[msg("Withholding rate must be between 1 and 5000 basis points")]
+    InvalidWithholdingRate,
+
+    #[msg("Deductions must be unique, non-zero, within the hourly rate and match at most 100%")]
+    InvalidDeductions,
+
+    #[msg("Signer is not the stream's garnishment issuer")]
+    UnauthorizedGarnishmentIssuer,
+
+    #[msg(
+        "Garnishment rate must be between 1 and 2500 basis points and the ordered amount non-zero"
+    )]
+    InvalidGarnishmentOrder,
+
+    #[msg("A garnishment order is already active on this stream")]
+    GarnishmentAlreadyActive,
+
+    #[msg("The stream's active garnishment order was not provided")]
+    MissingGarnishmentOrder,
+
+    #[msg("Garnishment order is not active")]
+    GarnishmentOrderInactive,
+
+    #[msg("Protocol fees must not exceed 500 basis points")]
+    InvalidFeeSchedule,
+
+    #[msg("The fee vault for this mint was not provided")]
+    MissingFeeVault,
+
+    #[msg("Signer may not submit timesheets for this stream")]
+    UnauthorizedTimesheetSubmitter,
+
+    #[msg("Stream does not accrue from timesheets")]
+    NotTimesheetStream,
+
+    #[msg("Timesheet period must be in the past, follow the previous period and fit the approved minutes")]
+    InvalidTimesheetPeriod,
+
+    #[msg("Stream does not accrue from clock-ins")]
+    NotClockInStream,
+
+    #[msg("Employee is clocked in")]
+    ShiftInProgress,
+
+    #[msg("Employee is not clocked in")]
+    NotClockedIn,
+
+    #[msg("Maximum shift length must be between 1 and 24 hours")]
+    InvalidMaxShiftLength,
+
+    #[msg("Shift cannot be voided")]
+    ShiftNotVoidable,
+
+    #[msg("Schedule windows must end after they start within the day and the UTC offset be at most 14 hours")]
+    InvalidWeeklySchedule,
+
+    #[msg("Pay multipliers must be between 1x and 3x and the night window within the day")]
+    InvalidPayMultipliers,
+
+    #[msg("Salary must pay a non-zero amount per period")]
+    InvalidSalarySchedule,
+
+    #[msg("Salaried streams accrue on the wall clock only")]
+    SalariedAccrualMode,
+
+    #[msg("The employer token account was not provided")]
+    MissingEmployerTokenAccount,
+
+    #[msg("Bonus exceeds the vault funding the employee has not yet earned")]
+    InsufficientVaultSurplus,
+
+    #[msg("Milestone is not in the required state")]
+    InvalidMilestoneStatus,
+
+    #[msg("Milestone review window has lapsed")]
+    MilestoneReviewLapsed,
+
+    #[msg("Expense claims must be for a non-zero amount")]
+    InvalidExpenseClaim,
+
+    #[msg("Expense claim has already been reviewed")]
+    ExpenseClaimNotPending,
+
+    #[msg("Signer is not the pending config admin")]
+    UnauthorizedPendingAdmin,
+
+    #[msg("Shift is still open or within its review window")]
+    ShiftNotFinal,
+
+    #[msg("Funded milestones must be approved or cancelled first")]
+    MilestonesOutstanding,
+
+    #[msg("Per-hour deductions are only available on hourly streams")]
+    HourlyDeductionOnSalariedStream,
 }
@@ -0,0 +1,465 @@
+use crate::state::{
+    AccrualMode, BonusSource, Deduction, ExpensePaymentSource, FeeSchedule, PayMultipliers,
+    StreamKind, WeeklySchedule,
+};
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct PauseStateChanged {
+    pub guardian: Pubkey,
+    pub previous_paused_operations: u8,
+    pub paused_operations: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InactivityThresholdUpdated {
+    pub stream: Pubkey,
+    pub previous_inactivity_threshold_seconds: i64,
+    pub inactivity_threshold_seconds: i64,
+}
+
+#[event]
+pub struct EmergencyWithdrawAnnounced {
+    pub stream: Pubkey,
+    pub announced_at: i64,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct EmergencyWithdrawCancelled {
+    pub stream: Pubkey,
+    pub announced_at: i64,
+    pub cancelled_at: i64,
+}
+
+#[event]
+pub struct EmergencyWithdrawExecuted {
+    pub stream: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrganizationMemberUpdated {
+    pub organization: Pubkey,
+    pub wallet: Pubkey,
+    pub roles: u8,
+}
+
+#[event]
+pub struct MultisigProposalCreated {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct MultisigProposalApproved {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub approver: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct MultisigProposalExecuted {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+}
+
+#[event]
+pub struct EmployerTransferProposed {
+    pub stream: Pubkey,
+    pub employer: Pubkey,
+    pub pending_employer: Pubkey,
+}
+
+#[event]
+pub struct EmployerTransferAccepted {
+    pub stream: Pubkey,
+    pub previous_employer: Pubkey,
+    pub employer: Pubkey,
+}
+
+#[event]
+pub struct EmployeeRotated {
+    pub stream: Pubkey,
+    pub previous_employee: Pubkey,
+    pub employee: Pubkey,
+}
+
+#[event]
+pub struct EmployeeRecoveryInitiated {
+    pub stream: Pubkey,
+    pub employee: Pubkey,
+    pub new_employee: Pubkey,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct EmployeeRecoveryCancelled {
+    pub stream: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub new_employee: Pubkey,
+}
+
+#[event]
+pub struct SessionKeyRegistered {
+    pub stream: Pubkey,
+    pub session_key: Pubkey,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct SessionKeyRevoked {
+    pub stream: Pubkey,
+    pub session_key: Pubkey,
+}
+
+#[event]
+pub struct StreamAccepted {
+    pub stream: Pubkey,
+    pub employee: Pubkey,
+    pub accrual_started_at: i64,
+}
+
+#[event]
+pub struct PendingStreamCancelled {
+    pub stream: Pubkey,
+    pub refunded_amount: u64,
+}
+
+#[event]
+pub struct PayoutDestinationUpdated {
+    pub stream: Pubkey,
+    pub token_account: Pubkey,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct PayoutSplitsUpdated {
+    pub stream: Pubkey,
+    pub split_count: u8,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct WithdrawalDistributed {
+    pub stream: Pubkey,
+    pub amount: u64,
+    pub protocol_fee: u64,
+    pub withheld_amount: u64,
+    pub garnished_amount: u64,
+    pub deducted_amount: u64,
+    pub employer_match_amount: u64,
+    pub split_amount: u64,
+    pub employee_amount: u64,
+    /// Premium multipliers in force, `10_000` (1x) when none are configured.
+    pub overtime_multiplier_bps: u16,
+    pub night_multiplier_bps: u16,
+    pub total_premium_earned: u64,
+}
+
+#[event]
+pub struct WithholdingRuleUpdated {
+    pub stream: Pubkey,
+    pub rate_bps: u16,
+    pub destination: Option<Pubkey>,
+}
+
+#[event]
+pub struct DeductionsUpdated {
+    pub stream: Pubkey,
+    pub deductions: Vec<Deduction>,
+}
+
+#[event]
+pub struct DeductionPaid {
+    pub stream: Pubkey,
+    pub destination: Pubkey,
+    pub deducted_amount: u64,
+    pub employer_match_amount: u64,
+}
+
+#[event]
+pub struct GarnishmentIssuerUpdated {
+    pub stream: Pubkey,
+    pub issuer: Option<Pubkey>,
+}
+
+#[event]
+pub struct GarnishmentOrderIssued {
+    pub stream: Pubkey,
+    pub garnishment_order: Pubkey,
+    pub issuer: Pubkey,
+    pub beneficiary: Pubkey,
+    pub rate_bps: u16,
+    pub ordered_amount: u64,
+}
+
+#[event]
+pub struct GarnishmentOrderClosed {
+    pub stream: Pubkey,
+    pub garnishment_order: Pubkey,
+    pub paid_amount: u64,
+    pub satisfied: bool,
+}
+
+#[event]
+pub struct FeeScheduleUpdated {
+    pub fee_schedule: FeeSchedule,
+}
+
+#[event]
+pub struct ProtocolFeeCharged {
+    pub stream: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ProtocolFeesCollected {
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct AdvanceLimitUpdated {
+    pub stream: Pubkey,
+    pub advance_limit: u64,
+}
+
+#[event]
+pub struct AdvanceDrawn {
+    pub stream: Pubkey,
+    pub amount: u64,
+    pub outstanding_advance: u64,
+}
+
+#[event]
+pub struct AdvanceRepaid {
+    pub stream: Pubkey,
+    pub amount: u64,
+    pub outstanding_advance: u64,
+}
+
+#[event]
+pub struct AdvanceWrittenOff {
+    pub stream: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct AccrualModeUpdated {
+    pub stream: Pubkey,
+    pub accrual_mode: AccrualMode,
+    pub accrued_amount: u64,
+}
+
+#[event]
+pub struct TimesheetApproverUpdated {
+    pub stream: Pubkey,
+    pub approver: Option<Pubkey>,
+}
+
+#[event]
+pub struct TimesheetSubmitted {
+    pub stream: Pubkey,
+    pub timesheet: Pubkey,
+    pub period_start: i64,
+    pub period_end: i64,
+    pub approved_minutes: u32,
+    pub earned_amount: u64,
+}
+
+#[event]
+pub struct MaxShiftLengthUpdated {
+    pub stream: Pubkey,
+    pub max_shift_seconds: i64,
+}
+
+#[event]
+pub struct ClockedIn {
+    pub stream: Pubkey,
+    pub shift: Pubkey,
+    pub clocked_in_at: i64,
+}
+
+#[event]
+pub struct ClockedOut {
+    pub stream: Pubkey,
+    pub shift: Pubkey,
+    pub clocked_out_at: i64,
+    pub duration_seconds: u64,
+    pub overtime_seconds: u64,
+    pub night_seconds: u64,
+    pub premium_amount: u64,
+    pub auto_closed: bool,
+}
+
+#[event]
+pub struct ShiftVoided {
+    pub stream: Pubkey,
+    pub shift: Pubkey,
+    pub duration_seconds: u64,
+}
+
+#[event]
+pub struct WeeklyScheduleUpdated {
+    pub stream: Pubkey,
+    pub weekly_schedule: WeeklySchedule,
+}
+
+#[event]
+pub struct PayMultipliersUpdated {
+    pub stream: Pubkey,
+    pub pay_multipliers: Option<PayMultipliers>,
+}
+
+#[event]
+pub struct StreamKindUpdated {
+    pub stream: Pubkey,
+    pub kind: StreamKind,
+    /// Earnings banked under the previous kind.
+    pub accrued_amount: u64,
+    /// Start of the first pay period under the new kind.
+    pub accrual_started_at: i64,
+}
+
+#[event]
+pub struct BonusAwarded {
+    pub stream: Pubkey,
+    pub source: BonusSource,
+    /// Amount credited to the employee, after any deposit fee.
+    pub amount: u64,
+    pub protocol_fee: u64,
+    pub total_bonus_awarded: u64,
+}
+
+#[event]
+pub struct MilestoneFunded {
+    pub stream: Pubkey,
+    pub milestone: Pubkey,
+    pub index: u32,
+    pub amount: u64,
+    pub description_hash: [u8; 32],
+}
+
+#[event]
+pub struct MilestoneSubmitted {
+    pub stream: Pubkey,
+    pub milestone: Pubkey,
+    pub submitted_at: i64,
+}
+
+#[event]
+pub struct MilestoneApproved {
+    pub stream: Pubkey,
+    pub milestone: Pubkey,
+    pub amount: u64,
+    pub auto_approved: bool,
+}
+
+#[event]
+pub struct MilestoneRejected {
+    pub stream: Pubkey,
+    pub milestone: Pubkey,
+    pub rejected_at: i64,
+}
+
+#[event]
+pub struct ExpenseClaimSubmitted {
+    pub stream: Pubkey,
+    pub expense_claim: Pubkey,
+    pub index: u32,
+    pub amount: u64,
+    pub receipt_hash: [u8; 32],
+}
+
+#[event]
+pub struct ExpenseClaimApproved {
+    pub stream: Pubkey,
+    pub expense_claim: Pubkey,
+    pub amount: u64,
+    pub source: ExpensePaymentSource,
+    pub destination: Pubkey,
+}
+
+#[event]
+pub struct ExpenseClaimRejected {
+    pub stream: Pubkey,
+    pub expense_claim: Pubkey,
+    pub rejected_at: i64,
+}
+
+#[event]
+pub struct ConfigAdminProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct ConfigAdminUpdated {
+    pub previous_admin: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct ConfigGuardianUpdated {
+    pub previous_guardian: Pubkey,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct MultisigSignersUpdated {
+    pub multisig: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct MultisigProposalCancelled {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub cancelled_by: Pubkey,
+}
+
+#[event]
+pub struct ShiftRecordClosed {
+    pub stream: Pubkey,
+    pub shift: Pubkey,
+    pub payer: Pubkey,
+}
+
+#[event]
+pub struct MilestoneCancelled {
+    pub stream: Pubkey,
+    pub milestone: Pubkey,
+    pub refunded_amount: u64,
+}
+
+#[event]
+pub struct AllowedMintAdded {
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct AllowedMintRemoved {
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct EmployerTreasuryWithdrawn {
+    pub employer: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
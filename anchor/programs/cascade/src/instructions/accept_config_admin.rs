@@ -0,0 +1,31 @@
+use crate::events::ConfigAdminUpdated;
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+
+/// Completes an admin handover. The nominee must sign, so a mistyped key can
+/// never take over the config.
+pub fn accept_config_admin(ctx: Context<AcceptConfigAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let new_admin = ctx.accounts.new_admin.key();
+
+    let previous_admin = config.accept_admin(new_admin)?;
+
+    emit!(ConfigAdminUpdated {
+        previous_admin,
+        admin: new_admin,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptConfigAdmin<'info> {
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
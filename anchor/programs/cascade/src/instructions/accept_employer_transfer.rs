@@ -0,0 +1,32 @@
+use crate::events::EmployerTransferAccepted;
+use crate::state::PaymentStream;
+use anchor_lang::prelude::*;
+
+/// Completes an employer transfer. Only the authority changes: the stream PDA
+/// keeps its original seeds, so the vault and its funds are untouched.
+pub fn accept_employer_transfer(ctx: Context<AcceptEmployerTransfer>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let new_employer = ctx.accounts.new_employer.key();
+
+    let previous_employer = stream.accept_employer(new_employer)?;
+
+    emit!(EmployerTransferAccepted {
+        stream: stream.key(),
+        previous_employer,
+        employer: new_employer,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptEmployerTransfer<'info> {
+    pub new_employer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
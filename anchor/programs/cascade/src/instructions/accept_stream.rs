@@ -0,0 +1,39 @@
+use crate::errors::ErrorCode;
+use crate::events::{EmergencyWithdrawCancelled, StreamAccepted};
+use crate::state::PaymentStream;
+use anchor_lang::prelude::*;
+
+pub fn accept_stream(ctx: Context<AcceptStream>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    if let Some(announced_at) = stream.accept(clock.unix_timestamp)? {
+        emit!(EmergencyWithdrawCancelled {
+            stream: stream.key(),
+            announced_at,
+            cancelled_at: clock.unix_timestamp,
+        });
+    }
+
+    emit!(StreamAccepted {
+        stream: stream.key(),
+        employee: stream.employee,
+        accrual_started_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptStream<'info> {
+    pub employee: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employee @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
@@ -0,0 +1,32 @@
+use crate::errors::ErrorCode;
+use crate::events::AllowedMintAdded;
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+pub fn add_allowed_mint(ctx: Context<AddAllowedMint>) -> Result<()> {
+    let mint = ctx.accounts.mint.key();
+    ctx.accounts.config.allow_mint(mint)?;
+
+    emit!(AllowedMintAdded {
+        admin: ctx.accounts.admin.key(),
+        mint,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddAllowedMint<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub mint: Account<'info, token::Mint>,
+}
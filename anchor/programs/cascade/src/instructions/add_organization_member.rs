@@ -0,0 +1,70 @@
+use crate::errors::ErrorCode;
+use crate::events::OrganizationMemberUpdated;
+use crate::state::{Organization, OrganizationMember, ALL_ROLES, ROLE_ADMIN};
+use anchor_lang::prelude::*;
+
+pub fn add_organization_member(ctx: Context<AddOrganizationMember>, roles: u8) -> Result<()> {
+    let organization = &mut ctx.accounts.organization;
+    let member = &mut ctx.accounts.member;
+    let clock = Clock::get()?;
+
+    require!(
+        ctx.accounts.admin_membership.has_role(ROLE_ADMIN),
+        ErrorCode::MissingOrganizationRole
+    );
+    require!(roles & !ALL_ROLES == 0, ErrorCode::InvalidOrganizationRoles);
+
+    if roles & ROLE_ADMIN != 0 {
+        organization.admin_count = organization
+            .admin_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+    }
+
+    member.organization = organization.key();
+    member.wallet = ctx.accounts.wallet.key();
+    member.roles = roles;
+    member.added_at = clock.unix_timestamp;
+    member.bump = ctx.bumps.member;
+
+    emit!(OrganizationMemberUpdated {
+        organization: organization.key(),
+        wallet: member.wallet,
+        roles,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddOrganizationMember<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"organization", organization.creator.as_ref()],
+        bump = organization.bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        seeds = [b"member", organization.key().as_ref(), admin.key().as_ref()],
+        bump = admin_membership.bump
+    )]
+    pub admin_membership: Account<'info, OrganizationMember>,
+
+    /// CHECK: Only used as the member PDA seed and stored on the membership record.
+    pub wallet: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = OrganizationMember::DISCRIMINATOR.len() + OrganizationMember::INIT_SPACE,
+        seeds = [b"member", organization.key().as_ref(), wallet.key().as_ref()],
+        bump
+    )]
+    pub member: Account<'info, OrganizationMember>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,9 @@
+use crate::instructions::withdraw::{process_withdrawal, Withdraw};
+use anchor_lang::prelude::*;
+
+/// Draws wages ahead of accrual, up to the employer-approved `advance_limit`.
+/// Takes the same accounts as `withdraw` and goes through the same payout
+/// pipeline, so withholding, garnishment and deductions still apply.
+pub fn advance<'info>(ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>, amount: u64) -> Result<()> {
+    process_withdrawal(ctx, amount, true)
+}
@@ -0,0 +1,52 @@
+use crate::errors::ErrorCode;
+use crate::events::EmergencyWithdrawAnnounced;
+use crate::instructions::execute_emergency_withdraw::assert_employee_inactive;
+use crate::state::{PaymentStream, EMERGENCY_WITHDRAW_NOTICE_SECONDS};
+use anchor_lang::prelude::*;
+
+pub fn announce_emergency_withdraw(ctx: Context<AnnounceEmergencyWithdraw>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    // Unaccepted streams are refunded through `close_stream` instead.
+    require!(
+        !stream.awaiting_acceptance,
+        ErrorCode::StreamAwaitingAcceptance
+    );
+    require!(
+        ctx.accounts.employer.key() == stream.employer,
+        ErrorCode::UnauthorizedEmployer
+    );
+    require!(
+        stream.emergency_withdraw_announced_at.is_none(),
+        ErrorCode::EmergencyWithdrawAlreadyAnnounced
+    );
+    assert_employee_inactive(stream, clock.unix_timestamp)?;
+
+    let executable_at = clock
+        .unix_timestamp
+        .checked_add(EMERGENCY_WITHDRAW_NOTICE_SECONDS)
+        .ok_or(ErrorCode::MathOverflow)?;
+    stream.emergency_withdraw_announced_at = Some(clock.unix_timestamp);
+
+    emit!(EmergencyWithdrawAnnounced {
+        stream: stream.key(),
+        announced_at: clock.unix_timestamp,
+        executable_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AnnounceEmergencyWithdraw<'info> {
+    pub employer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
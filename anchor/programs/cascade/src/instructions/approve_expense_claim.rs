@@ -0,0 +1,145 @@
+use crate::errors::ErrorCode;
+use crate::events::ExpenseClaimApproved;
+use crate::state::{
+    ExpenseClaim, ExpensePaymentSource, PaymentStream, ProgramConfig, PAUSE_WITHDRAW,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, TransferChecked};
+
+/// Pays an expense claim straight to the employee's payout account. It is
+/// not a wage: withholding, deductions, garnishment and protocol fees do not
+/// apply, and `withdrawn_amount` is untouched.
+pub fn approve_expense_claim(
+    ctx: Context<ApproveExpenseClaim>,
+    source: ExpensePaymentSource,
+) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let expense_claim = &mut ctx.accounts.expense_claim;
+    let clock = Clock::get()?;
+
+    expense_claim.approve(source, clock.unix_timestamp)?;
+    stream.assert_accounting_invariant()?;
+
+    match stream.apply_pending_payout_destination(clock.unix_timestamp) {
+        Some(payout_destination) => require_keys_eq!(
+            ctx.accounts.employee_token_account.key(),
+            payout_destination,
+            ErrorCode::InvalidTokenAccount
+        ),
+        None => require_keys_eq!(
+            ctx.accounts.employee_token_account.owner,
+            stream.employee,
+            ErrorCode::InvalidTokenAccount
+        ),
+    }
+
+    let amount = expense_claim.amount;
+    match source {
+        ExpensePaymentSource::Vault => {
+            // Paying from the vault moves stream funds like a withdrawal does.
+            require!(stream.is_active, ErrorCode::StreamInactive);
+            ctx.accounts.config.assert_not_paused(PAUSE_WITHDRAW)?;
+            stream.pay_expense_from_vault(amount, clock.unix_timestamp)?;
+
+            let employer_key = stream.original_employer;
+            let employee_key = stream.original_employee;
+            let seeds = &[
+                b"stream",
+                employer_key.as_ref(),
+                employee_key.as_ref(),
+                &[stream.bump],
+            ];
+            let signer = &[&seeds[..]];
+            let cpi_accounts = TransferChecked {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.employee_token_account.to_account_info(),
+                authority: stream.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer);
+            token::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+        }
+        ExpensePaymentSource::Employer => {
+            let employer_token_account = ctx
+                .accounts
+                .employer_token_account
+                .as_ref()
+                .ok_or(ErrorCode::MissingEmployerTokenAccount)?;
+            let cpi_accounts = TransferChecked {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: employer_token_account.to_account_info(),
+                to: ctx.accounts.employee_token_account.to_account_info(),
+                authority: ctx.accounts.employer.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+            token::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+        }
+    }
+
+    stream.total_expenses_reimbursed = stream
+        .total_expenses_reimbursed
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    stream.assert_accounting_invariant()?;
+
+    emit!(ExpenseClaimApproved {
+        stream: stream.key(),
+        expense_claim: expense_claim.key(),
+        amount,
+        source,
+        destination: ctx.accounts.employee_token_account.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveExpenseClaim<'info> {
+    pub employer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer,
+        has_one = vault,
+        has_one = mint
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    #[account(
+        mut,
+        seeds = [b"expense", stream.key().as_ref(), expense_claim.index.to_le_bytes().as_ref()],
+        bump = expense_claim.bump,
+        has_one = stream
+    )]
+    pub expense_claim: Account<'info, ExpenseClaim>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub mint: Account<'info, token::Mint>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// The stream's payout destination if one is configured, otherwise any
+    /// token account owned by the employee.
+    #[account(
+        mut,
+        constraint = employee_token_account.mint == stream.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub employee_token_account: Account<'info, TokenAccount>,
+
+    /// Required when the claim is paid by the employer.
+    #[account(
+        mut,
+        constraint = employer_token_account.owner == employer.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = employer_token_account.mint == stream.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub employer_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,59 @@
+use crate::errors::ErrorCode;
+use crate::events::MilestoneApproved;
+use crate::state::{Milestone, MilestoneStatus, PaymentStream};
+use anchor_lang::prelude::*;
+
+/// Releases a submitted milestone into the contractor's withdrawable balance.
+/// The employer may approve at any time; once the review window has lapsed
+/// anyone may.
+pub fn approve_milestone(ctx: Context<ApproveMilestone>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let milestone = &mut ctx.accounts.milestone;
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    require!(
+        milestone.status == MilestoneStatus::Submitted,
+        ErrorCode::InvalidMilestoneStatus
+    );
+    let auto_approved = milestone.review_lapsed(clock.unix_timestamp);
+    require!(
+        ctx.accounts.authority.key() == stream.employer || auto_approved,
+        ErrorCode::UnauthorizedEmployer
+    );
+
+    stream.release_milestone(milestone.amount)?;
+    milestone.status = MilestoneStatus::Approved;
+    milestone.approved_at = Some(clock.unix_timestamp);
+    milestone.auto_approved = auto_approved;
+
+    emit!(MilestoneApproved {
+        stream: stream.key(),
+        milestone: milestone.key(),
+        amount: milestone.amount,
+        auto_approved,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveMilestone<'info> {
+    /// The employer, or anyone once the review window has lapsed.
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    #[account(
+        mut,
+        seeds = [b"milestone", stream.key().as_ref(), milestone.index.to_le_bytes().as_ref()],
+        bump = milestone.bump,
+        has_one = stream
+    )]
+    pub milestone: Account<'info, Milestone>,
+}
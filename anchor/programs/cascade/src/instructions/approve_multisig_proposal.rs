@@ -0,0 +1,40 @@
+use crate::events::MultisigProposalApproved;
+use crate::state::{Multisig, MultisigProposal};
+use anchor_lang::prelude::*;
+
+pub fn approve_multisig_proposal(ctx: Context<ApproveMultisigProposal>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    let approver = ctx.accounts.approver.key();
+
+    proposal.approve(multisig, approver)?;
+
+    emit!(MultisigProposalApproved {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        approver,
+        approvals: proposal.current_approvals(multisig) as u8,
+        threshold: multisig.threshold,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveMultisigProposal<'info> {
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [b"multisig", multisig.creator.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", multisig.key().as_ref(), proposal.index.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = multisig
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+}
@@ -0,0 +1,127 @@
+use crate::deposit::{charge_deposit_fee, EmployerDeposit};
+use crate::errors::ErrorCode;
+use crate::events::BonusAwarded;
+use crate::state::{
+    BonusSource, PaymentStream, ProgramConfig, PAUSE_TOP_UP_STREAM, PAUSE_WITHDRAW,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Credits a one-off bonus the employee can withdraw straight away. Bonuses
+/// are tracked in `total_bonus_awarded`, apart from accrued wages.
+pub fn award_bonus(ctx: Context<AwardBonus>, amount: u64, source: BonusSource) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    stream.assert_accounting_invariant()?;
+
+    let (bonus_amount, protocol_fee) = match source {
+        BonusSource::Employer => {
+            ctx.accounts.config.assert_not_paused(PAUSE_TOP_UP_STREAM)?;
+            let employer_token_account = ctx
+                .accounts
+                .employer_token_account
+                .as_ref()
+                .ok_or(ErrorCode::MissingEmployerTokenAccount)?;
+
+            // Funded like a top-up: the protocol fee comes out of the deposit.
+            let deposit = EmployerDeposit {
+                mint: &ctx.accounts.mint,
+                from: employer_token_account,
+                authority: &ctx.accounts.employer,
+                token_program: &ctx.accounts.token_program,
+            };
+            let protocol_fee = charge_deposit_fee(
+                &ctx.accounts.config,
+                ctx.accounts.fee_vault.as_ref(),
+                &deposit,
+                stream.key(),
+                amount,
+            )?;
+            let net_amount = amount
+                .checked_sub(protocol_fee)
+                .ok_or(ErrorCode::MathOverflow)?;
+            deposit.transfer(ctx.accounts.vault.to_account_info(), net_amount)?;
+
+            stream.total_deposited = stream
+                .total_deposited
+                .checked_add(net_amount)
+                .ok_or(ErrorCode::MathOverflow)?;
+            stream.total_protocol_fees = stream
+                .total_protocol_fees
+                .checked_add(protocol_fee)
+                .ok_or(ErrorCode::MathOverflow)?;
+            (net_amount, protocol_fee)
+        }
+        BonusSource::VaultSurplus => {
+            // The bonus is withdrawable at once, so it counts as a vault outflow.
+            ctx.accounts.config.assert_not_paused(PAUSE_WITHDRAW)?;
+            // Only funding the employee has not yet earned can be turned into
+            // a bonus, so earned wages are never reduced.
+            require!(
+                amount <= stream.unearned_funding(clock.unix_timestamp)?,
+                ErrorCode::InsufficientVaultSurplus
+            );
+            (amount, 0)
+        }
+    };
+
+    stream.total_bonus_awarded = stream
+        .total_bonus_awarded
+        .checked_add(bonus_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    stream.assert_accounting_invariant()?;
+
+    emit!(BonusAwarded {
+        stream: stream.key(),
+        source,
+        amount: bonus_amount,
+        protocol_fee,
+        total_bonus_awarded: stream.total_bonus_awarded,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AwardBonus<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer,
+        has_one = vault,
+        has_one = mint
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    pub mint: Account<'info, token::Mint>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// Required when the bonus is funded by the employer.
+    #[account(
+        mut,
+        constraint = employer_token_account.owner == employer.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = employer_token_account.mint == stream.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub employer_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// The mint's fee vault. Required whenever a non-zero protocol fee applies.
+    #[account(
+        mut,
+        seeds = [b"fee_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,41 @@
+use crate::errors::ErrorCode;
+use crate::events::EmployeeRecoveryCancelled;
+use crate::state::PaymentStream;
+use anchor_lang::prelude::*;
+
+/// Either party may cancel a pending recovery. The current employee cancelling
+/// is the safeguard against an employer and guardian colluding to take over
+/// the stream.
+pub fn cancel_employee_recovery(ctx: Context<CancelEmployeeRecovery>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let authority = ctx.accounts.authority.key();
+
+    require!(
+        authority == stream.employee || authority == stream.employer,
+        ErrorCode::UnauthorizedEmployee
+    );
+    let recovery = stream
+        .pending_employee_recovery
+        .take()
+        .ok_or(ErrorCode::EmployeeRecoveryNotPending)?;
+
+    emit!(EmployeeRecoveryCancelled {
+        stream: stream.key(),
+        cancelled_by: authority,
+        new_employee: recovery.new_employee,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelEmployeeRecovery<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
@@ -0,0 +1,86 @@
+use crate::errors::ErrorCode;
+use crate::events::MilestoneCancelled;
+use crate::state::{Milestone, MilestoneStatus, PaymentStream};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, TransferChecked};
+
+/// Refunds a milestone the contractor has not submitted and closes it. A
+/// submitted milestone must be rejected first, which is only possible within
+/// its review window.
+pub fn cancel_milestone(ctx: Context<CancelMilestone>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let milestone = &ctx.accounts.milestone;
+
+    require!(
+        milestone.status == MilestoneStatus::Funded,
+        ErrorCode::InvalidMilestoneStatus
+    );
+
+    stream.refund_milestone(milestone.amount)?;
+
+    let employer_key = stream.original_employer;
+    let employee_key = stream.original_employee;
+    let seeds = &[
+        b"stream",
+        employer_key.as_ref(),
+        employee_key.as_ref(),
+        &[stream.bump],
+    ];
+    let signer = &[&seeds[..]];
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.vault.to_account_info(),
+        to: ctx.accounts.employer_token_account.to_account_info(),
+        authority: stream.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer);
+    token::transfer_checked(cpi_ctx, milestone.amount, ctx.accounts.mint.decimals)?;
+
+    emit!(MilestoneCancelled {
+        stream: stream.key(),
+        milestone: milestone.key(),
+        refunded_amount: milestone.amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelMilestone<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer,
+        has_one = vault,
+        has_one = mint
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    #[account(
+        mut,
+        close = employer,
+        seeds = [b"milestone", stream.key().as_ref(), milestone.index.to_le_bytes().as_ref()],
+        bump = milestone.bump,
+        has_one = stream
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    pub mint: Account<'info, token::Mint>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = employer_token_account.owner == employer.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = employer_token_account.mint == stream.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub employer_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
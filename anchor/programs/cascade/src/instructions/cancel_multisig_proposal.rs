@@ -0,0 +1,50 @@
+use crate::errors::ErrorCode;
+use crate::events::MultisigProposalCancelled;
+use crate::state::{Multisig, MultisigProposal};
+use anchor_lang::prelude::*;
+
+/// Closes a proposal without executing it, returning its rent to the
+/// proposer.
+pub fn cancel_multisig_proposal(ctx: Context<CancelMultisigProposal>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &ctx.accounts.proposal;
+
+    require!(
+        proposal.can_be_cancelled_by(multisig, &ctx.accounts.authority.key()),
+        ErrorCode::UnauthorizedMultisigSigner
+    );
+
+    emit!(MultisigProposalCancelled {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        index: proposal.index,
+        cancelled_by: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelMultisigProposal<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"multisig", multisig.creator.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"proposal", multisig.key().as_ref(), proposal.index.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = multisig,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    /// CHECK: Receives the proposal rent; validated by `has_one` on the proposal.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
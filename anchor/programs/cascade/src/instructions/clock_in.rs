@@ -0,0 +1,80 @@
+use crate::errors::ErrorCode;
+use crate::events::{ClockedIn, EmergencyWithdrawCancelled};
+use crate::state::{AccrualMode, PaymentStream, ShiftRecord};
+use anchor_lang::prelude::*;
+
+pub fn clock_in(ctx: Context<ClockIn>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let shift = &mut ctx.accounts.shift;
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    require!(
+        !stream.awaiting_acceptance,
+        ErrorCode::StreamAwaitingAcceptance
+    );
+    require!(
+        stream.accrual_mode == AccrualMode::ClockInOut,
+        ErrorCode::NotClockInStream
+    );
+    require!(stream.clocked_in_at.is_none(), ErrorCode::ShiftInProgress);
+
+    shift.stream = stream.key();
+    shift.index = stream.shift_count;
+    shift.payer = ctx.accounts.employee.key();
+    shift.clocked_in_at = clock.unix_timestamp;
+    shift.clocked_out_at = None;
+    shift.duration_seconds = 0;
+    shift.workweek = 0;
+    shift.overtime_seconds = 0;
+    shift.night_seconds = 0;
+    shift.premium_amount = 0;
+    shift.auto_closed = false;
+    shift.bump = ctx.bumps.shift;
+
+    stream.clocked_in_at = Some(clock.unix_timestamp);
+    stream.shift_count = stream
+        .shift_count
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    if let Some(announced_at) = stream.record_employee_activity(clock.unix_timestamp) {
+        emit!(EmergencyWithdrawCancelled {
+            stream: stream.key(),
+            announced_at,
+            cancelled_at: clock.unix_timestamp,
+        });
+    }
+
+    emit!(ClockedIn {
+        stream: stream.key(),
+        shift: shift.key(),
+        clocked_in_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClockIn<'info> {
+    #[account(mut)]
+    pub employee: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employee @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    #[account(
+        init,
+        payer = employee,
+        space = ShiftRecord::DISCRIMINATOR.len() + ShiftRecord::INIT_SPACE,
+        seeds = [b"shift", stream.key().as_ref(), stream.shift_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub shift: Account<'info, ShiftRecord>,
+
+    pub system_program: Program<'info, System>,
+}
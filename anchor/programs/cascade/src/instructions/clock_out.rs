@@ -0,0 +1,74 @@
+use crate::errors::ErrorCode;
+use crate::events::ClockedOut;
+use crate::state::{PaymentStream, ShiftRecord};
+use anchor_lang::prelude::*;
+
+/// Closes the open shift. The employee may clock out at any time; once the
+/// maximum shift length has passed anyone may close a forgotten shift, which
+/// is then paid for the maximum length only.
+pub fn clock_out(ctx: Context<ClockOut>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let shift = &mut ctx.accounts.shift;
+    let clock = Clock::get()?;
+
+    let clocked_in_at = stream.clocked_in_at.ok_or(ErrorCode::NotClockedIn)?;
+    let elapsed_seconds = clock
+        .unix_timestamp
+        .checked_sub(clocked_in_at)
+        .ok_or(ErrorCode::InvalidTimestamp)?;
+    let auto_closed = elapsed_seconds > stream.max_shift_seconds;
+    require!(
+        ctx.accounts.authority.key() == stream.employee || auto_closed,
+        ErrorCode::UnauthorizedEmployee
+    );
+
+    let duration_seconds = stream.shift_duration(clocked_in_at, clock.unix_timestamp)?;
+    let premium = stream.record_closed_shift(clocked_in_at, duration_seconds)?;
+    stream.clocked_in_at = None;
+
+    shift.clocked_out_at = Some(clock.unix_timestamp);
+    shift.duration_seconds = duration_seconds;
+    shift.workweek = premium.workweek;
+    shift.overtime_seconds = premium.overtime_seconds;
+    shift.night_seconds = premium.night_seconds;
+    shift.premium_amount = premium.premium_amount;
+    shift.auto_closed = auto_closed;
+
+    emit!(ClockedOut {
+        stream: stream.key(),
+        shift: shift.key(),
+        clocked_out_at: clock.unix_timestamp,
+        duration_seconds,
+        overtime_seconds: premium.overtime_seconds,
+        night_seconds: premium.night_seconds,
+        premium_amount: premium.premium_amount,
+        auto_closed,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClockOut<'info> {
+    /// The employee, or anyone once the shift has exceeded its maximum length.
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    /// The open shift, i.e. the most recently started one.
+    #[account(
+        mut,
+        seeds = [
+            b"shift",
+            stream.key().as_ref(),
+            stream.shift_count.saturating_sub(1).to_le_bytes().as_ref()
+        ],
+        bump = shift.bump
+    )]
+    pub shift: Account<'info, ShiftRecord>,
+}
@@ -0,0 +1,41 @@
+use crate::errors::ErrorCode;
+use crate::events::ShiftRecordClosed;
+use crate::state::ShiftRecord;
+use anchor_lang::prelude::*;
+
+/// Closes the record of a final shift and returns its rent to whoever paid it.
+/// The record is only evidence for a dispute, so once the review window has
+/// lapsed anyone may close it, even after the stream itself was closed.
+pub fn close_shift_record(ctx: Context<CloseShiftRecord>) -> Result<()> {
+    let shift = &ctx.accounts.shift;
+    let clock = Clock::get()?;
+
+    require!(
+        shift.is_final(clock.unix_timestamp),
+        ErrorCode::ShiftNotFinal
+    );
+
+    emit!(ShiftRecordClosed {
+        stream: shift.stream,
+        shift: shift.key(),
+        payer: shift.payer,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseShiftRecord<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"shift", shift.stream.as_ref(), shift.index.to_le_bytes().as_ref()],
+        bump = shift.bump,
+        has_one = payer
+    )]
+    pub shift: Account<'info, ShiftRecord>,
+
+    /// CHECK: Receives the shift record rent; validated by `has_one` on the shift.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
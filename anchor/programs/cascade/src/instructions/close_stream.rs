@@ -1,11 +1,18 @@
 use crate::errors::ErrorCode;
+use crate::events::PendingStreamCancelled;
 use crate::state::PaymentStream;
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, TransferChecked};
 
 pub fn close_stream(ctx: Context<CloseStream>) -> Result<()> {
     let stream = &ctx.accounts.stream;
-    require!(!stream.is_active, ErrorCode::StreamStillActive);
+    // A stream the employee never accepted can be cancelled and refunded
+    // immediately: nothing has accrued to the employee yet.
+    require!(
+        !stream.is_active || stream.awaiting_acceptance,
+        ErrorCode::StreamStillActive
+    );
+    stream.assert_milestones_resolved()?;
     stream.assert_accounting_invariant()?;
 
     let expected_vault_balance = stream.expected_vault_balance()?;
@@ -16,8 +23,8 @@ pub fn close_stream(ctx: Context<CloseStream>) -> Result<()> {
         ErrorCode::VaultBalanceInvariantViolated
     );
 
-    let employer_key = stream.employer.key();
-    let employee_key = stream.employee.key();
+    let employer_key = stream.original_employer;
+    let employee_key = stream.original_employee;
     let seeds = &[
         b"stream",
         employer_key.as_ref(),
@@ -46,6 +53,13 @@ pub fn close_stream(ctx: Context<CloseStream>) -> Result<()> {
     let close_ctx = CpiContext::new(close_program, close_accounts).with_signer(signer);
     token::close_account(close_ctx)?;
 
+    if stream.awaiting_acceptance {
+        emit!(PendingStreamCancelled {
+            stream: stream.key(),
+            refunded_amount: vault_balance,
+        });
+    }
+
     Ok(())
 }
 
@@ -57,7 +71,7 @@ pub struct CloseStream<'info> {
     #[account(
         mut,
         close = employer,
-        seeds = [b"stream", employer.key().as_ref(), stream.employee.as_ref()],
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
         bump = stream.bump,
         has_one = employer,
         has_one = mint
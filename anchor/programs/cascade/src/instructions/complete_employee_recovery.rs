@@ -0,0 +1,79 @@
+use crate::errors::ErrorCode;
+use crate::events::{EmergencyWithdrawCancelled, EmployeeRotated};
+use crate::state::{PaymentStream, PendingEmployeeRecovery};
+use anchor_lang::prelude::*;
+
+fn assert_recovery_executable(
+    pending_employee_recovery: Option<PendingEmployeeRecovery>,
+    current_timestamp: i64,
+) -> Result<PendingEmployeeRecovery> {
+    let recovery = pending_employee_recovery.ok_or(ErrorCode::EmployeeRecoveryNotPending)?;
+    require!(
+        current_timestamp >= recovery.executable_at,
+        ErrorCode::EmployeeRecoveryTimeLockActive
+    );
+    Ok(recovery)
+}
+
+pub fn complete_employee_recovery(ctx: Context<CompleteEmployeeRecovery>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    let recovery =
+        assert_recovery_executable(stream.pending_employee_recovery, clock.unix_timestamp)?;
+
+    let previous_employee = stream.replace_employee(recovery.new_employee);
+
+    // The recovered employee starts with a fresh inactivity window.
+    if let Some(announced_at) = stream.record_employee_activity(clock.unix_timestamp) {
+        emit!(EmergencyWithdrawCancelled {
+            stream: stream.key(),
+            announced_at,
+            cancelled_at: clock.unix_timestamp,
+        });
+    }
+
+    emit!(EmployeeRotated {
+        stream: stream.key(),
+        previous_employee,
+        employee: recovery.new_employee,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CompleteEmployeeRecovery<'info> {
+    pub employer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::assert_recovery_executable;
+    use crate::state::PendingEmployeeRecovery;
+    use anchor_lang::prelude::Pubkey;
+
+    #[test]
+    fn requires_pending_recovery_past_time_lock() {
+        let recovery = PendingEmployeeRecovery {
+            new_employee: Pubkey::new_unique(),
+            executable_at: 1_000,
+        };
+
+        assert!(assert_recovery_executable(None, 2_000).is_err());
+        assert!(assert_recovery_executable(Some(recovery), 999).is_err());
+        assert_eq!(
+            assert_recovery_executable(Some(recovery), 1_000).expect("time lock elapsed"),
+            recovery
+        );
+    }
+}
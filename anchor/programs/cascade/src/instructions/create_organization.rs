@@ -0,0 +1,54 @@
+use crate::events::OrganizationMemberUpdated;
+use crate::state::{Organization, OrganizationMember, ROLE_ADMIN};
+use anchor_lang::prelude::*;
+
+pub fn create_organization(ctx: Context<CreateOrganization>) -> Result<()> {
+    let organization = &mut ctx.accounts.organization;
+    let membership = &mut ctx.accounts.membership;
+    let clock = Clock::get()?;
+
+    organization.creator = ctx.accounts.creator.key();
+    organization.admin_count = 1;
+    organization.created_at = clock.unix_timestamp;
+    organization.bump = ctx.bumps.organization;
+
+    membership.organization = organization.key();
+    membership.wallet = ctx.accounts.creator.key();
+    membership.roles = ROLE_ADMIN;
+    membership.added_at = clock.unix_timestamp;
+    membership.bump = ctx.bumps.membership;
+
+    emit!(OrganizationMemberUpdated {
+        organization: organization.key(),
+        wallet: membership.wallet,
+        roles: membership.roles,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateOrganization<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = Organization::DISCRIMINATOR.len() + Organization::INIT_SPACE,
+        seeds = [b"organization", creator.key().as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = OrganizationMember::DISCRIMINATOR.len() + OrganizationMember::INIT_SPACE,
+        seeds = [b"member", organization.key().as_ref(), creator.key().as_ref()],
+        bump
+    )]
+    pub membership: Account<'info, OrganizationMember>,
+
+    pub system_program: Program<'info, System>,
+}
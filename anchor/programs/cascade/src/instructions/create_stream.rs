@@ -1,6 +1,11 @@
-use crate::state::PaymentStream;
+use crate::deposit::{charge_deposit_fee, EmployerDeposit};
+use crate::errors::ErrorCode;
+use crate::state::{
+    AccrualMode, PaymentStream, ProgramConfig, StreamKind, WeeklySchedule,
+    DEFAULT_MAX_SHIFT_SECONDS, PAUSE_CREATE_STREAM,
+};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, TransferChecked};
+use anchor_spl::token::{self, Token, TokenAccount};
 
 const SUPPORTED_STABLECOIN_DECIMALS: u8 = 6;
 
@@ -8,54 +13,133 @@ pub fn create_stream(
     ctx: Context<CreateStream>,
     hourly_rate: u64,
     total_deposit: u64,
+    inactivity_threshold_seconds: i64,
+    require_acceptance: bool,
+    kind: StreamKind,
 ) -> Result<()> {
     let stream = &mut ctx.accounts.stream;
     let clock = Clock::get()?;
 
+    ctx.accounts.config.assert_not_paused(PAUSE_CREATE_STREAM)?;
     require!(
         ctx.accounts.mint.decimals == SUPPORTED_STABLECOIN_DECIMALS,
-        crate::errors::ErrorCode::UnsupportedMintDecimals
+        ErrorCode::UnsupportedMintDecimals
     );
+    require!(
+        ctx.accounts
+            .config
+            .is_mint_allowed(&ctx.accounts.mint.key()),
+        ErrorCode::MintNotAllowed
+    );
+    PaymentStream::validate_inactivity_threshold(inactivity_threshold_seconds)?;
+    kind.validate(AccrualMode::WallClock)?;
+
+    // The protocol fee comes out of the deposit and never reaches the vault,
+    // so `total_deposited` matches the vault balance exactly.
+    let deposit = EmployerDeposit {
+        mint: &ctx.accounts.mint,
+        from: &ctx.accounts.employer_token_account,
+        authority: &ctx.accounts.employer,
+        token_program: &ctx.accounts.token_program,
+    };
+    let protocol_fee = charge_deposit_fee(
+        &ctx.accounts.config,
+        ctx.accounts.fee_vault.as_ref(),
+        &deposit,
+        stream.key(),
+        total_deposit,
+    )?;
+    let net_deposit = total_deposit
+        .checked_sub(protocol_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+    deposit.transfer(ctx.accounts.vault.to_account_info(), net_deposit)?;
 
     stream.employer = ctx.accounts.employer.key();
+    stream.original_employer = ctx.accounts.employer.key();
+    stream.pending_employer = None;
     stream.employee = ctx.accounts.employee.key();
+    stream.original_employee = ctx.accounts.employee.key();
+    stream.pending_employee_recovery = None;
+    stream.session_key = None;
     stream.mint = ctx.accounts.mint.key();
     stream.vault = ctx.accounts.vault.key();
+    stream.payout_destination = None;
+    stream.pending_payout_destination = None;
+    stream.payout_splits = Vec::new();
+    stream.pending_payout_splits = None;
+    stream.withholding = None;
+    stream.total_withheld = 0;
+    stream.withholding_year = 0;
+    stream.withheld_this_year = 0;
+    stream.deductions = Vec::new();
+    stream.total_deducted = 0;
+    stream.total_employer_matched = 0;
+    stream.garnishment_issuer = None;
+    stream.active_garnishment = None;
+    stream.garnishment_count = 0;
+    stream.total_garnished = 0;
+    stream.total_protocol_fees = protocol_fee;
+    stream.advance_limit = 0;
+    stream.outstanding_advance = 0;
+    stream.total_advanced = 0;
+    stream.total_bonus_awarded = 0;
+    stream.milestone_count = 0;
+    stream.milestone_escrow = 0;
+    stream.total_milestones_released = 0;
+    stream.expense_claim_count = 0;
+    stream.total_expenses_reimbursed = 0;
+    stream.expenses_paid_from_vault = 0;
+    stream.kind = kind;
+    stream.salary_periods_paid = 0;
+    stream.accrual_mode = AccrualMode::WallClock;
+    stream.accrued_amount = 0;
+    stream.timesheet_approver = None;
+    stream.last_timesheet_period_end = 0;
+    stream.max_shift_seconds = DEFAULT_MAX_SHIFT_SECONDS;
+    stream.clocked_in_at = None;
+    stream.shift_count = 0;
+    stream.shift_seconds = 0;
+    stream.pay_multipliers = None;
+    stream.overtime_workweek = 0;
+    stream.workweek_seconds = 0;
+    stream.shift_premium_amount = 0;
+    stream.total_premium_earned = 0;
+    stream.weekly_schedule = WeeklySchedule::default();
     stream.hourly_rate = hourly_rate;
-    stream.total_deposited = total_deposit;
+    stream.total_deposited = net_deposit;
     stream.withdrawn_amount = 0;
     stream.created_at = clock.unix_timestamp;
+    stream.accrual_started_at = clock.unix_timestamp;
+    stream.awaiting_acceptance = require_acceptance;
     stream.employee_last_activity_at = clock.unix_timestamp;
+    stream.inactivity_threshold_seconds = inactivity_threshold_seconds;
+    stream.emergency_withdraw_announced_at = None;
     stream.is_active = true;
     stream.bump = ctx.bumps.stream;
 
-    // Transfer USDC from employer to vault
-    let cpi_accounts = TransferChecked {
-        mint: ctx.accounts.mint.to_account_info(),
-        from: ctx.accounts.employer_token_account.to_account_info(),
-        to: ctx.accounts.vault.to_account_info(),
-        authority: ctx.accounts.employer.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    token::transfer_checked(cpi_ctx, total_deposit, ctx.accounts.mint.decimals)?;
-
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct CreateStream<'info> {
-    #[account(mut)]
     pub employer: Signer<'info>,
 
+    /// Pays rent for the stream and vault. Separate from `employer` so PDA
+    /// employers such as organizations can create streams.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     /// CHECK: Employee address is stored in the stream PDA for future validation. No authority checks needed at stream creation.
     pub employee: AccountInfo<'info>,
 
     pub mint: Account<'info, token::Mint>,
 
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProgramConfig>,
+
     #[account(
         init,
-        payer = employer,
+        payer = payer,
         space = PaymentStream::DISCRIMINATOR.len() + PaymentStream::INIT_SPACE,
         seeds = [b"stream", employer.key().as_ref(), employee.key().as_ref()],
         bump
@@ -64,7 +148,7 @@ pub struct CreateStream<'info> {
 
     #[account(
         init,
-        payer = employer,
+        payer = payer,
         token::mint = mint,
         token::authority = stream,
         seeds = [b"vault", stream.key().as_ref()],
@@ -79,6 +163,14 @@ pub struct CreateStream<'info> {
     )]
     pub employer_token_account: Account<'info, TokenAccount>,
 
+    /// The mint's fee vault. Required whenever a non-zero protocol fee applies.
+    #[account(
+        mut,
+        seeds = [b"fee_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: Option<Account<'info, TokenAccount>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
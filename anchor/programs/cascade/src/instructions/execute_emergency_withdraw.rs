@@ -1,11 +1,10 @@
 use crate::errors::ErrorCode;
-use crate::state::PaymentStream;
+use crate::events::{AdvanceWrittenOff, EmergencyWithdrawExecuted};
+use crate::state::{PaymentStream, EMERGENCY_WITHDRAW_NOTICE_SECONDS};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, TransferChecked};
 
-const INACTIVITY_THRESHOLD_SECONDS: i64 = 30 * 24 * 60 * 60;
-
-fn get_employee_inactive_duration(
+pub(crate) fn get_employee_inactive_duration(
     current_timestamp: i64,
     employee_last_activity_at: i64,
 ) -> Option<i64> {
@@ -14,6 +13,34 @@ fn get_employee_inactive_duration(
         .filter(|duration| *duration >= 0)
 }
 
+pub(crate) fn assert_employee_inactive(
+    stream: &PaymentStream,
+    current_timestamp: i64,
+) -> Result<()> {
+    let employee_inactive_duration =
+        get_employee_inactive_duration(current_timestamp, stream.employee_last_activity_at)
+            .ok_or(ErrorCode::InvalidTimestamp)?;
+
+    require!(
+        employee_inactive_duration >= stream.inactivity_threshold_seconds,
+        ErrorCode::EmployeeStillActive
+    );
+    Ok(())
+}
+
+fn assert_notice_period_elapsed(announced_at: Option<i64>, current_timestamp: i64) -> Result<()> {
+    let announced_at = announced_at.ok_or(ErrorCode::EmergencyWithdrawNotAnnounced)?;
+    let executable_at = announced_at
+        .checked_add(EMERGENCY_WITHDRAW_NOTICE_SECONDS)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    require!(
+        current_timestamp >= executable_at,
+        ErrorCode::EmergencyWithdrawNoticeActive
+    );
+    Ok(())
+}
+
 fn assert_vault_balance_not_deficit(
     withdrawable_vault_balance: u64,
     expected_vault_balance: u64,
@@ -30,13 +57,18 @@ fn assert_vault_balance_not_deficit(
 fn finalize_stream_after_emergency_withdraw(stream: &mut PaymentStream) -> Result<()> {
     // Stream vault is emptied (plus optional donation overflow), so mark the
     // accounted stream balance as fully withdrawn before close.
-    stream.withdrawn_amount = stream.total_deposited;
+    stream.withdrawn_amount = stream
+        .wage_funding()?
+        .checked_sub(stream.expenses_paid_from_vault)
+        .ok_or(ErrorCode::InvalidStreamAccounting)?;
     stream.is_active = false;
+    stream.emergency_withdraw_announced_at = None;
+    stream.outstanding_advance = 0;
     stream.assert_accounting_invariant()?;
     Ok(())
 }
 
-pub fn employer_emergency_withdraw(ctx: Context<EmployerEmergencyWithdraw>) -> Result<()> {
+pub fn execute_emergency_withdraw(ctx: Context<ExecuteEmergencyWithdraw>) -> Result<()> {
     let stream = &mut ctx.accounts.stream;
     let clock = Clock::get()?;
 
@@ -45,24 +77,25 @@ pub fn employer_emergency_withdraw(ctx: Context<EmployerEmergencyWithdraw>) -> R
         ErrorCode::UnauthorizedEmployer
     );
 
-    // Check if 30 days have passed since employee's last activity
-    let employee_inactive_duration =
-        get_employee_inactive_duration(clock.unix_timestamp, stream.employee_last_activity_at)
-            .ok_or(ErrorCode::InvalidTimestamp)?;
-
-    require!(
-        employee_inactive_duration >= INACTIVITY_THRESHOLD_SECONDS,
-        ErrorCode::EmployeeStillActive
-    );
+    // Employee activity clears the announcement, so reaching this point means
+    // the employee stayed silent for the whole notice window.
+    assert_notice_period_elapsed(stream.emergency_withdraw_announced_at, clock.unix_timestamp)?;
+    assert_employee_inactive(stream, clock.unix_timestamp)?;
+    stream.assert_milestones_resolved()?;
 
     stream.assert_accounting_invariant()?;
+    // The employer bears any advance that accrued wages have not repaid.
+    let total_earned = stream.earned_amount(clock.unix_timestamp)?;
+    stream.settle_outstanding_advance(total_earned);
+    let written_off_advance = stream.outstanding_advance;
+
     let expected_vault_balance = stream.expected_vault_balance()?;
     let withdrawable_vault_balance = ctx.accounts.vault.amount;
     assert_vault_balance_not_deficit(withdrawable_vault_balance, expected_vault_balance)?;
 
     // Transfer remaining balance back to employer
-    let employer_key = stream.employer.key();
-    let employee_key = stream.employee.key();
+    let employer_key = stream.original_employer;
+    let employee_key = stream.original_employee;
     let seeds = &[
         b"stream",
         employer_key.as_ref(),
@@ -89,16 +122,30 @@ pub fn employer_emergency_withdraw(ctx: Context<EmployerEmergencyWithdraw>) -> R
 
     finalize_stream_after_emergency_withdraw(stream)?;
 
+    if written_off_advance > 0 {
+        emit!(AdvanceWrittenOff {
+            stream: stream.key(),
+            amount: written_off_advance,
+        });
+    }
+
+    emit!(EmergencyWithdrawExecuted {
+        stream: stream.key(),
+        amount: withdrawable_vault_balance,
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        assert_vault_balance_not_deficit, finalize_stream_after_emergency_withdraw,
-        get_employee_inactive_duration,
+        assert_notice_period_elapsed, assert_vault_balance_not_deficit,
+        finalize_stream_after_emergency_withdraw, get_employee_inactive_duration,
     };
     use crate::state::PaymentStream;
+    use crate::state::EMERGENCY_WITHDRAW_NOTICE_SECONDS;
     use anchor_lang::prelude::Pubkey;
 
     #[test]
@@ -125,6 +172,17 @@ mod tests {
         assert!(assert_vault_balance_not_deficit(6, 5).is_ok());
     }
 
+    #[test]
+    fn requires_announcement_and_elapsed_notice_period() {
+        assert!(assert_notice_period_elapsed(None, 1_000).is_err());
+        assert!(assert_notice_period_elapsed(Some(1_000), 1_000).is_err());
+        assert!(assert_notice_period_elapsed(
+            Some(1_000),
+            1_000 + EMERGENCY_WITHDRAW_NOTICE_SECONDS
+        )
+        .is_ok());
+    }
+
     #[test]
     fn marks_stream_fully_withdrawn_and_inactive_after_emergency_withdraw() {
         let mut stream = PaymentStream {
@@ -137,8 +195,11 @@ mod tests {
             withdrawn_amount: 3,
             created_at: 0,
             employee_last_activity_at: 0,
+            inactivity_threshold_seconds: 30 * 24 * 60 * 60,
+            emergency_withdraw_announced_at: Some(0),
             is_active: true,
             bump: 0,
+            ..Default::default()
         };
 
         let result = finalize_stream_after_emergency_withdraw(&mut stream);
@@ -146,6 +207,7 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(stream.withdrawn_amount, stream.total_deposited);
         assert!(!stream.is_active);
+        assert_eq!(stream.emergency_withdraw_announced_at, None);
         assert_eq!(
             stream
                 .expected_vault_balance()
@@ -156,13 +218,13 @@ mod tests {
 }
 
 #[derive(Accounts)]
-pub struct EmployerEmergencyWithdraw<'info> {
+pub struct ExecuteEmergencyWithdraw<'info> {
     #[account(mut)]
     pub employer: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [b"stream", employer.key().as_ref(), stream.employee.as_ref()],
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
         bump = stream.bump,
         has_one = vault,
         has_one = mint
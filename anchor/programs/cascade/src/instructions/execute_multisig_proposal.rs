@@ -0,0 +1,85 @@
+use crate::employer_authority::invoke_as_employer;
+use crate::errors::ErrorCode;
+use crate::events::MultisigProposalExecuted;
+use crate::program::Cascade;
+use crate::state::{Multisig, MultisigProposal, ProposalAccount};
+use anchor_lang::prelude::*;
+
+fn assert_accounts_match_proposal(
+    proposal_accounts: &[ProposalAccount],
+    accounts: &[AccountInfo],
+) -> Result<()> {
+    require!(
+        proposal_accounts.len() == accounts.len(),
+        ErrorCode::ProposalAccountsMismatch
+    );
+    for (proposal_account, account) in proposal_accounts.iter().zip(accounts) {
+        require!(
+            proposal_account.pubkey == account.key()
+                && (account.is_writable || !proposal_account.is_writable),
+            ErrorCode::ProposalAccountsMismatch
+        );
+    }
+    Ok(())
+}
+
+/// Executes an approved proposal with the multisig PDA as the employer signer.
+/// Anyone may execute once the threshold is met; the proposal is closed
+/// afterwards, so it can only run once.
+pub fn execute_multisig_proposal<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteMultisigProposal<'info>>,
+) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &ctx.accounts.proposal;
+
+    require!(
+        proposal.is_approved(multisig),
+        ErrorCode::ProposalNotApproved
+    );
+    assert_accounts_match_proposal(&proposal.accounts, ctx.remaining_accounts)?;
+
+    let multisig_key = multisig.key();
+    let seeds = &[b"multisig", multisig.creator.as_ref(), &[multisig.bump]];
+    let signer = &[&seeds[..]];
+
+    invoke_as_employer(
+        &ctx.accounts.cascade_program.to_account_info(),
+        &multisig_key,
+        ctx.remaining_accounts,
+        proposal.data.clone(),
+        signer,
+    )?;
+
+    emit!(MultisigProposalExecuted {
+        multisig: multisig_key,
+        proposal: proposal.key(),
+        index: proposal.index,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteMultisigProposal<'info> {
+    #[account(
+        seeds = [b"multisig", multisig.creator.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"proposal", multisig.key().as_ref(), proposal.index.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = multisig,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    /// CHECK: Receives the proposal rent; validated by `has_one` on the proposal.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    pub cascade_program: Program<'info, Cascade>,
+}
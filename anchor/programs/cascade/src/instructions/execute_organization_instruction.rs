@@ -0,0 +1,56 @@
+use crate::employer_authority::{invoke_as_employer, EmployerInstruction};
+use crate::errors::ErrorCode;
+use crate::program::Cascade;
+use crate::state::{Organization, OrganizationMember};
+use anchor_lang::prelude::*;
+
+/// Lets an organization member sign an employer-side instruction on behalf of
+/// the organization. The inner instruction's accounts are passed as remaining
+/// accounts, with the organization PDA in the `employer` position.
+pub fn execute_organization_instruction<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteOrganizationInstruction<'info>>,
+    data: Vec<u8>,
+) -> Result<()> {
+    let organization = &ctx.accounts.organization;
+    let employer_instruction = EmployerInstruction::from_instruction_data(&data)?;
+
+    require!(
+        ctx.accounts.membership.can_execute(employer_instruction),
+        ErrorCode::MissingOrganizationRole
+    );
+
+    let organization_key = organization.key();
+    let seeds = &[
+        b"organization",
+        organization.creator.as_ref(),
+        &[organization.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    invoke_as_employer(
+        &ctx.accounts.cascade_program.to_account_info(),
+        &organization_key,
+        ctx.remaining_accounts,
+        data,
+        signer,
+    )
+}
+
+#[derive(Accounts)]
+pub struct ExecuteOrganizationInstruction<'info> {
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [b"organization", organization.creator.as_ref()],
+        bump = organization.bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        seeds = [b"member", organization.key().as_ref(), member.key().as_ref()],
+        bump = membership.bump
+    )]
+    pub membership: Account<'info, OrganizationMember>,
+
+    pub cascade_program: Program<'info, Cascade>,
+}
@@ -0,0 +1,131 @@
+use crate::deposit::{charge_deposit_fee, EmployerDeposit};
+use crate::errors::ErrorCode;
+use crate::events::MilestoneFunded;
+use crate::state::{Milestone, MilestoneStatus, PaymentStream, ProgramConfig, PAUSE_TOP_UP_STREAM};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Escrows the next milestone in the stream vault. Escrowed funds are held
+/// apart from wage funding until the milestone is approved.
+pub fn fund_milestone(
+    ctx: Context<FundMilestone>,
+    amount: u64,
+    description_hash: [u8; 32],
+) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let milestone = &mut ctx.accounts.milestone;
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    ctx.accounts.config.assert_not_paused(PAUSE_TOP_UP_STREAM)?;
+    stream.assert_accounting_invariant()?;
+
+    // The protocol fee comes out of the deposit and never reaches the vault.
+    let deposit = EmployerDeposit {
+        mint: &ctx.accounts.mint,
+        from: &ctx.accounts.employer_token_account,
+        authority: &ctx.accounts.employer,
+        token_program: &ctx.accounts.token_program,
+    };
+    let protocol_fee = charge_deposit_fee(
+        &ctx.accounts.config,
+        ctx.accounts.fee_vault.as_ref(),
+        &deposit,
+        stream.key(),
+        amount,
+    )?;
+    let net_amount = amount
+        .checked_sub(protocol_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+    deposit.transfer(ctx.accounts.vault.to_account_info(), net_amount)?;
+
+    milestone.stream = stream.key();
+    milestone.index = stream.milestone_count;
+    milestone.amount = net_amount;
+    milestone.description_hash = description_hash;
+    milestone.status = MilestoneStatus::Funded;
+    milestone.funded_at = clock.unix_timestamp;
+    milestone.submitted_at = None;
+    milestone.approved_at = None;
+    milestone.auto_approved = false;
+    milestone.bump = ctx.bumps.milestone;
+
+    stream.milestone_count = stream
+        .milestone_count
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    stream.milestone_escrow = stream
+        .milestone_escrow
+        .checked_add(net_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    stream.total_protocol_fees = stream
+        .total_protocol_fees
+        .checked_add(protocol_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    emit!(MilestoneFunded {
+        stream: stream.key(),
+        milestone: milestone.key(),
+        index: milestone.index,
+        amount: net_amount,
+        description_hash,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundMilestone<'info> {
+    pub employer: Signer<'info>,
+
+    /// Pays rent for the milestone. Separate from `employer` so PDA employers
+    /// such as organizations can fund milestones.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer,
+        has_one = vault,
+        has_one = mint
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Milestone::DISCRIMINATOR.len() + Milestone::INIT_SPACE,
+        seeds = [b"milestone", stream.key().as_ref(), stream.milestone_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    pub mint: Account<'info, token::Mint>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = employer_token_account.owner == employer.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = employer_token_account.mint == stream.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub employer_token_account: Account<'info, TokenAccount>,
+
+    /// The mint's fee vault. Required whenever a non-zero protocol fee applies.
+    #[account(
+        mut,
+        seeds = [b"fee_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
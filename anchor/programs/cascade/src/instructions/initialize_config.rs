@@ -0,0 +1,45 @@
+use crate::errors::ErrorCode;
+use crate::program::Cascade;
+use crate::state::{FeeSchedule, ProgramConfig};
+use anchor_lang::prelude::*;
+
+pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.admin = ctx.accounts.admin.key();
+    config.pending_admin = None;
+    config.guardian = ctx.accounts.admin.key();
+    config.allowed_mints = Vec::new();
+    config.paused_operations = 0;
+    config.fee_schedule = FeeSchedule::default();
+    config.bump = ctx.bumps.config;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ProgramConfig::DISCRIMINATOR.len() + ProgramConfig::INIT_SPACE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    // Only the upgrade authority may claim the config, otherwise anyone could
+    // front-run deployment and become admin.
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Cascade>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,39 @@
+use crate::errors::ErrorCode;
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Creates the fee vault for `mint`. It must exist before a non-zero fee can
+/// be charged on streams of that mint.
+pub fn initialize_fee_vault(_ctx: Context<InitializeFeeVault>) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeFeeVault<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub mint: Account<'info, token::Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        token::mint = mint,
+        token::authority = config,
+        seeds = [b"fee_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
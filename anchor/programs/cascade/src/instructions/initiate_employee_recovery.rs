@@ -0,0 +1,67 @@
+use crate::errors::ErrorCode;
+use crate::events::EmployeeRecoveryInitiated;
+use crate::state::{
+    PaymentStream, PendingEmployeeRecovery, ProgramConfig, EMPLOYEE_RECOVERY_DELAY_SECONDS,
+};
+use anchor_lang::prelude::*;
+
+/// Starts recovering a stream whose employee lost their key. Requires both the
+/// employer and the program guardian, and only takes effect after a time lock
+/// during which the current employee can still cancel it.
+pub fn initiate_employee_recovery(
+    ctx: Context<InitiateEmployeeRecovery>,
+    new_employee: Pubkey,
+) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    require!(
+        stream.pending_employee_recovery.is_none(),
+        ErrorCode::EmployeeRecoveryAlreadyPending
+    );
+    require!(
+        new_employee != stream.employee,
+        ErrorCode::InvalidNewEmployee
+    );
+
+    let executable_at = clock
+        .unix_timestamp
+        .checked_add(EMPLOYEE_RECOVERY_DELAY_SECONDS)
+        .ok_or(ErrorCode::MathOverflow)?;
+    stream.pending_employee_recovery = Some(PendingEmployeeRecovery {
+        new_employee,
+        executable_at,
+    });
+
+    emit!(EmployeeRecoveryInitiated {
+        stream: stream.key(),
+        employee: stream.employee,
+        new_employee,
+        executable_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitiateEmployeeRecovery<'info> {
+    pub employer: Signer<'info>,
+
+    pub guardian: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = guardian @ ErrorCode::UnauthorizedGuardian
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
@@ -0,0 +1,90 @@
+use crate::errors::ErrorCode;
+use crate::events::GarnishmentOrderIssued;
+use crate::state::{GarnishmentOrder, PaymentStream, MAX_GARNISHMENT_RATE_BPS};
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+pub fn issue_garnishment_order(
+    ctx: Context<IssueGarnishmentOrder>,
+    rate_bps: u16,
+    ordered_amount: u64,
+) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let order = &mut ctx.accounts.garnishment_order;
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    require!(
+        stream.garnishment_issuer == Some(ctx.accounts.issuer.key()),
+        ErrorCode::UnauthorizedGarnishmentIssuer
+    );
+    require!(
+        stream.active_garnishment.is_none(),
+        ErrorCode::GarnishmentAlreadyActive
+    );
+    require!(
+        rate_bps > 0 && rate_bps <= MAX_GARNISHMENT_RATE_BPS && ordered_amount > 0,
+        ErrorCode::InvalidGarnishmentOrder
+    );
+
+    order.stream = stream.key();
+    order.index = stream.garnishment_count;
+    order.issuer = ctx.accounts.issuer.key();
+    order.beneficiary = ctx.accounts.beneficiary_token_account.key();
+    order.rate_bps = rate_bps;
+    order.ordered_amount = ordered_amount;
+    order.paid_amount = 0;
+    order.issued_at = clock.unix_timestamp;
+    order.closed_at = None;
+    order.bump = ctx.bumps.garnishment_order;
+
+    stream.active_garnishment = Some(order.key());
+    stream.garnishment_count = stream
+        .garnishment_count
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    emit!(GarnishmentOrderIssued {
+        stream: stream.key(),
+        garnishment_order: order.key(),
+        issuer: order.issuer,
+        beneficiary: order.beneficiary,
+        rate_bps,
+        ordered_amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct IssueGarnishmentOrder<'info> {
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    #[account(
+        init,
+        payer = issuer,
+        space = GarnishmentOrder::DISCRIMINATOR.len() + GarnishmentOrder::INIT_SPACE,
+        seeds = [
+            b"garnishment",
+            stream.key().as_ref(),
+            stream.garnishment_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub garnishment_order: Account<'info, GarnishmentOrder>,
+
+    #[account(
+        constraint = beneficiary_token_account.mint == stream.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub beneficiary_token_account: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+}
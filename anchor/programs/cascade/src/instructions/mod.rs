@@ -1,13 +1,137 @@
+pub mod accept_config_admin;
+pub mod accept_employer_transfer;
+pub mod accept_stream;
+pub mod add_allowed_mint;
+pub mod add_organization_member;
+pub mod advance;
+pub mod announce_emergency_withdraw;
+pub mod approve_expense_claim;
+pub mod approve_milestone;
+pub mod approve_multisig_proposal;
+pub mod award_bonus;
+pub mod cancel_employee_recovery;
+pub mod cancel_milestone;
+pub mod cancel_multisig_proposal;
+pub mod clock_in;
+pub mod clock_out;
+pub mod close_shift_record;
 pub mod close_stream;
+pub mod collect_protocol_fees;
+pub mod complete_employee_recovery;
+pub mod create_multisig;
+pub mod create_organization;
 pub mod create_stream;
-pub mod employer_emergency_withdraw;
+pub mod execute_emergency_withdraw;
+pub mod execute_multisig_proposal;
+pub mod execute_organization_instruction;
+pub mod fund_milestone;
+pub mod initialize_config;
+pub mod initialize_fee_vault;
+pub mod initiate_employee_recovery;
+pub mod issue_garnishment_order;
+pub mod propose_config_admin;
+pub mod propose_employer_transfer;
+pub mod propose_multisig_instruction;
 pub mod refresh_activity;
+pub mod register_session_key;
+pub mod reject_expense_claim;
+pub mod reject_milestone;
+pub mod release_garnishment_order;
+pub mod remove_allowed_mint;
+pub mod remove_organization_member;
+pub mod revoke_session_key;
+pub mod rotate_employee;
+pub mod set_accrual_mode;
+pub mod set_advance_limit;
+pub mod set_deductions;
+pub mod set_fee_schedule;
+pub mod set_garnishment_issuer;
+pub mod set_max_shift_length;
+pub mod set_multisig_signers;
+pub mod set_paused_operations;
+pub mod set_pay_multipliers;
+pub mod set_payout_destination;
+pub mod set_payout_splits;
+pub mod set_stream_kind;
+pub mod set_timesheet_approver;
+pub mod set_weekly_schedule;
+pub mod set_withholding_rule;
+pub mod submit_expense_claim;
+pub mod submit_milestone;
+pub mod submit_timesheet;
 pub mod top_up_stream;
+pub mod update_config_guardian;
+pub mod update_inactivity_threshold;
+pub mod update_organization_member;
+pub mod void_shift;
 pub mod withdraw;
+pub mod withdraw_employer_treasury;
 
+pub use accept_config_admin::*;
+pub use accept_employer_transfer::*;
+pub use accept_stream::*;
+pub use add_allowed_mint::*;
+pub use add_organization_member::*;
+pub use advance::*;
+pub use announce_emergency_withdraw::*;
+pub use approve_expense_claim::*;
+pub use approve_milestone::*;
+pub use approve_multisig_proposal::*;
+pub use award_bonus::*;
+pub use cancel_employee_recovery::*;
+pub use cancel_milestone::*;
+pub use cancel_multisig_proposal::*;
+pub use clock_in::*;
+pub use clock_out::*;
+pub use close_shift_record::*;
 pub use close_stream::*;
+pub use collect_protocol_fees::*;
+pub use complete_employee_recovery::*;
+pub use create_multisig::*;
+pub use create_organization::*;
 pub use create_stream::*;
-pub use employer_emergency_withdraw::*;
+pub use execute_emergency_withdraw::*;
+pub use execute_multisig_proposal::*;
+pub use execute_organization_instruction::*;
+pub use fund_milestone::*;
+pub use initialize_config::*;
+pub use initialize_fee_vault::*;
+pub use initiate_employee_recovery::*;
+pub use issue_garnishment_order::*;
+pub use propose_config_admin::*;
+pub use propose_employer_transfer::*;
+pub use propose_multisig_instruction::*;
 pub use refresh_activity::*;
+pub use register_session_key::*;
+pub use reject_expense_claim::*;
+pub use reject_milestone::*;
+pub use release_garnishment_order::*;
+pub use remove_allowed_mint::*;
+pub use remove_organization_member::*;
+pub use revoke_session_key::*;
+pub use rotate_employee::*;
+pub use set_accrual_mode::*;
+pub use set_advance_limit::*;
+pub use set_deductions::*;
+pub use set_fee_schedule::*;
+pub use set_garnishment_issuer::*;
+pub use set_max_shift_length::*;
+pub use set_multisig_signers::*;
+pub use set_paused_operations::*;
+pub use set_pay_multipliers::*;
+pub use set_payout_destination::*;
+pub use set_payout_splits::*;
+pub use set_stream_kind::*;
+pub use set_timesheet_approver::*;
+pub use set_weekly_schedule::*;
+pub use set_withholding_rule::*;
+pub use submit_expense_claim::*;
+pub use submit_milestone::*;
+pub use submit_timesheet::*;
 pub use top_up_stream::*;
+pub use update_config_guardian::*;
+pub use update_inactivity_threshold::*;
+pub use update_organization_member::*;
+pub use void_shift::*;
 pub use withdraw::*;
+pub use withdraw_employer_treasury::*;
@@ -0,0 +1,32 @@
+use crate::errors::ErrorCode;
+use crate::events::ConfigAdminProposed;
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+
+/// Nominates a new config admin. Proposing again replaces the pending
+/// nominee; proposing the current admin cancels the handover.
+pub fn propose_config_admin(ctx: Context<ProposeConfigAdmin>, new_admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.propose_admin(new_admin);
+
+    emit!(ConfigAdminProposed {
+        admin: config.admin,
+        pending_admin: new_admin,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeConfigAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,35 @@
+use crate::errors::ErrorCode;
+use crate::events::EmployerTransferProposed;
+use crate::state::PaymentStream;
+use anchor_lang::prelude::*;
+
+/// Nominates a new employer authority. See `PaymentStream::propose_employer`.
+pub fn propose_employer_transfer(
+    ctx: Context<ProposeEmployerTransfer>,
+    new_employer: Pubkey,
+) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+
+    stream.propose_employer(new_employer);
+
+    emit!(EmployerTransferProposed {
+        stream: stream.key(),
+        employer: stream.employer,
+        pending_employer: new_employer,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeEmployerTransfer<'info> {
+    pub employer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
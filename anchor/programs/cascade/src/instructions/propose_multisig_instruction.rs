@@ -0,0 +1,80 @@
+use crate::errors::ErrorCode;
+use crate::events::MultisigProposalCreated;
+use crate::state::{Multisig, MultisigProposal, ProposalAccount};
+use anchor_lang::prelude::*;
+
+/// Records an employer-side instruction for approval. The instruction's
+/// accounts are passed as remaining accounts and pinned on the proposal.
+pub fn propose_multisig_instruction<'info>(
+    ctx: Context<'_, '_, '_, 'info, ProposeMultisigInstruction<'info>>,
+    data: Vec<u8>,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    let clock = Clock::get()?;
+
+    require!(
+        multisig.is_signer(&ctx.accounts.proposer.key()),
+        ErrorCode::UnauthorizedMultisigSigner
+    );
+    MultisigProposal::validate_instruction(&data, ctx.remaining_accounts.len())?;
+
+    proposal.multisig = multisig.key();
+    proposal.index = multisig.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.data = data;
+    proposal.accounts = ctx
+        .remaining_accounts
+        .iter()
+        .map(|account| ProposalAccount {
+            pubkey: account.key(),
+            is_writable: account.is_writable,
+        })
+        .collect();
+    // Proposing counts as the proposer's approval.
+    proposal.approvals = vec![ctx.accounts.proposer.key()];
+    proposal.created_at = clock.unix_timestamp;
+    proposal.bump = ctx.bumps.proposal;
+
+    multisig.proposal_count = multisig
+        .proposal_count
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    emit!(MultisigProposalCreated {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        index: proposal.index,
+        proposer: proposal.proposer,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeMultisigInstruction<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"multisig", multisig.creator.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = MultisigProposal::DISCRIMINATOR.len() + MultisigProposal::INIT_SPACE,
+        seeds = [
+            b"proposal",
+            multisig.key().as_ref(),
+            multisig.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,4 +1,5 @@
 use crate::errors::ErrorCode;
+use crate::events::EmergencyWithdrawCancelled;
 use crate::state::PaymentStream;
 use anchor_lang::prelude::*;
 
@@ -7,24 +8,33 @@ pub fn refresh_activity(ctx: Context<RefreshActivity>) -> Result<()> {
     let clock = Clock::get()?;
 
     require!(stream.is_active, ErrorCode::StreamInactive);
+    let authority = ctx.accounts.authority.key();
     require!(
-        ctx.accounts.employee.key() == stream.employee,
+        authority == stream.employee
+            || stream.is_valid_session_key(&authority, clock.unix_timestamp),
         ErrorCode::UnauthorizedEmployee
     );
 
-    stream.employee_last_activity_at = clock.unix_timestamp;
+    if let Some(announced_at) = stream.record_employee_activity(clock.unix_timestamp) {
+        emit!(EmergencyWithdrawCancelled {
+            stream: stream.key(),
+            announced_at,
+            cancelled_at: clock.unix_timestamp,
+        });
+    }
 
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct RefreshActivity<'info> {
+    /// The employee, or an unexpired session key registered by the employee.
     #[account(mut)]
-    pub employee: Signer<'info>,
+    pub authority: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [b"stream", stream.employer.as_ref(), employee.key().as_ref()],
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
         bump = stream.bump
     )]
     pub stream: Account<'info, PaymentStream>,
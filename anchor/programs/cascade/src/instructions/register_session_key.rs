@@ -0,0 +1,51 @@
+use crate::errors::ErrorCode;
+use crate::events::SessionKeyRegistered;
+use crate::state::{PaymentStream, SessionKey, MAX_SESSION_KEY_DURATION_SECONDS};
+use anchor_lang::prelude::*;
+
+/// Registers a key that may send activity heartbeats for the employee.
+/// Replaces any previously registered session key.
+pub fn register_session_key(
+    ctx: Context<RegisterSessionKey>,
+    session_key: Pubkey,
+    expires_at: i64,
+) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    let max_expires_at = clock
+        .unix_timestamp
+        .checked_add(MAX_SESSION_KEY_DURATION_SECONDS)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(
+        expires_at > clock.unix_timestamp && expires_at <= max_expires_at,
+        ErrorCode::InvalidSessionKeyExpiry
+    );
+
+    stream.session_key = Some(SessionKey {
+        key: session_key,
+        expires_at,
+    });
+
+    emit!(SessionKeyRegistered {
+        stream: stream.key(),
+        session_key,
+        expires_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterSessionKey<'info> {
+    pub employee: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employee @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
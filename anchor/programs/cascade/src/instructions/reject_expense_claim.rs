@@ -0,0 +1,39 @@
+use crate::errors::ErrorCode;
+use crate::events::ExpenseClaimRejected;
+use crate::state::{ExpenseClaim, PaymentStream};
+use anchor_lang::prelude::*;
+
+pub fn reject_expense_claim(ctx: Context<RejectExpenseClaim>) -> Result<()> {
+    let expense_claim = &mut ctx.accounts.expense_claim;
+    let clock = Clock::get()?;
+
+    expense_claim.reject(clock.unix_timestamp)?;
+
+    emit!(ExpenseClaimRejected {
+        stream: ctx.accounts.stream.key(),
+        expense_claim: expense_claim.key(),
+        rejected_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RejectExpenseClaim<'info> {
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    #[account(
+        mut,
+        seeds = [b"expense", stream.key().as_ref(), expense_claim.index.to_le_bytes().as_ref()],
+        bump = expense_claim.bump,
+        has_one = stream
+    )]
+    pub expense_claim: Account<'info, ExpenseClaim>,
+}
@@ -0,0 +1,53 @@
+use crate::errors::ErrorCode;
+use crate::events::MilestoneRejected;
+use crate::state::{Milestone, MilestoneStatus, PaymentStream};
+use anchor_lang::prelude::*;
+
+/// Sends a submission back to the contractor within the review window. The
+/// funds stay escrowed and the milestone can be submitted again.
+pub fn reject_milestone(ctx: Context<RejectMilestone>) -> Result<()> {
+    let stream = &ctx.accounts.stream;
+    let milestone = &mut ctx.accounts.milestone;
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    require!(
+        milestone.status == MilestoneStatus::Submitted,
+        ErrorCode::InvalidMilestoneStatus
+    );
+    require!(
+        !milestone.review_lapsed(clock.unix_timestamp),
+        ErrorCode::MilestoneReviewLapsed
+    );
+
+    milestone.status = MilestoneStatus::Funded;
+    milestone.submitted_at = None;
+
+    emit!(MilestoneRejected {
+        stream: stream.key(),
+        milestone: milestone.key(),
+        rejected_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RejectMilestone<'info> {
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    #[account(
+        mut,
+        seeds = [b"milestone", stream.key().as_ref(), milestone.index.to_le_bytes().as_ref()],
+        bump = milestone.bump,
+        has_one = stream
+    )]
+    pub milestone: Account<'info, Milestone>,
+}
@@ -0,0 +1,54 @@
+use crate::errors::ErrorCode;
+use crate::events::GarnishmentOrderClosed;
+use crate::state::{GarnishmentOrder, PaymentStream};
+use anchor_lang::prelude::*;
+
+/// Lifts an active order before it is satisfied, e.g. when the court
+/// vacates it. Satisfied orders expire on their own in `withdraw`.
+pub fn release_garnishment_order(ctx: Context<ReleaseGarnishmentOrder>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let order = &mut ctx.accounts.garnishment_order;
+    let clock = Clock::get()?;
+
+    require!(
+        stream.active_garnishment == Some(order.key()),
+        ErrorCode::GarnishmentOrderInactive
+    );
+
+    order.closed_at = Some(clock.unix_timestamp);
+    stream.active_garnishment = None;
+
+    emit!(GarnishmentOrderClosed {
+        stream: stream.key(),
+        garnishment_order: order.key(),
+        paid_amount: order.paid_amount,
+        satisfied: false,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReleaseGarnishmentOrder<'info> {
+    pub issuer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"garnishment",
+            stream.key().as_ref(),
+            garnishment_order.index.to_le_bytes().as_ref()
+        ],
+        bump = garnishment_order.bump,
+        has_one = stream,
+        has_one = issuer @ ErrorCode::UnauthorizedGarnishmentIssuer
+    )]
+    pub garnishment_order: Account<'info, GarnishmentOrder>,
+}
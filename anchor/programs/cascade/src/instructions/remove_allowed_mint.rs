@@ -0,0 +1,28 @@
+use crate::errors::ErrorCode;
+use crate::events::AllowedMintRemoved;
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+
+pub fn remove_allowed_mint(ctx: Context<RemoveAllowedMint>, mint: Pubkey) -> Result<()> {
+    ctx.accounts.config.disallow_mint(&mint)?;
+
+    emit!(AllowedMintRemoved {
+        admin: ctx.accounts.admin.key(),
+        mint,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveAllowedMint<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,51 @@
+use crate::errors::ErrorCode;
+use crate::events::OrganizationMemberUpdated;
+use crate::instructions::update_organization_member::apply_admin_role_change;
+use crate::state::{Organization, OrganizationMember, ROLE_ADMIN};
+use anchor_lang::prelude::*;
+
+pub fn remove_organization_member(ctx: Context<RemoveOrganizationMember>) -> Result<()> {
+    let organization = &mut ctx.accounts.organization;
+    let member = &ctx.accounts.member;
+
+    require!(
+        ctx.accounts.admin_membership.has_role(ROLE_ADMIN),
+        ErrorCode::MissingOrganizationRole
+    );
+    apply_admin_role_change(organization, member.roles, 0)?;
+
+    emit!(OrganizationMemberUpdated {
+        organization: organization.key(),
+        wallet: member.wallet,
+        roles: 0,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveOrganizationMember<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"organization", organization.creator.as_ref()],
+        bump = organization.bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        seeds = [b"member", organization.key().as_ref(), admin.key().as_ref()],
+        bump = admin_membership.bump
+    )]
+    pub admin_membership: Account<'info, OrganizationMember>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"member", organization.key().as_ref(), member.wallet.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, OrganizationMember>,
+}
@@ -0,0 +1,30 @@
+use crate::errors::ErrorCode;
+use crate::events::SessionKeyRevoked;
+use crate::state::PaymentStream;
+use anchor_lang::prelude::*;
+
+pub fn revoke_session_key(ctx: Context<RevokeSessionKey>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+
+    if let Some(session_key) = stream.session_key.take() {
+        emit!(SessionKeyRevoked {
+            stream: stream.key(),
+            session_key: session_key.key,
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeSessionKey<'info> {
+    pub employee: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employee @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
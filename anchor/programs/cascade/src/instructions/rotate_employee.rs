@@ -0,0 +1,52 @@
+use crate::errors::ErrorCode;
+use crate::events::{EmergencyWithdrawCancelled, EmployeeRotated};
+use crate::state::PaymentStream;
+use anchor_lang::prelude::*;
+
+pub fn rotate_employee(ctx: Context<RotateEmployee>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let clock = Clock::get()?;
+    let new_employee = ctx.accounts.new_employee.key();
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    require!(
+        new_employee != stream.employee,
+        ErrorCode::InvalidNewEmployee
+    );
+
+    // The employee evidently still controls their key, so any recovery that
+    // was started on their behalf is moot and is cleared with the rest.
+    let previous_employee = stream.replace_employee(new_employee);
+
+    if let Some(announced_at) = stream.record_employee_activity(clock.unix_timestamp) {
+        emit!(EmergencyWithdrawCancelled {
+            stream: stream.key(),
+            announced_at,
+            cancelled_at: clock.unix_timestamp,
+        });
+    }
+
+    emit!(EmployeeRotated {
+        stream: stream.key(),
+        previous_employee,
+        employee: new_employee,
+    });
+
+    Ok(())
+}
+
+/// The new key co-signs so a typo cannot strand the stream.
+#[derive(Accounts)]
+pub struct RotateEmployee<'info> {
+    pub employee: Signer<'info>,
+
+    pub new_employee: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employee @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
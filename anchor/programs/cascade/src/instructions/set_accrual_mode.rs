@@ -0,0 +1,46 @@
+use crate::errors::ErrorCode;
+use crate::events::AccrualModeUpdated;
+use crate::state::{AccrualMode, PaymentStream, StreamKind};
+use anchor_lang::prelude::*;
+
+/// Earnings accrued under the previous mode are banked before switching, so
+/// a mode change never alters what the employee has already earned.
+pub fn set_accrual_mode(ctx: Context<SetAccrualMode>, accrual_mode: AccrualMode) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    require!(
+        stream.kind == StreamKind::Hourly || accrual_mode == AccrualMode::WallClock,
+        ErrorCode::SalariedAccrualMode
+    );
+
+    stream.checkpoint_accrual(clock.unix_timestamp)?;
+    stream.accrual_mode = accrual_mode;
+
+    emit!(AccrualModeUpdated {
+        stream: stream.key(),
+        accrual_mode,
+        accrued_amount: stream.accrued_amount,
+    });
+
+    Ok(())
+}
+
+/// Both parties must sign: the accrual mode decides what the employee is
+/// paid for.
+#[derive(Accounts)]
+pub struct SetAccrualMode<'info> {
+    pub employer: Signer<'info>,
+
+    pub employee: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer,
+        has_one = employee @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
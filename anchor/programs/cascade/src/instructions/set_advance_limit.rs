@@ -0,0 +1,34 @@
+use crate::errors::ErrorCode;
+use crate::events::AdvanceLimitUpdated;
+use crate::state::PaymentStream;
+use anchor_lang::prelude::*;
+
+/// Lowering the limit below the outstanding advance only blocks new
+/// advances; what was already drawn is still repaid from accruing wages.
+pub fn set_advance_limit(ctx: Context<SetAdvanceLimit>, advance_limit: u64) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+
+    stream.advance_limit = advance_limit;
+
+    emit!(AdvanceLimitUpdated {
+        stream: stream.key(),
+        advance_limit,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAdvanceLimit<'info> {
+    pub employer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
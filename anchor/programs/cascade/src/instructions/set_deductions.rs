@@ -0,0 +1,38 @@
+use crate::errors::ErrorCode;
+use crate::events::DeductionsUpdated;
+use crate::state::{Deduction, PaymentStream};
+use anchor_lang::prelude::*;
+
+pub fn set_deductions(ctx: Context<SetDeductions>, deductions: Vec<Deduction>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    PaymentStream::validate_deductions(&deductions, &stream.kind, stream.hourly_rate)?;
+
+    stream.deductions = deductions.clone();
+
+    emit!(DeductionsUpdated {
+        stream: stream.key(),
+        deductions,
+    });
+
+    Ok(())
+}
+
+/// Both parties must sign: deductions reduce the employee's take-home pay and
+/// the employer match is funded from the employer's deposits.
+#[derive(Accounts)]
+pub struct SetDeductions<'info> {
+    pub employer: Signer<'info>,
+
+    pub employee: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer,
+        has_one = employee @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
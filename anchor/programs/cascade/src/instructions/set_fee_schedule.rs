@@ -0,0 +1,27 @@
+use crate::errors::ErrorCode;
+use crate::events::FeeScheduleUpdated;
+use crate::state::{FeeSchedule, ProgramConfig};
+use anchor_lang::prelude::*;
+
+pub fn set_fee_schedule(ctx: Context<SetFeeSchedule>, fee_schedule: FeeSchedule) -> Result<()> {
+    fee_schedule.validate()?;
+
+    ctx.accounts.config.fee_schedule = fee_schedule;
+
+    emit!(FeeScheduleUpdated { fee_schedule });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetFeeSchedule<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
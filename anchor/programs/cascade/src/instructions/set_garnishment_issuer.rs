@@ -0,0 +1,38 @@
+use crate::errors::ErrorCode;
+use crate::events::GarnishmentIssuerUpdated;
+use crate::state::PaymentStream;
+use anchor_lang::prelude::*;
+
+/// Authorizes the key (typically a court or its agent) that may attach
+/// garnishment orders to the stream. Changing it does not affect an order
+/// that is already active.
+pub fn set_garnishment_issuer(
+    ctx: Context<SetGarnishmentIssuer>,
+    issuer: Option<Pubkey>,
+) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+
+    stream.garnishment_issuer = issuer;
+
+    emit!(GarnishmentIssuerUpdated {
+        stream: stream.key(),
+        issuer,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGarnishmentIssuer<'info> {
+    pub employer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
@@ -0,0 +1,38 @@
+use crate::errors::ErrorCode;
+use crate::events::MaxShiftLengthUpdated;
+use crate::state::{PaymentStream, MAX_MAX_SHIFT_SECONDS, MIN_MAX_SHIFT_SECONDS};
+use anchor_lang::prelude::*;
+
+pub fn set_max_shift_length(ctx: Context<SetMaxShiftLength>, max_shift_seconds: i64) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    // Changing the cap mid-shift would retroactively change the open shift's pay.
+    require!(stream.clocked_in_at.is_none(), ErrorCode::ShiftInProgress);
+    require!(
+        (MIN_MAX_SHIFT_SECONDS..=MAX_MAX_SHIFT_SECONDS).contains(&max_shift_seconds),
+        ErrorCode::InvalidMaxShiftLength
+    );
+
+    stream.max_shift_seconds = max_shift_seconds;
+
+    emit!(MaxShiftLengthUpdated {
+        stream: stream.key(),
+        max_shift_seconds,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMaxShiftLength<'info> {
+    pub employer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
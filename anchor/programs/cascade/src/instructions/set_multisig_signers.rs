@@ -0,0 +1,37 @@
+use crate::events::MultisigSignersUpdated;
+use crate::state::Multisig;
+use anchor_lang::prelude::*;
+
+/// Replaces the signer set and threshold. The multisig PDA must sign, so this
+/// only runs through an approved proposal of the multisig itself.
+pub fn set_multisig_signers(
+    ctx: Context<SetMultisigSigners>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+
+    Multisig::validate_signers(&signers, threshold)?;
+
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+
+    emit!(MultisigSignersUpdated {
+        multisig: multisig.key(),
+        signers: multisig.signers.clone(),
+        threshold,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMultisigSigners<'info> {
+    #[account(
+        mut,
+        signer,
+        seeds = [b"multisig", multisig.creator.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+}
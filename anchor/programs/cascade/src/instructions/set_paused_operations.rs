@@ -0,0 +1,42 @@
+use crate::errors::ErrorCode;
+use crate::events::PauseStateChanged;
+use crate::state::{ProgramConfig, PAUSE_ALL};
+use anchor_lang::prelude::*;
+
+pub fn set_paused_operations(
+    ctx: Context<SetPausedOperations>,
+    paused_operations: u8,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    require!(
+        paused_operations & !PAUSE_ALL == 0,
+        ErrorCode::InvalidPauseFlags
+    );
+
+    let previous_paused_operations = config.paused_operations;
+    config.paused_operations = paused_operations;
+
+    emit!(PauseStateChanged {
+        guardian: ctx.accounts.guardian.key(),
+        previous_paused_operations,
+        paused_operations,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPausedOperations<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = guardian @ ErrorCode::UnauthorizedGuardian
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
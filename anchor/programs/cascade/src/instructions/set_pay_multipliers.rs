@@ -0,0 +1,41 @@
+use crate::errors::ErrorCode;
+use crate::events::PayMultipliersUpdated;
+use crate::state::{PayMultipliers, PaymentStream};
+use anchor_lang::prelude::*;
+
+/// Premiums are priced when a shift closes, so a change applies to shifts
+/// closed afterwards and is rejected while the employee is clocked in.
+pub fn set_pay_multipliers(
+    ctx: Context<SetPayMultipliers>,
+    pay_multipliers: Option<PayMultipliers>,
+) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    require!(stream.clocked_in_at.is_none(), ErrorCode::ShiftInProgress);
+    if let Some(pay_multipliers) = pay_multipliers {
+        pay_multipliers.validate()?;
+    }
+
+    stream.pay_multipliers = pay_multipliers;
+
+    emit!(PayMultipliersUpdated {
+        stream: stream.key(),
+        pay_multipliers,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPayMultipliers<'info> {
+    pub employer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
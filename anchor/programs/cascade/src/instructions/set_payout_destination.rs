@@ -0,0 +1,66 @@
+use crate::errors::ErrorCode;
+use crate::events::PayoutDestinationUpdated;
+use crate::state::{
+    PaymentStream, PendingPayoutDestination, PAYOUT_DESTINATION_CHANGE_DELAY_SECONDS,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+/// Sets the token account that `withdraw` pays into. The first destination
+/// applies immediately; later changes are time-locked so a compromised
+/// employee key cannot instantly redirect wages. Re-submitting the current
+/// destination cancels a pending change.
+pub fn set_payout_destination(ctx: Context<SetPayoutDestination>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let clock = Clock::get()?;
+    let token_account = ctx.accounts.payout_token_account.key();
+
+    let current_destination = stream.apply_pending_payout_destination(clock.unix_timestamp);
+    let effective_at = match current_destination {
+        None => {
+            stream.payout_destination = Some(token_account);
+            clock.unix_timestamp
+        }
+        Some(current) if current == token_account => {
+            stream.pending_payout_destination = None;
+            clock.unix_timestamp
+        }
+        Some(_) => {
+            let effective_at = clock
+                .unix_timestamp
+                .checked_add(PAYOUT_DESTINATION_CHANGE_DELAY_SECONDS)
+                .ok_or(ErrorCode::MathOverflow)?;
+            stream.pending_payout_destination = Some(PendingPayoutDestination {
+                token_account,
+                effective_at,
+            });
+            effective_at
+        }
+    };
+
+    emit!(PayoutDestinationUpdated {
+        stream: stream.key(),
+        token_account,
+        effective_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPayoutDestination<'info> {
+    pub employee: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employee @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    #[account(
+        constraint = payout_token_account.mint == stream.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub payout_token_account: Account<'info, TokenAccount>,
+}
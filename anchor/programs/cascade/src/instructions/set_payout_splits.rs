@@ -0,0 +1,57 @@
+use crate::errors::ErrorCode;
+use crate::events::PayoutSplitsUpdated;
+use crate::state::{
+    PaymentStream, PayoutSplit, PendingPayoutSplits, PAYOUT_DESTINATION_CHANGE_DELAY_SECONDS,
+};
+use anchor_lang::prelude::*;
+
+/// Replaces the employee's payout splits. Once a fixed payout destination is
+/// configured, split changes share its time lock; otherwise they would be a
+/// way around it.
+pub fn set_payout_splits(ctx: Context<SetPayoutSplits>, splits: Vec<PayoutSplit>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let clock = Clock::get()?;
+
+    PaymentStream::validate_payout_splits(&splits)?;
+    let split_count = splits.len() as u8;
+
+    let effective_at = if stream
+        .apply_pending_payout_destination(clock.unix_timestamp)
+        .is_some()
+    {
+        let effective_at = clock
+            .unix_timestamp
+            .checked_add(PAYOUT_DESTINATION_CHANGE_DELAY_SECONDS)
+            .ok_or(ErrorCode::MathOverflow)?;
+        stream.pending_payout_splits = Some(PendingPayoutSplits {
+            splits,
+            effective_at,
+        });
+        effective_at
+    } else {
+        stream.payout_splits = splits;
+        stream.pending_payout_splits = None;
+        clock.unix_timestamp
+    };
+
+    emit!(PayoutSplitsUpdated {
+        stream: stream.key(),
+        split_count,
+        effective_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPayoutSplits<'info> {
+    pub employee: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employee @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
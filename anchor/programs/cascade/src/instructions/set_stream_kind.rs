@@ -0,0 +1,45 @@
+use crate::errors::ErrorCode;
+use crate::events::StreamKindUpdated;
+use crate::state::{PaymentStream, StreamKind};
+use anchor_lang::prelude::*;
+
+/// Earnings under the previous kind are banked first. A salaried stream
+/// banks whole pay periods only, so time already spent in the current period
+/// is paid under the new kind.
+pub fn set_stream_kind(ctx: Context<SetStreamKind>, kind: StreamKind) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    kind.validate(stream.accrual_mode)?;
+    PaymentStream::validate_deductions(&stream.deductions, &kind, stream.hourly_rate)?;
+
+    stream.set_kind(kind, clock.unix_timestamp)?;
+
+    emit!(StreamKindUpdated {
+        stream: stream.key(),
+        kind,
+        accrued_amount: stream.accrued_amount,
+        accrual_started_at: stream.accrual_started_at,
+    });
+
+    Ok(())
+}
+
+/// Both parties must sign: the stream kind decides what the employee is
+/// paid.
+#[derive(Accounts)]
+pub struct SetStreamKind<'info> {
+    pub employer: Signer<'info>,
+
+    pub employee: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer,
+        has_one = employee @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
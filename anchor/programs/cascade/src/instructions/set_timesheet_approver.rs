@@ -0,0 +1,35 @@
+use crate::errors::ErrorCode;
+use crate::events::TimesheetApproverUpdated;
+use crate::state::PaymentStream;
+use anchor_lang::prelude::*;
+
+pub fn set_timesheet_approver(
+    ctx: Context<SetTimesheetApprover>,
+    approver: Option<Pubkey>,
+) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+
+    stream.timesheet_approver = approver;
+
+    emit!(TimesheetApproverUpdated {
+        stream: stream.key(),
+        approver,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetTimesheetApprover<'info> {
+    pub employer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
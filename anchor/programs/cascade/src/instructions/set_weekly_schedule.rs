@@ -0,0 +1,42 @@
+use crate::errors::ErrorCode;
+use crate::events::WeeklyScheduleUpdated;
+use crate::state::{AccrualMode, PaymentStream, WeeklySchedule};
+use anchor_lang::prelude::*;
+
+/// Replaces the working schedule. On schedule-mode streams, time accrued under
+/// the old schedule is banked first, so the change only affects time from now on.
+pub fn set_weekly_schedule(
+    ctx: Context<SetWeeklySchedule>,
+    weekly_schedule: WeeklySchedule,
+) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    weekly_schedule.validate()?;
+
+    if stream.accrual_mode == AccrualMode::Schedule {
+        stream.checkpoint_accrual(clock.unix_timestamp)?;
+    }
+    stream.weekly_schedule = weekly_schedule;
+
+    emit!(WeeklyScheduleUpdated {
+        stream: stream.key(),
+        weekly_schedule,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetWeeklySchedule<'info> {
+    pub employer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
@@ -0,0 +1,56 @@
+use crate::errors::ErrorCode;
+use crate::events::WithholdingRuleUpdated;
+use crate::state::{PaymentStream, WithholdingRule};
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+/// Sets or clears the tax withholding applied to every withdrawal. The rate
+/// is capped so withholding can never divert the majority of wages.
+pub fn set_withholding_rule(ctx: Context<SetWithholdingRule>, rate_bps: Option<u16>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    let rule = match rate_bps {
+        Some(rate_bps) => {
+            let destination = ctx
+                .accounts
+                .withholding_token_account
+                .as_ref()
+                .ok_or(ErrorCode::MissingPayoutAccount)?;
+            Some(WithholdingRule::new(
+                rate_bps,
+                destination.key(),
+                destination.mint,
+                stream.mint,
+            )?)
+        }
+        None => None,
+    };
+
+    stream.withholding = rule;
+
+    emit!(WithholdingRuleUpdated {
+        stream: stream.key(),
+        rate_bps: rule.map_or(0, |rule| rule.rate_bps),
+        destination: rule.map(|rule| rule.destination),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetWithholdingRule<'info> {
+    pub employer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    /// Receives the withheld amount. Only needed when setting a rule, and
+    /// must hold the stream's mint.
+    pub withholding_token_account: Option<Account<'info, TokenAccount>>,
+}
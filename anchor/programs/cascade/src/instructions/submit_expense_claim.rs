@@ -0,0 +1,78 @@
+use crate::errors::ErrorCode;
+use crate::events::{EmergencyWithdrawCancelled, ExpenseClaimSubmitted};
+use crate::state::{ExpenseClaim, ExpenseClaimStatus, PaymentStream};
+use anchor_lang::prelude::*;
+
+pub fn submit_expense_claim(
+    ctx: Context<SubmitExpenseClaim>,
+    amount: u64,
+    receipt_hash: [u8; 32],
+) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let expense_claim = &mut ctx.accounts.expense_claim;
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    require!(
+        !stream.awaiting_acceptance,
+        ErrorCode::StreamAwaitingAcceptance
+    );
+    require!(amount > 0, ErrorCode::InvalidExpenseClaim);
+
+    expense_claim.stream = stream.key();
+    expense_claim.index = stream.expense_claim_count;
+    expense_claim.amount = amount;
+    expense_claim.receipt_hash = receipt_hash;
+    expense_claim.status = ExpenseClaimStatus::Submitted;
+    expense_claim.submitted_at = clock.unix_timestamp;
+    expense_claim.reviewed_at = None;
+    expense_claim.paid_from = None;
+    expense_claim.bump = ctx.bumps.expense_claim;
+
+    stream.expense_claim_count = stream
+        .expense_claim_count
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    if let Some(announced_at) = stream.record_employee_activity(clock.unix_timestamp) {
+        emit!(EmergencyWithdrawCancelled {
+            stream: stream.key(),
+            announced_at,
+            cancelled_at: clock.unix_timestamp,
+        });
+    }
+
+    emit!(ExpenseClaimSubmitted {
+        stream: stream.key(),
+        expense_claim: expense_claim.key(),
+        index: expense_claim.index,
+        amount,
+        receipt_hash,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SubmitExpenseClaim<'info> {
+    #[account(mut)]
+    pub employee: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employee @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    #[account(
+        init,
+        payer = employee,
+        space = ExpenseClaim::DISCRIMINATOR.len() + ExpenseClaim::INIT_SPACE,
+        seeds = [b"expense", stream.key().as_ref(), stream.expense_claim_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub expense_claim: Account<'info, ExpenseClaim>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,62 @@
+use crate::errors::ErrorCode;
+use crate::events::{EmergencyWithdrawCancelled, MilestoneSubmitted};
+use crate::state::{Milestone, MilestoneStatus, PaymentStream};
+use anchor_lang::prelude::*;
+
+/// The contractor marks a funded milestone complete, starting the employer's
+/// review window.
+pub fn submit_milestone(ctx: Context<SubmitMilestone>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let milestone = &mut ctx.accounts.milestone;
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    require!(
+        !stream.awaiting_acceptance,
+        ErrorCode::StreamAwaitingAcceptance
+    );
+    require!(
+        milestone.status == MilestoneStatus::Funded,
+        ErrorCode::InvalidMilestoneStatus
+    );
+
+    milestone.status = MilestoneStatus::Submitted;
+    milestone.submitted_at = Some(clock.unix_timestamp);
+
+    if let Some(announced_at) = stream.record_employee_activity(clock.unix_timestamp) {
+        emit!(EmergencyWithdrawCancelled {
+            stream: stream.key(),
+            announced_at,
+            cancelled_at: clock.unix_timestamp,
+        });
+    }
+
+    emit!(MilestoneSubmitted {
+        stream: stream.key(),
+        milestone: milestone.key(),
+        submitted_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SubmitMilestone<'info> {
+    pub employee: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employee @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    #[account(
+        mut,
+        seeds = [b"milestone", stream.key().as_ref(), milestone.index.to_le_bytes().as_ref()],
+        bump = milestone.bump,
+        has_one = stream
+    )]
+    pub milestone: Account<'info, Milestone>,
+}
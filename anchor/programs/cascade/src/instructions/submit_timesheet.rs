@@ -0,0 +1,134 @@
+use crate::errors::ErrorCode;
+use crate::events::TimesheetSubmitted;
+use crate::state::{AccrualMode, PaymentStream, TimesheetPeriod, SECONDS_PER_MINUTE};
+use anchor_lang::prelude::*;
+
+/// Credits the stream with wages for `approved_minutes` worked between
+/// `period_start` and `period_end`. Periods are final once submitted.
+pub fn submit_timesheet(
+    ctx: Context<SubmitTimesheet>,
+    period_start: i64,
+    period_end: i64,
+    approved_minutes: u32,
+) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let timesheet = &mut ctx.accounts.timesheet;
+    let submitter = ctx.accounts.submitter.key();
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    require!(
+        !stream.awaiting_acceptance,
+        ErrorCode::StreamAwaitingAcceptance
+    );
+    require!(
+        stream.accrual_mode == AccrualMode::Timesheet,
+        ErrorCode::NotTimesheetStream
+    );
+    require!(
+        submitter == stream.employer || stream.timesheet_approver == Some(submitter),
+        ErrorCode::UnauthorizedTimesheetSubmitter
+    );
+    assert_valid_timesheet_period(
+        period_start,
+        period_end,
+        approved_minutes,
+        stream
+            .last_timesheet_period_end
+            .max(stream.accrual_started_at),
+        clock.unix_timestamp,
+    )?;
+
+    let earned_amount = stream.wages_for_minutes(approved_minutes)?;
+    stream.accrued_amount = stream
+        .accrued_amount
+        .checked_add(earned_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    stream.last_timesheet_period_end = period_end;
+
+    timesheet.stream = stream.key();
+    timesheet.period_start = period_start;
+    timesheet.period_end = period_end;
+    timesheet.approved_minutes = approved_minutes;
+    timesheet.earned_amount = earned_amount;
+    timesheet.submitted_by = submitter;
+    timesheet.submitted_at = clock.unix_timestamp;
+    timesheet.bump = ctx.bumps.timesheet;
+
+    emit!(TimesheetSubmitted {
+        stream: stream.key(),
+        timesheet: timesheet.key(),
+        period_start,
+        period_end,
+        approved_minutes,
+        earned_amount,
+    });
+
+    Ok(())
+}
+
+fn assert_valid_timesheet_period(
+    period_start: i64,
+    period_end: i64,
+    approved_minutes: u32,
+    earliest_start: i64,
+    current_timestamp: i64,
+) -> Result<()> {
+    require!(
+        period_start >= earliest_start
+            && period_start < period_end
+            && period_end <= current_timestamp,
+        ErrorCode::InvalidTimesheetPeriod
+    );
+    let period_minutes = (period_end - period_start) / SECONDS_PER_MINUTE;
+    require!(
+        i64::from(approved_minutes) <= period_minutes,
+        ErrorCode::InvalidTimesheetPeriod
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(period_start: i64)]
+pub struct SubmitTimesheet<'info> {
+    /// The employer or the stream's timesheet approver.
+    pub submitter: Signer<'info>,
+
+    /// Pays rent for the timesheet. Separate from `submitter` so PDA
+    /// employers can submit timesheets.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = TimesheetPeriod::DISCRIMINATOR.len() + TimesheetPeriod::INIT_SPACE,
+        seeds = [b"timesheet", stream.key().as_ref(), period_start.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub timesheet: Account<'info, TimesheetPeriod>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::assert_valid_timesheet_period;
+
+    #[test]
+    fn rejects_overlapping_future_or_overfull_periods() {
+        let week = 7 * 24 * 60 * 60;
+
+        assert!(assert_valid_timesheet_period(week, 2 * week, 40 * 60, week, 2 * week).is_ok());
+        assert!(assert_valid_timesheet_period(week - 1, 2 * week, 60, week, 2 * week).is_err());
+        assert!(assert_valid_timesheet_period(week, 2 * week, 60, week, 2 * week - 1).is_err());
+        assert!(assert_valid_timesheet_period(week, week + 3_600, 61, week, 2 * week).is_err());
+    }
+}
@@ -1,32 +1,46 @@
+use crate::deposit::{charge_deposit_fee, EmployerDeposit};
 use crate::errors::ErrorCode;
-use crate::state::PaymentStream;
+use crate::state::{PaymentStream, ProgramConfig, PAUSE_TOP_UP_STREAM};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, TransferChecked};
+use anchor_spl::token::{self, Token, TokenAccount};
 
 pub fn top_up_stream(ctx: Context<TopUpStream>, additional_amount: u64) -> Result<()> {
     let stream = &mut ctx.accounts.stream;
 
     require!(stream.is_active, ErrorCode::StreamInactive);
+    ctx.accounts.config.assert_not_paused(PAUSE_TOP_UP_STREAM)?;
     require!(
         ctx.accounts.employer.key() == stream.employer,
         ErrorCode::UnauthorizedEmployer
     );
     stream.assert_accounting_invariant()?;
 
-    // Transfer additional USDC from employer to vault
-    let cpi_accounts = TransferChecked {
-        mint: ctx.accounts.mint.to_account_info(),
-        from: ctx.accounts.employer_token_account.to_account_info(),
-        to: ctx.accounts.vault.to_account_info(),
-        authority: ctx.accounts.employer.to_account_info(),
+    // The protocol fee comes out of the top-up and never reaches the vault.
+    let deposit = EmployerDeposit {
+        mint: &ctx.accounts.mint,
+        from: &ctx.accounts.employer_token_account,
+        authority: &ctx.accounts.employer,
+        token_program: &ctx.accounts.token_program,
     };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    token::transfer_checked(cpi_ctx, additional_amount, ctx.accounts.mint.decimals)?;
+    let protocol_fee = charge_deposit_fee(
+        &ctx.accounts.config,
+        ctx.accounts.fee_vault.as_ref(),
+        &deposit,
+        stream.key(),
+        additional_amount,
+    )?;
+    let net_amount = additional_amount
+        .checked_sub(protocol_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+    deposit.transfer(ctx.accounts.vault.to_account_info(), net_amount)?;
 
     stream.total_deposited = stream
         .total_deposited
-        .checked_add(additional_amount)
+        .checked_add(net_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    stream.total_protocol_fees = stream
+        .total_protocol_fees
+        .checked_add(protocol_fee)
         .ok_or(ErrorCode::MathOverflow)?;
     stream.assert_accounting_invariant()?;
 
@@ -40,7 +54,7 @@ pub struct TopUpStream<'info> {
 
     #[account(
         mut,
-        seeds = [b"stream", employer.key().as_ref(), stream.employee.as_ref()],
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
         bump = stream.bump,
         has_one = vault,
         has_one = mint
@@ -49,6 +63,9 @@ pub struct TopUpStream<'info> {
 
     pub mint: Account<'info, token::Mint>,
 
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProgramConfig>,
+
     #[account(mut)]
     pub vault: Account<'info, TokenAccount>,
 
@@ -59,5 +76,13 @@ pub struct TopUpStream<'info> {
     )]
     pub employer_token_account: Account<'info, TokenAccount>,
 
+    /// The mint's fee vault. Required whenever a non-zero protocol fee applies.
+    #[account(
+        mut,
+        seeds = [b"fee_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: Option<Account<'info, TokenAccount>>,
+
     pub token_program: Program<'info, Token>,
 }
@@ -0,0 +1,33 @@
+use crate::errors::ErrorCode;
+use crate::events::ConfigGuardianUpdated;
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+
+pub fn update_config_guardian(
+    ctx: Context<UpdateConfigGuardian>,
+    new_guardian: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let previous_guardian = config.guardian;
+    config.guardian = new_guardian;
+
+    emit!(ConfigGuardianUpdated {
+        previous_guardian,
+        guardian: new_guardian,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfigGuardian<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,43 @@
+use crate::errors::ErrorCode;
+use crate::events::InactivityThresholdUpdated;
+use crate::state::PaymentStream;
+use anchor_lang::prelude::*;
+
+pub fn update_inactivity_threshold(
+    ctx: Context<UpdateInactivityThreshold>,
+    inactivity_threshold_seconds: i64,
+) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    PaymentStream::validate_inactivity_threshold(inactivity_threshold_seconds)?;
+
+    let previous_inactivity_threshold_seconds = stream.inactivity_threshold_seconds;
+    stream.inactivity_threshold_seconds = inactivity_threshold_seconds;
+
+    emit!(InactivityThresholdUpdated {
+        stream: stream.key(),
+        previous_inactivity_threshold_seconds,
+        inactivity_threshold_seconds,
+    });
+
+    Ok(())
+}
+
+/// Both parties must sign: the threshold decides when the employer may claw
+/// back unvested funds, so neither side can move it unilaterally.
+#[derive(Accounts)]
+pub struct UpdateInactivityThreshold<'info> {
+    pub employer: Signer<'info>,
+
+    pub employee: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer,
+        has_one = employee @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub stream: Account<'info, PaymentStream>,
+}
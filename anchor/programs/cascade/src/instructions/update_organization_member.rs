@@ -0,0 +1,115 @@
+use crate::errors::ErrorCode;
+use crate::events::OrganizationMemberUpdated;
+use crate::state::{Organization, OrganizationMember, ALL_ROLES, ROLE_ADMIN};
+use anchor_lang::prelude::*;
+
+/// Applies an admin-count change when a member gains or loses the admin role.
+/// Organizations must always keep at least one admin.
+pub(crate) fn apply_admin_role_change(
+    organization: &mut Organization,
+    previous_roles: u8,
+    roles: u8,
+) -> Result<()> {
+    let was_admin = previous_roles & ROLE_ADMIN != 0;
+    let is_admin = roles & ROLE_ADMIN != 0;
+
+    organization.admin_count = match (was_admin, is_admin) {
+        (false, true) => organization
+            .admin_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?,
+        (true, false) => organization
+            .admin_count
+            .checked_sub(1)
+            .ok_or(ErrorCode::MathOverflow)?,
+        _ => organization.admin_count,
+    };
+    require!(
+        organization.admin_count > 0,
+        ErrorCode::LastOrganizationAdmin
+    );
+    Ok(())
+}
+
+pub fn update_organization_member(ctx: Context<UpdateOrganizationMember>, roles: u8) -> Result<()> {
+    let organization = &mut ctx.accounts.organization;
+    let member = &mut ctx.accounts.member;
+
+    require!(
+        ctx.accounts.admin_membership.has_role(ROLE_ADMIN),
+        ErrorCode::MissingOrganizationRole
+    );
+    require!(roles & !ALL_ROLES == 0, ErrorCode::InvalidOrganizationRoles);
+
+    apply_admin_role_change(organization, member.roles, roles)?;
+    member.roles = roles;
+
+    emit!(OrganizationMemberUpdated {
+        organization: organization.key(),
+        wallet: member.wallet,
+        roles,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateOrganizationMember<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"organization", organization.creator.as_ref()],
+        bump = organization.bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        seeds = [b"member", organization.key().as_ref(), admin.key().as_ref()],
+        bump = admin_membership.bump
+    )]
+    pub admin_membership: Account<'info, OrganizationMember>,
+
+    #[account(
+        mut,
+        seeds = [b"member", organization.key().as_ref(), member.wallet.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, OrganizationMember>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::apply_admin_role_change;
+    use crate::state::{Organization, ROLE_ADMIN, ROLE_HR_MANAGER};
+    use anchor_lang::prelude::Pubkey;
+
+    fn organization_with_admins(admin_count: u16) -> Organization {
+        Organization {
+            creator: Pubkey::new_unique(),
+            admin_count,
+            created_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn tracks_admin_count_across_role_changes() {
+        let mut organization = organization_with_admins(1);
+
+        apply_admin_role_change(&mut organization, ROLE_HR_MANAGER, ROLE_ADMIN)
+            .expect("promotion should succeed");
+        assert_eq!(organization.admin_count, 2);
+
+        apply_admin_role_change(&mut organization, ROLE_ADMIN, ROLE_HR_MANAGER)
+            .expect("demotion should succeed");
+        assert_eq!(organization.admin_count, 1);
+    }
+
+    #[test]
+    fn refuses_to_remove_the_last_admin() {
+        let mut organization = organization_with_admins(1);
+
+        assert!(apply_admin_role_change(&mut organization, ROLE_ADMIN, 0).is_err());
+    }
+}
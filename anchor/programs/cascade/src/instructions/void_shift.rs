@@ -0,0 +1,76 @@
+use crate::errors::ErrorCode;
+use crate::events::ShiftVoided;
+use crate::state::{PaymentStream, ShiftRecord};
+use anchor_lang::prelude::*;
+
+/// Removes a disputed shift's time and premiums from the stream and closes its
+/// record, returning the rent to whoever paid it. Only closed shifts still in
+/// their review window that have not been banked by an accrual-mode change can
+/// be voided. If the employee already withdrew the wages, they become an
+/// outstanding advance repaid from future accrual.
+pub fn void_shift(ctx: Context<VoidShift>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    let shift = &ctx.accounts.shift;
+    let clock = Clock::get()?;
+
+    require!(stream.is_active, ErrorCode::StreamInactive);
+    require!(
+        shift.clocked_out_at.is_some()
+            && !shift.is_final(clock.unix_timestamp)
+            && shift.clocked_in_at >= stream.accrual_started_at,
+        ErrorCode::ShiftNotVoidable
+    );
+
+    stream.shift_seconds = stream
+        .shift_seconds
+        .checked_sub(shift.duration_seconds)
+        .ok_or(ErrorCode::ShiftNotVoidable)?;
+    stream.shift_premium_amount = stream
+        .shift_premium_amount
+        .checked_sub(shift.premium_amount)
+        .ok_or(ErrorCode::ShiftNotVoidable)?;
+    stream.total_premium_earned = stream
+        .total_premium_earned
+        .saturating_sub(shift.premium_amount);
+    // Later shifts in the same week keep the overtime they were priced with.
+    if stream.pay_multipliers.is_some() && shift.workweek == stream.overtime_workweek {
+        stream.workweek_seconds = stream
+            .workweek_seconds
+            .saturating_sub(shift.duration_seconds);
+    }
+
+    emit!(ShiftVoided {
+        stream: stream.key(),
+        shift: shift.key(),
+        duration_seconds: shift.duration_seconds,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct VoidShift<'info> {
+    pub employer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
+        bump = stream.bump,
+        has_one = employer @ ErrorCode::UnauthorizedEmployer
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"shift", stream.key().as_ref(), shift.index.to_le_bytes().as_ref()],
+        bump = shift.bump,
+        has_one = stream,
+        has_one = payer
+    )]
+    pub shift: Account<'info, ShiftRecord>,
+
+    /// CHECK: Receives the shift record rent; validated by `has_one` on the shift.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
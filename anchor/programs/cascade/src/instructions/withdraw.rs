@@ -1,45 +1,126 @@
 use crate::errors::ErrorCode;
-use crate::state::PaymentStream;
+use crate::events::{
+    AdvanceDrawn, AdvanceRepaid, DeductionPaid, EmergencyWithdrawCancelled, GarnishmentOrderClosed,
+    ProtocolFeeCharged, WithdrawalDistributed,
+};
+use crate::payout::{find_payout_account, plan_withdrawal};
+use crate::state::{
+    GarnishmentOrder, PaymentStream, ProgramConfig, BASIS_POINTS_DENOMINATOR, PAUSE_WITHDRAW,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, TransferChecked};
 
-const SECONDS_PER_HOUR: i64 = 60 * 60;
+/// Withholding, garnishment, deduction and split destinations are passed as
+/// writable remaining accounts.
+pub fn withdraw<'info>(
+    ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
+    amount: u64,
+) -> Result<()> {
+    process_withdrawal(ctx, amount, false)
+}
 
-pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+/// Pays out `amount` through the full payout pipeline. Advances may draw up to
+/// `advance_limit` beyond accrued wages; plain withdrawals only what accrued.
+pub(crate) fn process_withdrawal<'info>(
+    ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
+    amount: u64,
+    is_advance: bool,
+) -> Result<()> {
     let stream = &mut ctx.accounts.stream;
     let clock = Clock::get()?;
 
     require!(stream.is_active, ErrorCode::StreamInactive);
+    require!(
+        !stream.awaiting_acceptance,
+        ErrorCode::StreamAwaitingAcceptance
+    );
+    ctx.accounts.config.assert_not_paused(PAUSE_WITHDRAW)?;
     require!(
         ctx.accounts.employee.key() == stream.employee,
         ErrorCode::UnauthorizedEmployee
     );
     stream.assert_accounting_invariant()?;
 
-    // Calculate total earned based on time elapsed
-    let seconds_elapsed = clock
-        .unix_timestamp
-        .checked_sub(stream.created_at)
-        .ok_or(ErrorCode::InvalidTimestamp)?;
+    // An active garnishment order takes priority, so it cannot be skipped by
+    // leaving its account out.
+    let garnishment_order = &mut ctx.accounts.garnishment_order;
+    if let Some(active_garnishment) = stream.active_garnishment {
+        require!(
+            garnishment_order
+                .as_ref()
+                .is_some_and(|order| order.key() == active_garnishment),
+            ErrorCode::MissingGarnishmentOrder
+        );
+    }
+    let garnishment_order = garnishment_order
+        .as_mut()
+        .filter(|order| stream.active_garnishment == Some(order.key()));
 
-    let hours_elapsed = seconds_elapsed / SECONDS_PER_HOUR;
-    let total_earned_uncapped = (hours_elapsed as u64)
-        .checked_mul(stream.hourly_rate)
-        .ok_or(ErrorCode::MathOverflow)?;
+    // A configured payout destination replaces any employee-owned account.
+    match stream.apply_pending_payout_destination(clock.unix_timestamp) {
+        Some(payout_destination) => require_keys_eq!(
+            ctx.accounts.employee_token_account.key(),
+            payout_destination,
+            ErrorCode::InvalidTokenAccount
+        ),
+        None => require_keys_eq!(
+            ctx.accounts.employee_token_account.owner,
+            stream.employee,
+            ErrorCode::InvalidTokenAccount
+        ),
+    }
 
-    // Cap earned amount at total deposited
-    let total_earned = std::cmp::min(total_earned_uncapped, stream.total_deposited);
+    // Accrued wages first repay any outstanding advance, then become available.
+    // Vault-paid reimbursements have already left the vault.
+    let wage_funding = stream
+        .wage_funding()?
+        .checked_sub(stream.expenses_paid_from_vault)
+        .ok_or(ErrorCode::InvalidStreamAccounting)?;
+    let total_earned = stream.earned_amount(clock.unix_timestamp)?;
+    let advance_repaid = stream.settle_outstanding_advance(total_earned);
+    let outstanding_advance_before = stream.outstanding_advance;
 
-    // Calculate available balance
-    let available_balance = total_earned
+    let withdrawal_ceiling = if is_advance {
+        total_earned
+            .checked_add(stream.advance_limit)
+            .ok_or(ErrorCode::MathOverflow)?
+            .min(wage_funding)
+    } else {
+        total_earned
+    };
+    let available_balance = withdrawal_ceiling
         .checked_sub(stream.withdrawn_amount)
         .ok_or(ErrorCode::InsufficientBalance)?;
 
     require!(amount <= available_balance, ErrorCode::InsufficientBalance);
 
+    // Every share is rounded down and the remainder goes to the primary
+    // destination, so the transfers sum to exactly `amount`.
+    // Employer match may only spend funding that is not yet earned, so it can
+    // never eat into wages the employee is already owed.
+    stream.apply_pending_payout_splits(clock.unix_timestamp);
+    let withdrawn_after = stream
+        .withdrawn_amount
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let employer_match_budget = wage_funding
+        .checked_sub(total_earned.max(withdrawn_after))
+        .ok_or(ErrorCode::MathOverflow)?;
+    // The protocol fee comes off the top; everything else is planned on the
+    // remainder.
+    let protocol_fee = ctx.accounts.config.fee_schedule.withdraw_fee(amount)?;
+    let plan = plan_withdrawal(
+        amount
+            .checked_sub(protocol_fee)
+            .ok_or(ErrorCode::MathOverflow)?,
+        stream,
+        garnishment_order.as_deref().map(|order| &**order),
+        employer_match_budget,
+    )?;
+
     // Transfer tokens from vault to employee using PDA signer
-    let employer_key = stream.employer.key();
-    let employee_key = stream.employee.key();
+    let employer_key = stream.original_employer;
+    let employee_key = stream.original_employee;
     let seeds = &[
         b"stream",
         employer_key.as_ref(),
@@ -48,24 +129,151 @@ pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
     ];
     let signer = &[&seeds[..]];
 
-    let cpi_accounts = TransferChecked {
-        mint: ctx.accounts.mint.to_account_info(),
-        from: ctx.accounts.vault.to_account_info(),
-        to: ctx.accounts.employee_token_account.to_account_info(),
-        authority: stream.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer);
-    token::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+    if protocol_fee > 0 {
+        let fee_vault = ctx
+            .accounts
+            .fee_vault
+            .as_ref()
+            .ok_or(ErrorCode::MissingFeeVault)?;
+        let cpi_accounts = TransferChecked {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.vault.to_account_info(),
+            to: fee_vault.to_account_info(),
+            authority: stream.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer);
+        token::transfer_checked(cpi_ctx, protocol_fee, ctx.accounts.mint.decimals)?;
+    }
+
+    for payout_transfer in &plan.transfers {
+        let cpi_accounts = TransferChecked {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.vault.to_account_info(),
+            to: find_payout_account(ctx.remaining_accounts, &payout_transfer.destination)?,
+            authority: stream.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer);
+        token::transfer_checked(cpi_ctx, payout_transfer.amount, ctx.accounts.mint.decimals)?;
+    }
+
+    if plan.employee_amount > 0 {
+        let cpi_accounts = TransferChecked {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.vault.to_account_info(),
+            to: ctx.accounts.employee_token_account.to_account_info(),
+            authority: stream.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer);
+        token::transfer_checked(cpi_ctx, plan.employee_amount, ctx.accounts.mint.decimals)?;
+    }
 
     // Update stream state
-    stream.withdrawn_amount = stream
-        .withdrawn_amount
-        .checked_add(amount)
+    stream.withdrawn_amount = withdrawn_after;
+    stream.settle_outstanding_advance(total_earned);
+    let advanced_amount = stream
+        .outstanding_advance
+        .saturating_sub(outstanding_advance_before);
+    stream.total_advanced = stream
+        .total_advanced
+        .checked_add(advanced_amount)
         .ok_or(ErrorCode::MathOverflow)?;
-    stream.employee_last_activity_at = clock.unix_timestamp;
+    stream.total_protocol_fees = stream
+        .total_protocol_fees
+        .checked_add(protocol_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+    stream.record_withholding(plan.withheld_amount, clock.unix_timestamp)?;
+    stream.total_garnished = stream
+        .total_garnished
+        .checked_add(plan.garnished_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    if let Some(order) = garnishment_order {
+        order.paid_amount = order
+            .paid_amount
+            .checked_add(plan.garnished_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        if order.is_satisfied() {
+            order.closed_at = Some(clock.unix_timestamp);
+            stream.active_garnishment = None;
+            emit!(GarnishmentOrderClosed {
+                stream: stream.key(),
+                garnishment_order: order.key(),
+                paid_amount: order.paid_amount,
+                satisfied: true,
+            });
+        }
+    }
+    stream.total_deducted = stream
+        .total_deducted
+        .checked_add(plan.deducted_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    stream.total_employer_matched = stream
+        .total_employer_matched
+        .checked_add(plan.employer_match_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    if let Some(announced_at) = stream.record_employee_activity(clock.unix_timestamp) {
+        emit!(EmergencyWithdrawCancelled {
+            stream: stream.key(),
+            announced_at,
+            cancelled_at: clock.unix_timestamp,
+        });
+    }
     stream.assert_accounting_invariant()?;
 
+    if advance_repaid > 0 {
+        emit!(AdvanceRepaid {
+            stream: stream.key(),
+            amount: advance_repaid,
+            outstanding_advance: outstanding_advance_before,
+        });
+    }
+    if advanced_amount > 0 {
+        emit!(AdvanceDrawn {
+            stream: stream.key(),
+            amount: advanced_amount,
+            outstanding_advance: stream.outstanding_advance,
+        });
+    }
+    if protocol_fee > 0 {
+        emit!(ProtocolFeeCharged {
+            stream: stream.key(),
+            mint: stream.mint,
+            amount: protocol_fee,
+        });
+    }
+    for deduction in &plan.deductions {
+        emit!(DeductionPaid {
+            stream: stream.key(),
+            destination: deduction.destination,
+            deducted_amount: deduction.deducted_amount,
+            employer_match_amount: deduction.employer_match_amount,
+        });
+    }
+    emit!(WithdrawalDistributed {
+        stream: stream.key(),
+        amount,
+        protocol_fee,
+        withheld_amount: plan.withheld_amount,
+        garnished_amount: plan.garnished_amount,
+        deducted_amount: plan.deducted_amount,
+        employer_match_amount: plan.employer_match_amount,
+        split_amount: plan.split_amount,
+        employee_amount: plan.employee_amount,
+        overtime_multiplier_bps: stream
+            .pay_multipliers
+            .map_or(BASIS_POINTS_DENOMINATOR, |multipliers| {
+                multipliers.overtime_multiplier_bps
+            }),
+        night_multiplier_bps: stream
+            .pay_multipliers
+            .map_or(BASIS_POINTS_DENOMINATOR, |multipliers| {
+                multipliers.night_multiplier_bps
+            }),
+        total_premium_earned: stream.total_premium_earned,
+    });
+
     Ok(())
 }
 
@@ -76,7 +284,7 @@ pub struct Withdraw<'info> {
 
     #[account(
         mut,
-        seeds = [b"stream", stream.employer.as_ref(), employee.key().as_ref()],
+        seeds = [b"stream", stream.original_employer.as_ref(), stream.original_employee.as_ref()],
         bump = stream.bump,
         has_one = vault,
         has_one = mint
@@ -85,15 +293,40 @@ pub struct Withdraw<'info> {
 
     pub mint: Account<'info, token::Mint>,
 
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProgramConfig>,
+
     #[account(mut)]
     pub vault: Account<'info, TokenAccount>,
 
+    /// The stream's payout destination if one is configured, otherwise any
+    /// token account owned by the employee.
     #[account(
         mut,
-        constraint = employee_token_account.owner == employee.key() @ ErrorCode::InvalidTokenAccount,
         constraint = employee_token_account.mint == stream.mint @ ErrorCode::InvalidTokenAccount
     )]
     pub employee_token_account: Account<'info, TokenAccount>,
 
+    /// Required when the stream has an active garnishment order.
+    #[account(
+        mut,
+        seeds = [
+            b"garnishment",
+            stream.key().as_ref(),
+            garnishment_order.index.to_le_bytes().as_ref()
+        ],
+        bump = garnishment_order.bump,
+        has_one = stream
+    )]
+    pub garnishment_order: Option<Account<'info, GarnishmentOrder>>,
+
+    /// The mint's fee vault. Required whenever a non-zero protocol fee applies.
+    #[account(
+        mut,
+        seeds = [b"fee_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: Option<Account<'info, TokenAccount>>,
+
     pub token_program: Program<'info, Token>,
 }
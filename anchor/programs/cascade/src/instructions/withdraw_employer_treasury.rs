@@ -0,0 +1,58 @@
+use crate::errors::ErrorCode;
+use crate::events::EmployerTreasuryWithdrawn;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, TransferChecked};
+
+/// Moves tokens out of a token account owned by the employer. Refunds from
+/// closed streams, emergency withdrawals and cancelled milestones land in such
+/// an account, and a PDA employer can only spend it through this instruction.
+pub fn withdraw_employer_treasury(
+    ctx: Context<WithdrawEmployerTreasury>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        amount <= ctx.accounts.employer_token_account.amount,
+        ErrorCode::InsufficientBalance
+    );
+
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.employer_token_account.to_account_info(),
+        to: ctx.accounts.destination_token_account.to_account_info(),
+        authority: ctx.accounts.employer.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    emit!(EmployerTreasuryWithdrawn {
+        employer: ctx.accounts.employer.key(),
+        source: ctx.accounts.employer_token_account.key(),
+        destination: ctx.accounts.destination_token_account.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawEmployerTreasury<'info> {
+    pub employer: Signer<'info>,
+
+    pub mint: Account<'info, token::Mint>,
+
+    #[account(
+        mut,
+        constraint = employer_token_account.owner == employer.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = employer_token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount
+    )]
+    pub employer_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = destination_token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
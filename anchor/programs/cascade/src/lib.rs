@@ -1,10 +1,18 @@
 use anchor_lang::prelude::*;
 
+pub mod deposit;
+pub mod employer_authority;
 pub mod errors;
+pub mod events;
 pub mod instructions;
+pub mod payout;
 pub mod state;
 
 use instructions::*;
+use state::{
+    AccrualMode, BonusSource, Deduction, ExpensePaymentSource, FeeSchedule, PayMultipliers,
+    PayoutSplit, StreamKind, WeeklySchedule,
+};
 
 declare_id!("FiE8MasF8sQEsruhk5FGxwR25DvQDS4nfji3h2bvVRoi");
 
@@ -16,27 +24,385 @@ pub mod cascade {
         ctx: Context<CreateStream>,
         hourly_rate: u64,
         total_deposit: u64,
+        inactivity_threshold_seconds: i64,
+        require_acceptance: bool,
+        kind: StreamKind,
     ) -> Result<()> {
-        instructions::create_stream::create_stream(ctx, hourly_rate, total_deposit)
+        instructions::create_stream::create_stream(
+            ctx,
+            hourly_rate,
+            total_deposit,
+            inactivity_threshold_seconds,
+            require_acceptance,
+            kind,
+        )
     }
 
-    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+    pub fn accept_stream(ctx: Context<AcceptStream>) -> Result<()> {
+        instructions::accept_stream::accept_stream(ctx)
+    }
+
+    pub fn withdraw<'info>(
+        ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
+        amount: u64,
+    ) -> Result<()> {
         instructions::withdraw::withdraw(ctx, amount)
     }
 
+    pub fn advance<'info>(
+        ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::advance::advance(ctx, amount)
+    }
+
+    pub fn set_advance_limit(ctx: Context<SetAdvanceLimit>, advance_limit: u64) -> Result<()> {
+        instructions::set_advance_limit::set_advance_limit(ctx, advance_limit)
+    }
+
+    pub fn set_accrual_mode(ctx: Context<SetAccrualMode>, accrual_mode: AccrualMode) -> Result<()> {
+        instructions::set_accrual_mode::set_accrual_mode(ctx, accrual_mode)
+    }
+
+    pub fn set_stream_kind(ctx: Context<SetStreamKind>, kind: StreamKind) -> Result<()> {
+        instructions::set_stream_kind::set_stream_kind(ctx, kind)
+    }
+
+    pub fn set_timesheet_approver(
+        ctx: Context<SetTimesheetApprover>,
+        approver: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_timesheet_approver::set_timesheet_approver(ctx, approver)
+    }
+
+    pub fn submit_timesheet(
+        ctx: Context<SubmitTimesheet>,
+        period_start: i64,
+        period_end: i64,
+        approved_minutes: u32,
+    ) -> Result<()> {
+        instructions::submit_timesheet::submit_timesheet(
+            ctx,
+            period_start,
+            period_end,
+            approved_minutes,
+        )
+    }
+
+    pub fn set_weekly_schedule(
+        ctx: Context<SetWeeklySchedule>,
+        weekly_schedule: WeeklySchedule,
+    ) -> Result<()> {
+        instructions::set_weekly_schedule::set_weekly_schedule(ctx, weekly_schedule)
+    }
+
+    pub fn set_max_shift_length(
+        ctx: Context<SetMaxShiftLength>,
+        max_shift_seconds: i64,
+    ) -> Result<()> {
+        instructions::set_max_shift_length::set_max_shift_length(ctx, max_shift_seconds)
+    }
+
+    pub fn set_pay_multipliers(
+        ctx: Context<SetPayMultipliers>,
+        pay_multipliers: Option<PayMultipliers>,
+    ) -> Result<()> {
+        instructions::set_pay_multipliers::set_pay_multipliers(ctx, pay_multipliers)
+    }
+
+    pub fn clock_in(ctx: Context<ClockIn>) -> Result<()> {
+        instructions::clock_in::clock_in(ctx)
+    }
+
+    pub fn clock_out(ctx: Context<ClockOut>) -> Result<()> {
+        instructions::clock_out::clock_out(ctx)
+    }
+
+    pub fn void_shift(ctx: Context<VoidShift>) -> Result<()> {
+        instructions::void_shift::void_shift(ctx)
+    }
+
+    pub fn close_shift_record(ctx: Context<CloseShiftRecord>) -> Result<()> {
+        instructions::close_shift_record::close_shift_record(ctx)
+    }
+
     pub fn refresh_activity(ctx: Context<RefreshActivity>) -> Result<()> {
         instructions::refresh_activity::refresh_activity(ctx)
     }
 
-    pub fn employer_emergency_withdraw(ctx: Context<EmployerEmergencyWithdraw>) -> Result<()> {
-        instructions::employer_emergency_withdraw::employer_emergency_withdraw(ctx)
+    pub fn announce_emergency_withdraw(ctx: Context<AnnounceEmergencyWithdraw>) -> Result<()> {
+        instructions::announce_emergency_withdraw::announce_emergency_withdraw(ctx)
+    }
+
+    pub fn execute_emergency_withdraw(ctx: Context<ExecuteEmergencyWithdraw>) -> Result<()> {
+        instructions::execute_emergency_withdraw::execute_emergency_withdraw(ctx)
     }
 
     pub fn top_up_stream(ctx: Context<TopUpStream>, additional_amount: u64) -> Result<()> {
         instructions::top_up_stream::top_up_stream(ctx, additional_amount)
     }
 
+    pub fn award_bonus(ctx: Context<AwardBonus>, amount: u64, source: BonusSource) -> Result<()> {
+        instructions::award_bonus::award_bonus(ctx, amount, source)
+    }
+
+    pub fn fund_milestone(
+        ctx: Context<FundMilestone>,
+        amount: u64,
+        description_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::fund_milestone::fund_milestone(ctx, amount, description_hash)
+    }
+
+    pub fn submit_milestone(ctx: Context<SubmitMilestone>) -> Result<()> {
+        instructions::submit_milestone::submit_milestone(ctx)
+    }
+
+    pub fn approve_milestone(ctx: Context<ApproveMilestone>) -> Result<()> {
+        instructions::approve_milestone::approve_milestone(ctx)
+    }
+
+    pub fn reject_milestone(ctx: Context<RejectMilestone>) -> Result<()> {
+        instructions::reject_milestone::reject_milestone(ctx)
+    }
+
+    pub fn cancel_milestone(ctx: Context<CancelMilestone>) -> Result<()> {
+        instructions::cancel_milestone::cancel_milestone(ctx)
+    }
+
+    pub fn submit_expense_claim(
+        ctx: Context<SubmitExpenseClaim>,
+        amount: u64,
+        receipt_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::submit_expense_claim::submit_expense_claim(ctx, amount, receipt_hash)
+    }
+
+    pub fn approve_expense_claim(
+        ctx: Context<ApproveExpenseClaim>,
+        source: ExpensePaymentSource,
+    ) -> Result<()> {
+        instructions::approve_expense_claim::approve_expense_claim(ctx, source)
+    }
+
+    pub fn reject_expense_claim(ctx: Context<RejectExpenseClaim>) -> Result<()> {
+        instructions::reject_expense_claim::reject_expense_claim(ctx)
+    }
+
     pub fn close_stream(ctx: Context<CloseStream>) -> Result<()> {
         instructions::close_stream::close_stream(ctx)
     }
+
+    pub fn withdraw_employer_treasury(
+        ctx: Context<WithdrawEmployerTreasury>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_employer_treasury::withdraw_employer_treasury(ctx, amount)
+    }
+
+    pub fn update_inactivity_threshold(
+        ctx: Context<UpdateInactivityThreshold>,
+        inactivity_threshold_seconds: i64,
+    ) -> Result<()> {
+        instructions::update_inactivity_threshold::update_inactivity_threshold(
+            ctx,
+            inactivity_threshold_seconds,
+        )
+    }
+
+    pub fn propose_employer_transfer(
+        ctx: Context<ProposeEmployerTransfer>,
+        new_employer: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_employer_transfer::propose_employer_transfer(ctx, new_employer)
+    }
+
+    pub fn accept_employer_transfer(ctx: Context<AcceptEmployerTransfer>) -> Result<()> {
+        instructions::accept_employer_transfer::accept_employer_transfer(ctx)
+    }
+
+    pub fn set_payout_destination(ctx: Context<SetPayoutDestination>) -> Result<()> {
+        instructions::set_payout_destination::set_payout_destination(ctx)
+    }
+
+    pub fn set_payout_splits(
+        ctx: Context<SetPayoutSplits>,
+        splits: Vec<PayoutSplit>,
+    ) -> Result<()> {
+        instructions::set_payout_splits::set_payout_splits(ctx, splits)
+    }
+
+    pub fn set_withholding_rule(
+        ctx: Context<SetWithholdingRule>,
+        rate_bps: Option<u16>,
+    ) -> Result<()> {
+        instructions::set_withholding_rule::set_withholding_rule(ctx, rate_bps)
+    }
+
+    pub fn set_deductions(ctx: Context<SetDeductions>, deductions: Vec<Deduction>) -> Result<()> {
+        instructions::set_deductions::set_deductions(ctx, deductions)
+    }
+
+    pub fn set_garnishment_issuer(
+        ctx: Context<SetGarnishmentIssuer>,
+        issuer: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_garnishment_issuer::set_garnishment_issuer(ctx, issuer)
+    }
+
+    pub fn issue_garnishment_order(
+        ctx: Context<IssueGarnishmentOrder>,
+        rate_bps: u16,
+        ordered_amount: u64,
+    ) -> Result<()> {
+        instructions::issue_garnishment_order::issue_garnishment_order(
+            ctx,
+            rate_bps,
+            ordered_amount,
+        )
+    }
+
+    pub fn release_garnishment_order(ctx: Context<ReleaseGarnishmentOrder>) -> Result<()> {
+        instructions::release_garnishment_order::release_garnishment_order(ctx)
+    }
+
+    pub fn register_session_key(
+        ctx: Context<RegisterSessionKey>,
+        session_key: Pubkey,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::register_session_key::register_session_key(ctx, session_key, expires_at)
+    }
+
+    pub fn revoke_session_key(ctx: Context<RevokeSessionKey>) -> Result<()> {
+        instructions::revoke_session_key::revoke_session_key(ctx)
+    }
+
+    pub fn rotate_employee(ctx: Context<RotateEmployee>) -> Result<()> {
+        instructions::rotate_employee::rotate_employee(ctx)
+    }
+
+    pub fn initiate_employee_recovery(
+        ctx: Context<InitiateEmployeeRecovery>,
+        new_employee: Pubkey,
+    ) -> Result<()> {
+        instructions::initiate_employee_recovery::initiate_employee_recovery(ctx, new_employee)
+    }
+
+    pub fn complete_employee_recovery(ctx: Context<CompleteEmployeeRecovery>) -> Result<()> {
+        instructions::complete_employee_recovery::complete_employee_recovery(ctx)
+    }
+
+    pub fn cancel_employee_recovery(ctx: Context<CancelEmployeeRecovery>) -> Result<()> {
+        instructions::cancel_employee_recovery::cancel_employee_recovery(ctx)
+    }
+
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        instructions::initialize_config::initialize_config(ctx)
+    }
+
+    pub fn add_allowed_mint(ctx: Context<AddAllowedMint>) -> Result<()> {
+        instructions::add_allowed_mint::add_allowed_mint(ctx)
+    }
+
+    pub fn remove_allowed_mint(ctx: Context<RemoveAllowedMint>, mint: Pubkey) -> Result<()> {
+        instructions::remove_allowed_mint::remove_allowed_mint(ctx, mint)
+    }
+
+    pub fn propose_config_admin(ctx: Context<ProposeConfigAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::propose_config_admin::propose_config_admin(ctx, new_admin)
+    }
+
+    pub fn accept_config_admin(ctx: Context<AcceptConfigAdmin>) -> Result<()> {
+        instructions::accept_config_admin::accept_config_admin(ctx)
+    }
+
+    pub fn update_config_guardian(
+        ctx: Context<UpdateConfigGuardian>,
+        new_guardian: Pubkey,
+    ) -> Result<()> {
+        instructions::update_config_guardian::update_config_guardian(ctx, new_guardian)
+    }
+
+    pub fn set_paused_operations(
+        ctx: Context<SetPausedOperations>,
+        paused_operations: u8,
+    ) -> Result<()> {
+        instructions::set_paused_operations::set_paused_operations(ctx, paused_operations)
+    }
+
+    pub fn set_fee_schedule(ctx: Context<SetFeeSchedule>, fee_schedule: FeeSchedule) -> Result<()> {
+        instructions::set_fee_schedule::set_fee_schedule(ctx, fee_schedule)
+    }
+
+    pub fn initialize_fee_vault(ctx: Context<InitializeFeeVault>) -> Result<()> {
+        instructions::initialize_fee_vault::initialize_fee_vault(ctx)
+    }
+
+    pub fn collect_protocol_fees(ctx: Context<CollectProtocolFees>, amount: u64) -> Result<()> {
+        instructions::collect_protocol_fees::collect_protocol_fees(ctx, amount)
+    }
+
+    pub fn create_organization(ctx: Context<CreateOrganization>) -> Result<()> {
+        instructions::create_organization::create_organization(ctx)
+    }
+
+    pub fn add_organization_member(ctx: Context<AddOrganizationMember>, roles: u8) -> Result<()> {
+        instructions::add_organization_member::add_organization_member(ctx, roles)
+    }
+
+    pub fn update_organization_member(
+        ctx: Context<UpdateOrganizationMember>,
+        roles: u8,
+    ) -> Result<()> {
+        instructions::update_organization_member::update_organization_member(ctx, roles)
+    }
+
+    pub fn remove_organization_member(ctx: Context<RemoveOrganizationMember>) -> Result<()> {
+        instructions::remove_organization_member::remove_organization_member(ctx)
+    }
+
+    pub fn execute_organization_instruction<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteOrganizationInstruction<'info>>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::execute_organization_instruction::execute_organization_instruction(ctx, data)
+    }
+
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::create_multisig::create_multisig(ctx, signers, threshold)
+    }
+
+    pub fn propose_multisig_instruction<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProposeMultisigInstruction<'info>>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::propose_multisig_instruction::propose_multisig_instruction(ctx, data)
+    }
+
+    pub fn approve_multisig_proposal(ctx: Context<ApproveMultisigProposal>) -> Result<()> {
+        instructions::approve_multisig_proposal::approve_multisig_proposal(ctx)
+    }
+
+    pub fn execute_multisig_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteMultisigProposal<'info>>,
+    ) -> Result<()> {
+        instructions::execute_multisig_proposal::execute_multisig_proposal(ctx)
+    }
+
+    pub fn cancel_multisig_proposal(ctx: Context<CancelMultisigProposal>) -> Result<()> {
+        instructions::cancel_multisig_proposal::cancel_multisig_proposal(ctx)
+    }
+
+    pub fn set_multisig_signers(
+        ctx: Context<SetMultisigSigners>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_multisig_signers::set_multisig_signers(ctx, signers, threshold)
+    }
 }
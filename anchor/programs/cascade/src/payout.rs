@@ -0,0 +1,352 @@
+use crate::errors::ErrorCode;
+use crate::state::{
+    Deduction, DeductionBasis, GarnishmentOrder, PaymentStream, PayoutSplit,
+    BASIS_POINTS_DENOMINATOR,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PayoutTransfer {
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+/// Rounds down, so a share can never exceed its fraction of `amount`.
+pub fn basis_points_of(amount: u64, basis_points: u16) -> Result<u64> {
+    let share = u128::from(amount)
+        .checked_mul(u128::from(basis_points))
+        .ok_or(ErrorCode::MathOverflow)?
+        / u128::from(BASIS_POINTS_DENOMINATOR);
+    u64::try_from(share).map_err(|_| ErrorCode::MathOverflow.into())
+}
+
+/// Splits `amount` across `splits`. Each share is rounded down and the
+/// remainder, including all rounding dust, is returned for the primary
+/// destination, so the transfers always sum to exactly `amount`.
+pub fn split_by_shares(amount: u64, splits: &[PayoutSplit]) -> Result<(Vec<PayoutTransfer>, u64)> {
+    let mut remainder = amount;
+    let mut transfers = Vec::with_capacity(splits.len());
+
+    for split in splits {
+        let share = basis_points_of(amount, split.share_bps)?;
+        remainder = remainder
+            .checked_sub(share)
+            .ok_or(ErrorCode::InvalidPayoutSplits)?;
+        if share > 0 {
+            transfers.push(PayoutTransfer {
+                destination: split.destination,
+                amount: share,
+            });
+        }
+    }
+
+    Ok((transfers, remainder))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DeductionPayment {
+    pub destination: Pubkey,
+    pub deducted_amount: u64,
+    pub employer_match_amount: u64,
+}
+
+/// How a single withdrawal of `amount` leaves the vault. Third-party transfers
+/// plus `employee_amount` always sum to exactly `amount + employer_match_amount`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WithdrawalPlan {
+    pub transfers: Vec<PayoutTransfer>,
+    pub deductions: Vec<DeductionPayment>,
+    pub withheld_amount: u64,
+    pub garnished_amount: u64,
+    pub deducted_amount: u64,
+    pub employer_match_amount: u64,
+    pub split_amount: u64,
+    pub employee_amount: u64,
+}
+
+/// The employee-side amount of `deduction` for a gross withdrawal of `amount`.
+fn deduction_of(amount: u64, deduction: &Deduction, hourly_rate: u64) -> Result<u64> {
+    match deduction.basis {
+        DeductionBasis::Percent { rate_bps } => basis_points_of(amount, rate_bps),
+        DeductionBasis::FixedPerHour {
+            amount: amount_per_hour,
+        } => {
+            if hourly_rate == 0 {
+                return Ok(0);
+            }
+            let deducted = u128::from(amount)
+                .checked_mul(u128::from(amount_per_hour))
+                .ok_or(ErrorCode::MathOverflow)?
+                / u128::from(hourly_rate);
+            u64::try_from(deducted).map_err(|_| ErrorCode::MathOverflow.into())
+        }
+    }
+}
+
+/// Withholding comes off the gross amount first. A garnishment order then
+/// takes its rate of the disposable (post-withholding) amount, up to what is
+/// still owed, ahead of any voluntary deduction. Deductions follow, each capped
+/// at what is left, and the employee's payout splits apply to the net.
+/// Employer match is paid on top of `amount` and limited to
+/// `employer_match_budget`.
+pub fn plan_withdrawal(
+    amount: u64,
+    stream: &PaymentStream,
+    garnishment: Option<&GarnishmentOrder>,
+    employer_match_budget: u64,
+) -> Result<WithdrawalPlan> {
+    let mut plan = WithdrawalPlan::default();
+    let mut net_amount = amount;
+
+    if let Some(rule) = stream.withholding {
+        plan.withheld_amount = basis_points_of(amount, rule.rate_bps)?;
+        net_amount = net_amount
+            .checked_sub(plan.withheld_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        if plan.withheld_amount > 0 {
+            plan.transfers.push(PayoutTransfer {
+                destination: rule.destination,
+                amount: plan.withheld_amount,
+            });
+        }
+    }
+
+    if let Some(order) = garnishment {
+        plan.garnished_amount =
+            basis_points_of(net_amount, order.rate_bps)?.min(order.remaining_amount());
+        net_amount -= plan.garnished_amount;
+        if plan.garnished_amount > 0 {
+            plan.transfers.push(PayoutTransfer {
+                destination: order.beneficiary,
+                amount: plan.garnished_amount,
+            });
+        }
+    }
+
+    let mut remaining_match_budget = employer_match_budget;
+    for deduction in &stream.deductions {
+        let deducted_amount = deduction_of(amount, deduction, stream.hourly_rate)?.min(net_amount);
+        let employer_match_amount = basis_points_of(deducted_amount, deduction.employer_match_bps)?
+            .min(remaining_match_budget);
+        net_amount -= deducted_amount;
+        remaining_match_budget -= employer_match_amount;
+
+        plan.deducted_amount = plan
+            .deducted_amount
+            .checked_add(deducted_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        plan.employer_match_amount = plan
+            .employer_match_amount
+            .checked_add(employer_match_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        let transfer_amount = deducted_amount
+            .checked_add(employer_match_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        if transfer_amount > 0 {
+            plan.transfers.push(PayoutTransfer {
+                destination: deduction.destination,
+                amount: transfer_amount,
+            });
+            plan.deductions.push(DeductionPayment {
+                destination: deduction.destination,
+                deducted_amount,
+                employer_match_amount,
+            });
+        }
+    }
+
+    let (split_transfers, employee_amount) = split_by_shares(net_amount, &stream.payout_splits)?;
+    plan.split_amount = net_amount
+        .checked_sub(employee_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    plan.transfers.extend(split_transfers);
+    plan.employee_amount = employee_amount;
+
+    Ok(plan)
+}
+
+/// Finds the writable token account for a payout among the remaining accounts.
+pub fn find_payout_account<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    destination: &Pubkey,
+) -> Result<AccountInfo<'info>> {
+    remaining_accounts
+        .iter()
+        .find(|account| account.key == destination && account.is_writable)
+        .cloned()
+        .ok_or(ErrorCode::MissingPayoutAccount.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{basis_points_of, plan_withdrawal, split_by_shares};
+    use crate::state::{
+        Deduction, DeductionBasis, GarnishmentOrder, PaymentStream, PayoutSplit, WithholdingRule,
+    };
+    use anchor_lang::prelude::Pubkey;
+
+    #[test]
+    fn rounds_basis_points_down() {
+        assert_eq!(basis_points_of(999, 1_000).unwrap(), 99);
+        assert_eq!(basis_points_of(u64::MAX, 10_000).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn split_transfers_and_remainder_sum_to_amount() {
+        let savings = Pubkey::new_unique();
+        let family = Pubkey::new_unique();
+        let splits = [
+            PayoutSplit {
+                destination: savings,
+                share_bps: 3_333,
+            },
+            PayoutSplit {
+                destination: family,
+                share_bps: 3_333,
+            },
+        ];
+
+        let (transfers, remainder) = split_by_shares(100, &splits).unwrap();
+
+        assert_eq!(transfers[0].destination, savings);
+        assert_eq!(transfers[0].amount, 33);
+        assert_eq!(transfers[1].amount, 33);
+        assert_eq!(remainder, 34);
+        assert_eq!(
+            transfers
+                .iter()
+                .map(|transfer| transfer.amount)
+                .sum::<u64>()
+                + remainder,
+            100
+        );
+    }
+
+    #[test]
+    fn omits_zero_amount_shares() {
+        let splits = [PayoutSplit {
+            destination: Pubkey::new_unique(),
+            share_bps: 1,
+        }];
+
+        let (transfers, remainder) = split_by_shares(10, &splits).unwrap();
+
+        assert!(transfers.is_empty());
+        assert_eq!(remainder, 10);
+    }
+
+    #[test]
+    fn withholds_from_gross_before_splitting_the_net() {
+        let tax_escrow = Pubkey::new_unique();
+        let savings = Pubkey::new_unique();
+        let stream = PaymentStream {
+            withholding: Some(WithholdingRule {
+                rate_bps: 2_000,
+                destination: tax_escrow,
+            }),
+            payout_splits: vec![PayoutSplit {
+                destination: savings,
+                share_bps: 5_000,
+            }],
+            ..Default::default()
+        };
+
+        let plan = plan_withdrawal(1_001, &stream, None, 0).unwrap();
+
+        assert_eq!(plan.withheld_amount, 200);
+        assert_eq!(plan.split_amount, 400);
+        assert_eq!(plan.employee_amount, 401);
+        assert_eq!(plan.transfers[0].destination, tax_escrow);
+        assert_eq!(plan.transfers[1].destination, savings);
+        assert_eq!(
+            plan.transfers
+                .iter()
+                .map(|transfer| transfer.amount)
+                .sum::<u64>()
+                + plan.employee_amount,
+            1_001
+        );
+    }
+
+    #[test]
+    fn deducts_and_matches_within_the_employer_budget() {
+        let retirement = Pubkey::new_unique();
+        let insurance = Pubkey::new_unique();
+        let stream = PaymentStream {
+            hourly_rate: 100,
+            deductions: vec![
+                Deduction {
+                    destination: retirement,
+                    basis: DeductionBasis::Percent { rate_bps: 1_000 },
+                    employer_match_bps: 5_000,
+                },
+                Deduction {
+                    destination: insurance,
+                    basis: DeductionBasis::FixedPerHour { amount: 5 },
+                    employer_match_bps: 10_000,
+                },
+            ],
+            ..Default::default()
+        };
+
+        // 8 hours of wages: 80 to retirement, 40 to insurance, matched 40 + 40
+        // but the budget only covers 50 of it.
+        let plan = plan_withdrawal(800, &stream, None, 50).unwrap();
+
+        assert_eq!(plan.deducted_amount, 120);
+        assert_eq!(plan.employer_match_amount, 50);
+        assert_eq!(plan.deductions[0].employer_match_amount, 40);
+        assert_eq!(plan.deductions[1].employer_match_amount, 10);
+        assert_eq!(plan.transfers[0].amount, 120);
+        assert_eq!(plan.transfers[1].amount, 50);
+        assert_eq!(plan.employee_amount, 680);
+        assert_eq!(
+            plan.transfers
+                .iter()
+                .map(|transfer| transfer.amount)
+                .sum::<u64>()
+                + plan.employee_amount,
+            800 + plan.employer_match_amount
+        );
+    }
+
+    #[test]
+    fn garnishes_disposable_amount_until_the_order_is_satisfied() {
+        let tax_escrow = Pubkey::new_unique();
+        let creditor = Pubkey::new_unique();
+        let retirement = Pubkey::new_unique();
+        let stream = PaymentStream {
+            withholding: Some(WithholdingRule {
+                rate_bps: 2_000,
+                destination: tax_escrow,
+            }),
+            deductions: vec![Deduction {
+                destination: retirement,
+                basis: DeductionBasis::Percent { rate_bps: 1_000 },
+                employer_match_bps: 0,
+            }],
+            ..Default::default()
+        };
+        let mut order = GarnishmentOrder {
+            beneficiary: creditor,
+            rate_bps: 2_500,
+            ordered_amount: 1_000,
+            ..Default::default()
+        };
+
+        // 1_000 gross: 200 withheld, 25% of the remaining 800 garnished,
+        // then the 10% deduction of gross.
+        let plan = plan_withdrawal(1_000, &stream, Some(&order), 0).unwrap();
+        assert_eq!(plan.withheld_amount, 200);
+        assert_eq!(plan.garnished_amount, 200);
+        assert_eq!(plan.deducted_amount, 100);
+        assert_eq!(plan.employee_amount, 500);
+        assert_eq!(plan.transfers[1].destination, creditor);
+
+        order.paid_amount = 950;
+        let plan = plan_withdrawal(1_000, &stream, Some(&order), 0).unwrap();
+        assert_eq!(plan.garnished_amount, 50);
+        assert_eq!(plan.employee_amount, 650);
+    }
+}
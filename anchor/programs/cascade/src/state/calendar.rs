@@ -0,0 +1,64 @@
+use crate::state::SECONDS_PER_HOUR;
+
+pub(crate) const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
+pub(crate) const SECONDS_PER_WEEK: i64 = 7 * SECONDS_PER_DAY;
+/// 1970-01-01 was a Thursday; shifting by three days aligns weeks to Monday.
+pub(crate) const EPOCH_TO_MONDAY_SECONDS: i64 = 3 * SECONDS_PER_DAY;
+
+/// Calendar year (UTC) containing `timestamp`.
+pub(crate) fn utc_year(timestamp: i64) -> i64 {
+    civil_from_days(timestamp.div_euclid(SECONDS_PER_DAY)).0
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date.
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian date for a number of days since 1970-01-01.
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{civil_from_days, days_from_civil, utc_year};
+
+    #[test]
+    fn converts_between_days_and_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        for days in -800_000..800_000 {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn finds_the_utc_year() {
+        // 2023-12-31 23:59:59 and 2024-01-01 00:00:00 UTC.
+        assert_eq!(utc_year(1_704_067_199), 2023);
+        assert_eq!(utc_year(1_704_067_200), 2024);
+        assert_eq!(utc_year(-1), 1969);
+    }
+}
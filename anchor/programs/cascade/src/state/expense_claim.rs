@@ -0,0 +1,115 @@
+use crate::errors::ErrorCode;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub enum ExpenseClaimStatus {
+    Submitted,
+    /// Reimbursed to the employee.
+    Approved,
+    Rejected,
+}
+
+/// Where a reimbursement is paid from.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub enum ExpensePaymentSource {
+    /// Vault funding the employee has not yet earned.
+    Vault,
+    /// The employer's token account.
+    Employer,
+}
+
+/// A reimbursable expense submitted by the employee.
+#[account]
+#[derive(InitSpace)]
+pub struct ExpenseClaim {
+    pub stream: Pubkey,
+    pub index: u32,
+    pub amount: u64,
+    /// Hash of the off-chain receipt.
+    pub receipt_hash: [u8; 32],
+    pub status: ExpenseClaimStatus,
+    pub submitted_at: i64,
+    pub reviewed_at: Option<i64>,
+    /// Set once the claim has been paid.
+    pub paid_from: Option<ExpensePaymentSource>,
+    pub bump: u8,
+}
+
+impl ExpenseClaim {
+    /// Marks a submitted claim as paid from `source`.
+    pub fn approve(&mut self, source: ExpensePaymentSource, reviewed_at: i64) -> Result<()> {
+        self.review(ExpenseClaimStatus::Approved, reviewed_at)?;
+        self.paid_from = Some(source);
+        Ok(())
+    }
+
+    pub fn reject(&mut self, reviewed_at: i64) -> Result<()> {
+        self.review(ExpenseClaimStatus::Rejected, reviewed_at)
+    }
+
+    /// A claim is reviewed exactly once.
+    fn review(&mut self, status: ExpenseClaimStatus, reviewed_at: i64) -> Result<()> {
+        require!(
+            self.status == ExpenseClaimStatus::Submitted,
+            ErrorCode::ExpenseClaimNotPending
+        );
+        self.status = status;
+        self.reviewed_at = Some(reviewed_at);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExpenseClaim, ExpenseClaimStatus, ExpensePaymentSource};
+    use crate::errors::ErrorCode;
+    use anchor_lang::prelude::*;
+
+    fn submitted_claim() -> ExpenseClaim {
+        ExpenseClaim {
+            stream: Pubkey::new_unique(),
+            index: 0,
+            amount: 250,
+            receipt_hash: [7; 32],
+            status: ExpenseClaimStatus::Submitted,
+            submitted_at: 100,
+            reviewed_at: None,
+            paid_from: None,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn approves_a_submitted_claim_once() {
+        let mut claim = submitted_claim();
+        claim.approve(ExpensePaymentSource::Vault, 200).unwrap();
+
+        assert_eq!(claim.status, ExpenseClaimStatus::Approved);
+        assert_eq!(claim.reviewed_at, Some(200));
+        assert_eq!(claim.paid_from, Some(ExpensePaymentSource::Vault));
+        assert_eq!(
+            claim.approve(ExpensePaymentSource::Employer, 300),
+            Err(ErrorCode::ExpenseClaimNotPending.into())
+        );
+        assert_eq!(
+            claim.reject(300),
+            Err(ErrorCode::ExpenseClaimNotPending.into())
+        );
+        assert_eq!(claim.paid_from, Some(ExpensePaymentSource::Vault));
+    }
+
+    #[test]
+    fn rejects_a_submitted_claim_once() {
+        let mut claim = submitted_claim();
+        claim.reject(200).unwrap();
+
+        assert_eq!(claim.status, ExpenseClaimStatus::Rejected);
+        assert_eq!(claim.reviewed_at, Some(200));
+        assert_eq!(claim.paid_from, None);
+        assert_eq!(
+            claim.approve(ExpensePaymentSource::Employer, 300),
+            Err(ErrorCode::ExpenseClaimNotPending.into())
+        );
+        assert_eq!(claim.reviewed_at, Some(200));
+    }
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+/// Statutory ceiling on the share of a withdrawal an order may divert.
+pub const MAX_GARNISHMENT_RATE_BPS: u16 = 2_500;
+
+/// A court-ordered garnishment against a stream. `withdraw` diverts
+/// `rate_bps` of every withdrawal to `beneficiary` until `ordered_amount`
+/// has been paid, at which point the order expires.
+#[account]
+#[derive(Default, InitSpace)]
+pub struct GarnishmentOrder {
+    pub stream: Pubkey,
+    pub index: u32,
+    pub issuer: Pubkey,
+    /// Token account receiving the garnished amounts.
+    pub beneficiary: Pubkey,
+    pub rate_bps: u16,
+    pub ordered_amount: u64,
+    pub paid_amount: u64,
+    pub issued_at: i64,
+    /// Set once the order is satisfied or released by its issuer.
+    pub closed_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl GarnishmentOrder {
+    pub fn remaining_amount(&self) -> u64 {
+        self.ordered_amount.saturating_sub(self.paid_amount)
+    }
+
+    pub fn is_satisfied(&self) -> bool {
+        self.paid_amount >= self.ordered_amount
+    }
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+/// How long the employer has to review a submitted milestone before anyone
+/// may approve it on their behalf.
+pub const MILESTONE_REVIEW_SECONDS: i64 = 14 * 24 * 60 * 60;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub enum MilestoneStatus {
+    /// Escrowed in the vault, waiting for the contractor.
+    Funded,
+    /// Marked complete by the contractor, waiting for review.
+    Submitted,
+    /// Released into the contractor's withdrawable balance.
+    Approved,
+}
+
+/// An escrowed payment for one piece of contract work.
+#[account]
+#[derive(InitSpace)]
+pub struct Milestone {
+    pub stream: Pubkey,
+    pub index: u32,
+    /// Escrowed amount, after any deposit fee.
+    pub amount: u64,
+    /// Hash of the off-chain description of the work.
+    pub description_hash: [u8; 32],
+    pub status: MilestoneStatus,
+    pub funded_at: i64,
+    pub submitted_at: Option<i64>,
+    pub approved_at: Option<i64>,
+    /// Set when the milestone was approved after the review window lapsed.
+    pub auto_approved: bool,
+    pub bump: u8,
+}
+
+impl Milestone {
+    /// Whether the employer's review window for a submission has lapsed.
+    pub fn review_lapsed(&self, current_timestamp: i64) -> bool {
+        self.status == MilestoneStatus::Submitted
+            && self.submitted_at.is_some_and(|submitted_at| {
+                current_timestamp.saturating_sub(submitted_at) >= MILESTONE_REVIEW_SECONDS
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Milestone, MilestoneStatus, MILESTONE_REVIEW_SECONDS};
+    use anchor_lang::prelude::Pubkey;
+
+    #[test]
+    fn review_lapses_only_for_submitted_milestones() {
+        let mut milestone = Milestone {
+            stream: Pubkey::new_unique(),
+            index: 0,
+            amount: 1_000,
+            description_hash: [7; 32],
+            status: MilestoneStatus::Funded,
+            funded_at: 0,
+            submitted_at: None,
+            approved_at: None,
+            auto_approved: false,
+            bump: 255,
+        };
+        assert!(!milestone.review_lapsed(MILESTONE_REVIEW_SECONDS * 2));
+
+        milestone.status = MilestoneStatus::Submitted;
+        milestone.submitted_at = Some(100);
+        assert!(!milestone.review_lapsed(99 + MILESTONE_REVIEW_SECONDS));
+        assert!(milestone.review_lapsed(100 + MILESTONE_REVIEW_SECONDS));
+
+        milestone.status = MilestoneStatus::Approved;
+        assert!(!milestone.review_lapsed(100 + MILESTONE_REVIEW_SECONDS));
+    }
+}
@@ -1,3 +1,27 @@
+pub(crate) mod calendar;
+pub mod expense_claim;
+pub mod garnishment_order;
+pub mod milestone;
+pub mod multisig;
+pub mod organization;
+pub mod pay_multipliers;
 pub mod payment_stream;
+pub mod program_config;
+pub mod salary_schedule;
+pub mod shift_record;
+pub mod timesheet_period;
+pub mod weekly_schedule;
 
+pub(crate) use calendar::*;
+pub use expense_claim::*;
+pub use garnishment_order::*;
+pub use milestone::*;
+pub use multisig::*;
+pub use organization::*;
+pub use pay_multipliers::*;
 pub use payment_stream::*;
+pub use program_config::*;
+pub use salary_schedule::*;
+pub use shift_record::*;
+pub use timesheet_period::*;
+pub use weekly_schedule::*;
@@ -0,0 +1,271 @@
+use crate::employer_authority::EmployerInstruction;
+use crate::errors::ErrorCode;
+use crate::instruction;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+/// Large enough for `set_multisig_signers` with a full signer set
+/// (discriminator, signer vector and threshold), which is the largest
+/// instruction a multisig can propose.
+pub const MAX_PROPOSAL_DATA_LEN: usize = 8 + 4 + 32 * MAX_MULTISIG_SIGNERS + 1;
+pub const MAX_PROPOSAL_ACCOUNTS: usize = 20;
+
+/// An M-of-N employer. Its PDA signs employer-side instructions once a
+/// proposal collects `threshold` approvals from `signers`.
+#[account]
+#[derive(InitSpace)]
+pub struct Multisig {
+    pub creator: Pubkey,
+    #[max_len(MAX_MULTISIG_SIGNERS)]
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub proposal_count: u64,
+    pub bump: u8,
+}
+
+impl Multisig {
+    pub fn validate_signers(signers: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !signers.is_empty() && signers.len() <= MAX_MULTISIG_SIGNERS,
+            ErrorCode::InvalidMultisigSigners
+        );
+        let has_duplicates = signers
+            .iter()
+            .enumerate()
+            .any(|(index, signer)| signers[..index].contains(signer));
+        require!(!has_duplicates, ErrorCode::InvalidMultisigSigners);
+        require!(
+            threshold > 0 && usize::from(threshold) <= signers.len(),
+            ErrorCode::InvalidMultisigThreshold
+        );
+        Ok(())
+    }
+
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signers.contains(key)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace, PartialEq, Eq)]
+pub struct ProposalAccount {
+    pub pubkey: Pubkey,
+    pub is_writable: bool,
+}
+
+/// A pending employer-side instruction. Approvers sign off on the exact
+/// instruction data and account list that will be executed.
+#[account]
+#[derive(InitSpace)]
+pub struct MultisigProposal {
+    pub multisig: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    #[max_len(MAX_PROPOSAL_DATA_LEN)]
+    pub data: Vec<u8>,
+    #[max_len(MAX_PROPOSAL_ACCOUNTS)]
+    pub accounts: Vec<ProposalAccount>,
+    #[max_len(MAX_MULTISIG_SIGNERS)]
+    pub approvals: Vec<Pubkey>,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl MultisigProposal {
+    /// Besides employer-side instructions, a multisig may vote on changes to
+    /// its own signer set.
+    pub fn validate_instruction(data: &[u8], account_count: usize) -> Result<()> {
+        if !data.starts_with(instruction::SetMultisigSigners::DISCRIMINATOR) {
+            EmployerInstruction::from_instruction_data(data)?;
+        }
+        require!(
+            data.len() <= MAX_PROPOSAL_DATA_LEN && account_count <= MAX_PROPOSAL_ACCOUNTS,
+            ErrorCode::ProposalTooLarge
+        );
+        Ok(())
+    }
+
+    /// Approvals from wallets that are still signers. Approvals recorded
+    /// before a signer was removed no longer count.
+    pub fn current_approvals(&self, multisig: &Multisig) -> usize {
+        self.approvals
+            .iter()
+            .filter(|approver| multisig.is_signer(approver))
+            .count()
+    }
+
+    /// Records `approver`'s approval. Approvals from removed signers are
+    /// dropped first, so the list never outgrows the current signer set.
+    pub fn approve(&mut self, multisig: &Multisig, approver: Pubkey) -> Result<()> {
+        require!(
+            multisig.is_signer(&approver),
+            ErrorCode::UnauthorizedMultisigSigner
+        );
+        require!(
+            !self.approvals.contains(&approver),
+            ErrorCode::ProposalAlreadyApproved
+        );
+        self.approvals
+            .retain(|existing_approver| multisig.is_signer(existing_approver));
+        self.approvals.push(approver);
+        Ok(())
+    }
+
+    pub fn is_approved(&self, multisig: &Multisig) -> bool {
+        self.current_approvals(multisig) >= usize::from(multisig.threshold)
+    }
+
+    /// The proposer may withdraw a proposal; once the proposer is no longer
+    /// a signer, any current signer may clear it.
+    pub fn can_be_cancelled_by(&self, multisig: &Multisig, key: &Pubkey) -> bool {
+        *key == self.proposer || (multisig.is_signer(key) && !multisig.is_signer(&self.proposer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Multisig, MultisigProposal, MAX_MULTISIG_SIGNERS, MAX_PROPOSAL_ACCOUNTS};
+    use crate::instruction;
+    use anchor_lang::prelude::Pubkey;
+    use anchor_lang::InstructionData;
+
+    fn multisig(signers: Vec<Pubkey>, threshold: u8) -> Multisig {
+        Multisig {
+            creator: Pubkey::new_unique(),
+            signers,
+            threshold,
+            proposal_count: 0,
+            bump: 0,
+        }
+    }
+
+    fn proposal(proposer: Pubkey, approvals: Vec<Pubkey>) -> MultisigProposal {
+        MultisigProposal {
+            multisig: Pubkey::new_unique(),
+            index: 0,
+            proposer,
+            data: Vec::new(),
+            accounts: Vec::new(),
+            approvals,
+            created_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn accepts_threshold_within_signer_count() {
+        let signers = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+
+        assert!(Multisig::validate_signers(&signers, 2).is_ok());
+        assert!(Multisig::validate_signers(&signers, 3).is_ok());
+    }
+
+    #[test]
+    fn rejects_invalid_thresholds_and_signer_sets() {
+        let signer = Pubkey::new_unique();
+        let signers = [signer, Pubkey::new_unique()];
+
+        assert!(Multisig::validate_signers(&signers, 0).is_err());
+        assert!(Multisig::validate_signers(&signers, 3).is_err());
+        assert!(Multisig::validate_signers(&[], 1).is_err());
+        assert!(Multisig::validate_signers(&[signer, signer], 1).is_err());
+
+        let too_many_signers: Vec<Pubkey> = (0..=MAX_MULTISIG_SIGNERS)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        assert!(Multisig::validate_signers(&too_many_signers, 1).is_err());
+    }
+
+    #[test]
+    fn a_full_signer_rotation_fits_in_a_proposal() {
+        let data = instruction::SetMultisigSigners {
+            signers: (0..MAX_MULTISIG_SIGNERS)
+                .map(|_| Pubkey::new_unique())
+                .collect(),
+            threshold: MAX_MULTISIG_SIGNERS as u8,
+        }
+        .data();
+
+        assert!(MultisigProposal::validate_instruction(&data, 1).is_ok());
+        assert!(MultisigProposal::validate_instruction(&data, MAX_PROPOSAL_ACCOUNTS + 1).is_err());
+        assert!(MultisigProposal::validate_instruction(
+            &instruction::Withdraw { amount: 5 }.data(),
+            1
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn treasury_withdrawals_can_be_proposed() {
+        let data = instruction::WithdrawEmployerTreasury { amount: 1_000 }.data();
+        assert!(MultisigProposal::validate_instruction(&data, 5).is_ok());
+    }
+
+    #[test]
+    fn approvals_from_removed_signers_do_not_count() {
+        let [alice, bob, carol] = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let mut multisig = multisig(vec![alice, bob, carol], 2);
+        let proposal = proposal(alice, vec![alice, bob]);
+        assert!(proposal.is_approved(&multisig));
+
+        multisig.signers = vec![alice, carol];
+        assert_eq!(proposal.current_approvals(&multisig), 1);
+        assert!(!proposal.is_approved(&multisig));
+
+        multisig.threshold = 1;
+        assert!(proposal.is_approved(&multisig));
+    }
+
+    #[test]
+    fn approving_drops_approvals_from_removed_signers() {
+        let old_signers: Vec<Pubkey> = (0..MAX_MULTISIG_SIGNERS)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        let mut multisig = multisig(old_signers.clone(), MAX_MULTISIG_SIGNERS as u8);
+        let mut proposal = proposal(old_signers[0], vec![old_signers[0]]);
+        for signer in &old_signers[1..] {
+            proposal.approve(&multisig, *signer).unwrap();
+        }
+        assert!(proposal.approve(&multisig, Pubkey::new_unique()).is_err());
+        assert!(proposal.approve(&multisig, old_signers[1]).is_err());
+
+        // A fully rotated signer set can still approve up to its threshold.
+        let new_signers: Vec<Pubkey> = (0..MAX_MULTISIG_SIGNERS)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        multisig.signers = new_signers.clone();
+        for signer in &new_signers {
+            proposal.approve(&multisig, *signer).unwrap();
+            assert!(proposal.approvals.len() <= MAX_MULTISIG_SIGNERS);
+        }
+        assert_eq!(proposal.approvals, new_signers);
+        assert!(proposal.is_approved(&multisig));
+    }
+
+    #[test]
+    fn only_the_proposer_cancels_while_still_a_signer() {
+        let [alice, bob, outsider] = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let mut multisig = multisig(vec![alice, bob], 2);
+        let proposal = proposal(alice, vec![alice]);
+
+        assert!(proposal.can_be_cancelled_by(&multisig, &alice));
+        assert!(!proposal.can_be_cancelled_by(&multisig, &bob));
+
+        multisig.signers = vec![bob];
+        assert!(proposal.can_be_cancelled_by(&multisig, &alice));
+        assert!(proposal.can_be_cancelled_by(&multisig, &bob));
+        assert!(!proposal.can_be_cancelled_by(&multisig, &outsider));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::employer_authority::EmployerInstruction;
+use anchor_lang::prelude::*;
+
+pub const ROLE_ADMIN: u8 = 1 << 0;
+pub const ROLE_HR_MANAGER: u8 = 1 << 1;
+pub const ROLE_FINANCE_APPROVER: u8 = 1 << 2;
+/// Read-only role for off-chain reporting; grants no on-chain permissions.
+pub const ROLE_AUDITOR: u8 = 1 << 3;
+pub const ALL_ROLES: u8 = ROLE_ADMIN | ROLE_HR_MANAGER | ROLE_FINANCE_APPROVER | ROLE_AUDITOR;
+
+/// An organization acts as the `employer` of its streams. Its PDA signs on the
+/// organization's behalf once a member with a suitable role approves.
+#[account]
+#[derive(InitSpace)]
+pub struct Organization {
+    pub creator: Pubkey,
+    pub admin_count: u16,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct OrganizationMember {
+    pub organization: Pubkey,
+    pub wallet: Pubkey,
+    pub roles: u8,
+    pub added_at: i64,
+    pub bump: u8,
+}
+
+impl OrganizationMember {
+    pub fn has_role(&self, role: u8) -> bool {
+        self.roles & role != 0
+    }
+
+    /// Admins may perform every employer action; other roles are scoped.
+    pub fn can_execute(&self, instruction: EmployerInstruction) -> bool {
+        self.has_role(ROLE_ADMIN) || self.has_role(required_role(instruction))
+    }
+}
+
+pub fn required_role(instruction: EmployerInstruction) -> u8 {
+    match instruction {
+        EmployerInstruction::CreateStream
+        | EmployerInstruction::UpdateInactivityThreshold
+        | EmployerInstruction::AnnounceEmergencyWithdraw
+        | EmployerInstruction::InitiateEmployeeRecovery
+        | EmployerInstruction::CompleteEmployeeRecovery
+        | EmployerInstruction::CancelEmployeeRecovery
+        | EmployerInstruction::SetDeductions
+        | EmployerInstruction::SetGarnishmentIssuer
+        | EmployerInstruction::SetAccrualMode
+        | EmployerInstruction::SetTimesheetApprover
+        | EmployerInstruction::SubmitTimesheet
+        | EmployerInstruction::SetMaxShiftLength
+        | EmployerInstruction::VoidShift
+        | EmployerInstruction::SetWeeklySchedule
+        | EmployerInstruction::SetPayMultipliers
+        | EmployerInstruction::SetStreamKind => ROLE_HR_MANAGER,
+        EmployerInstruction::TopUpStream
+        | EmployerInstruction::ExecuteEmergencyWithdraw
+        | EmployerInstruction::CloseStream
+        | EmployerInstruction::SetWithholdingRule
+        | EmployerInstruction::SetAdvanceLimit
+        | EmployerInstruction::AwardBonus
+        | EmployerInstruction::FundMilestone
+        | EmployerInstruction::ApproveMilestone
+        | EmployerInstruction::RejectMilestone
+        | EmployerInstruction::CancelMilestone
+        | EmployerInstruction::ApproveExpenseClaim
+        | EmployerInstruction::RejectExpenseClaim
+        | EmployerInstruction::WithdrawEmployerTreasury => ROLE_FINANCE_APPROVER,
+        EmployerInstruction::ProposeEmployerTransfer
+        | EmployerInstruction::AcceptEmployerTransfer => ROLE_ADMIN,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        OrganizationMember, ROLE_ADMIN, ROLE_AUDITOR, ROLE_FINANCE_APPROVER, ROLE_HR_MANAGER,
+    };
+    use crate::employer_authority::EmployerInstruction;
+    use anchor_lang::prelude::Pubkey;
+
+    fn member_with_roles(roles: u8) -> OrganizationMember {
+        OrganizationMember {
+            organization: Pubkey::new_unique(),
+            wallet: Pubkey::new_unique(),
+            roles,
+            added_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn scopes_employer_actions_by_role() {
+        let hr_manager = member_with_roles(ROLE_HR_MANAGER);
+        assert!(hr_manager.can_execute(EmployerInstruction::CreateStream));
+        assert!(!hr_manager.can_execute(EmployerInstruction::TopUpStream));
+
+        let finance_approver = member_with_roles(ROLE_FINANCE_APPROVER);
+        assert!(finance_approver.can_execute(EmployerInstruction::TopUpStream));
+        assert!(finance_approver.can_execute(EmployerInstruction::WithdrawEmployerTreasury));
+        assert!(!finance_approver.can_execute(EmployerInstruction::CreateStream));
+        assert!(!hr_manager.can_execute(EmployerInstruction::WithdrawEmployerTreasury));
+    }
+
+    #[test]
+    fn admins_can_execute_everything_and_auditors_nothing() {
+        let admin = member_with_roles(ROLE_ADMIN);
+        let auditor = member_with_roles(ROLE_AUDITOR);
+
+        for instruction in [
+            EmployerInstruction::CreateStream,
+            EmployerInstruction::TopUpStream,
+            EmployerInstruction::CloseStream,
+        ] {
+            assert!(admin.can_execute(instruction));
+            assert!(!auditor.can_execute(instruction));
+        }
+    }
+}
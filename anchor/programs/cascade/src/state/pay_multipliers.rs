@@ -0,0 +1,210 @@
+use crate::errors::ErrorCode;
+use crate::state::{
+    BASIS_POINTS_DENOMINATOR, EPOCH_TO_MONDAY_SECONDS, MAX_UTC_OFFSET_MINUTES, MINUTES_PER_DAY,
+    SECONDS_PER_DAY, SECONDS_PER_HOUR, SECONDS_PER_WEEK,
+};
+use anchor_lang::prelude::*;
+
+pub const MAX_PAY_MULTIPLIER_BPS: u16 = 30_000;
+
+/// Premium pay for clock-in streams. Multipliers are in basis points of the
+/// hourly rate (`15_000` = 1.5x) and stack additively: an overtime night hour
+/// at 1.5x and 1.1x pays 1.6x.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace, PartialEq, Eq,
+)]
+pub struct PayMultipliers {
+    /// Paid seconds per workweek before overtime applies.
+    pub overtime_threshold_seconds: u32,
+    pub overtime_multiplier_bps: u16,
+    /// Local night window; it may wrap past midnight (e.g. 22:00-06:00).
+    pub night_start_minute: u16,
+    pub night_end_minute: u16,
+    pub night_multiplier_bps: u16,
+    /// Offset used for both workweeks (Monday to Sunday) and the night window.
+    pub utc_offset_minutes: i16,
+}
+
+/// How a closed shift's paid time was classified.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ShiftPremium {
+    pub workweek: i64,
+    pub overtime_seconds: u64,
+    pub night_seconds: u64,
+    pub premium_amount: u64,
+}
+
+impl PayMultipliers {
+    pub fn validate(&self) -> Result<()> {
+        let valid_multiplier =
+            |bps: u16| (BASIS_POINTS_DENOMINATOR..=MAX_PAY_MULTIPLIER_BPS).contains(&bps);
+        require!(
+            valid_multiplier(self.overtime_multiplier_bps)
+                && valid_multiplier(self.night_multiplier_bps)
+                && self.night_start_minute < MINUTES_PER_DAY
+                && self.night_end_minute < MINUTES_PER_DAY
+                && self.utc_offset_minutes.abs() <= MAX_UTC_OFFSET_MINUTES,
+            ErrorCode::InvalidPayMultipliers
+        );
+        Ok(())
+    }
+
+    fn local_seconds(&self, timestamp: i64) -> i64 {
+        timestamp + i64::from(self.utc_offset_minutes) * 60
+    }
+
+    /// Index of the Monday-to-Sunday workweek containing `timestamp`.
+    pub fn workweek(&self, timestamp: i64) -> i64 {
+        (self.local_seconds(timestamp) + EPOCH_TO_MONDAY_SECONDS).div_euclid(SECONDS_PER_WEEK)
+    }
+
+    /// Night seconds between `from` and `to`, in constant time.
+    pub fn night_seconds_between(&self, from: i64, to: i64) -> u64 {
+        let night_seconds =
+            self.night_seconds_since_epoch(to) - self.night_seconds_since_epoch(from);
+        night_seconds.max(0) as u64
+    }
+
+    fn night_seconds_since_epoch(&self, timestamp: i64) -> i64 {
+        let local_seconds = self.local_seconds(timestamp);
+        let days = local_seconds.div_euclid(SECONDS_PER_DAY);
+        let second_of_day = local_seconds.rem_euclid(SECONDS_PER_DAY);
+        let start = i64::from(self.night_start_minute) * 60;
+        let end = i64::from(self.night_end_minute) * 60;
+
+        let (per_day, today) = if start <= end {
+            (end - start, second_of_day.clamp(start, end) - start)
+        } else {
+            // Wrapping window: [0, end) and [start, midnight).
+            (
+                SECONDS_PER_DAY - start + end,
+                second_of_day.min(end) + (second_of_day.max(start) - start),
+            )
+        };
+        days * per_day + today
+    }
+
+    /// Classifies a closed shift and prices its premium. `worked_this_week`
+    /// is the paid time already recorded in the shift's workweek; shifts
+    /// count towards the week in which they start.
+    pub fn price_shift(
+        &self,
+        clocked_in_at: i64,
+        duration_seconds: u64,
+        worked_this_week: u64,
+        hourly_rate: u64,
+    ) -> Result<ShiftPremium> {
+        let regular_capacity =
+            u64::from(self.overtime_threshold_seconds).saturating_sub(worked_this_week);
+        let overtime_seconds = duration_seconds.saturating_sub(regular_capacity);
+        let clocked_out_at = clocked_in_at
+            .checked_add(i64::try_from(duration_seconds).map_err(|_| ErrorCode::MathOverflow)?)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let night_seconds = self.night_seconds_between(clocked_in_at, clocked_out_at);
+
+        let premium_second_bps = u128::from(overtime_seconds)
+            * u128::from(self.overtime_multiplier_bps - BASIS_POINTS_DENOMINATOR)
+            + u128::from(night_seconds)
+                * u128::from(self.night_multiplier_bps - BASIS_POINTS_DENOMINATOR);
+        let premium_amount = premium_second_bps
+            .checked_mul(u128::from(hourly_rate))
+            .ok_or(ErrorCode::MathOverflow)?
+            / (SECONDS_PER_HOUR as u128 * u128::from(BASIS_POINTS_DENOMINATOR));
+
+        Ok(ShiftPremium {
+            workweek: self.workweek(clocked_in_at),
+            overtime_seconds,
+            night_seconds,
+            premium_amount: u64::try_from(premium_amount).map_err(|_| ErrorCode::MathOverflow)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PayMultipliers;
+    use crate::state::SECONDS_PER_DAY;
+
+    const HOUR: i64 = 60 * 60;
+    // Monday 2024-01-01 00:00 UTC.
+    const MONDAY: i64 = 1_704_067_200;
+
+    fn multipliers() -> PayMultipliers {
+        PayMultipliers {
+            overtime_threshold_seconds: 40 * 60 * 60,
+            overtime_multiplier_bps: 15_000,
+            night_start_minute: 22 * 60,
+            night_end_minute: 6 * 60,
+            night_multiplier_bps: 11_000,
+            utc_offset_minutes: 0,
+        }
+    }
+
+    #[test]
+    fn counts_night_time_across_midnight() {
+        let multipliers = multipliers();
+
+        // 20:00 Monday to 08:00 Tuesday covers 22:00-06:00.
+        assert_eq!(
+            multipliers.night_seconds_between(MONDAY + 20 * HOUR, MONDAY + 32 * HOUR),
+            8 * HOUR as u64
+        );
+        assert_eq!(
+            multipliers.night_seconds_between(MONDAY, MONDAY + 10 * SECONDS_PER_DAY),
+            80 * HOUR as u64
+        );
+        assert_eq!(
+            multipliers.night_seconds_between(MONDAY + 9 * HOUR, MONDAY + 17 * HOUR),
+            0
+        );
+    }
+
+    #[test]
+    fn prices_overtime_beyond_the_weekly_threshold_and_night_premiums() {
+        let multipliers = multipliers();
+
+        // A 10-hour day shift with 34 hours already worked: 4 hours overtime.
+        let premium = multipliers
+            .price_shift(
+                MONDAY + 4 * SECONDS_PER_DAY + 8 * HOUR,
+                10 * HOUR as u64,
+                34 * HOUR as u64,
+                100,
+            )
+            .unwrap();
+        assert_eq!(premium.overtime_seconds, 4 * HOUR as u64);
+        assert_eq!(premium.night_seconds, 0);
+        assert_eq!(premium.premium_amount, 4 * 50);
+
+        // An 8-hour night shift in a fresh week: 1.1x on all of it.
+        let premium = multipliers
+            .price_shift(MONDAY + 22 * HOUR, 8 * HOUR as u64, 0, 100)
+            .unwrap();
+        assert_eq!(premium.overtime_seconds, 0);
+        assert_eq!(premium.premium_amount, 8 * 10);
+    }
+
+    #[test]
+    fn assigns_shifts_to_monday_based_workweeks() {
+        let multipliers = multipliers();
+
+        assert_eq!(
+            multipliers.workweek(MONDAY),
+            multipliers.workweek(MONDAY + 7 * SECONDS_PER_DAY - 1)
+        );
+        assert_eq!(
+            multipliers.workweek(MONDAY) + 1,
+            multipliers.workweek(MONDAY + 7 * SECONDS_PER_DAY)
+        );
+    }
+
+    #[test]
+    fn rejects_discounting_multipliers() {
+        let multipliers = PayMultipliers {
+            overtime_multiplier_bps: 9_999,
+            ..multipliers()
+        };
+
+        assert!(multipliers.validate().is_err());
+    }
+}
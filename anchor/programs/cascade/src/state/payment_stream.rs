@@ -1,34 +1,1348 @@
 use crate::errors::ErrorCode;
+use crate::state::{utc_year, PayMultipliers, SalarySchedule, ShiftPremium, WeeklySchedule};
 use anchor_lang::prelude::*;
 
+pub const SECONDS_PER_MINUTE: i64 = 60;
+pub const SECONDS_PER_HOUR: i64 = 60 * 60;
+pub const MIN_INACTIVITY_THRESHOLD_SECONDS: i64 = 7 * 24 * 60 * 60;
+pub const MAX_INACTIVITY_THRESHOLD_SECONDS: i64 = 180 * 24 * 60 * 60;
+pub const EMERGENCY_WITHDRAW_NOTICE_SECONDS: i64 = 7 * 24 * 60 * 60;
+pub const EMPLOYEE_RECOVERY_DELAY_SECONDS: i64 = 7 * 24 * 60 * 60;
+pub const MAX_SESSION_KEY_DURATION_SECONDS: i64 = 90 * 24 * 60 * 60;
+pub const PAYOUT_DESTINATION_CHANGE_DELAY_SECONDS: i64 = 7 * 24 * 60 * 60;
+pub const MAX_PAYOUT_SPLITS: usize = 4;
+pub const BASIS_POINTS_DENOMINATOR: u16 = 10_000;
+pub const MAX_WITHHOLDING_RATE_BPS: u16 = 5_000;
+pub const MAX_DEDUCTIONS: usize = 4;
+pub const MAX_EMPLOYER_MATCH_BPS: u16 = 10_000;
+pub const DEFAULT_MAX_SHIFT_SECONDS: i64 = 12 * 60 * 60;
+pub const MIN_MAX_SHIFT_SECONDS: i64 = 60 * 60;
+pub const MAX_MAX_SHIFT_SECONDS: i64 = 24 * 60 * 60;
+
+/// How a stream's wages accrue.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace, PartialEq, Eq,
+)]
+pub enum AccrualMode {
+    /// Every whole hour since `accrual_started_at` is paid.
+    #[default]
+    WallClock,
+    /// Only hours approved through `submit_timesheet` are paid.
+    Timesheet,
+    /// Only time between `clock_in` and `clock_out` is paid.
+    ClockInOut,
+    /// Only time inside `weekly_schedule` is paid.
+    Schedule,
+}
+
+/// Whether a stream pays by the hour or a fixed salary per pay period.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace, PartialEq, Eq,
+)]
+pub enum StreamKind {
+    /// Wages accrue at `hourly_rate` under the stream's `accrual_mode`.
+    #[default]
+    Hourly,
+    /// A fixed amount per pay period; always accrues on the wall clock.
+    Salaried { salary: SalarySchedule },
+}
+
+impl StreamKind {
+    pub fn validate(&self, accrual_mode: AccrualMode) -> Result<()> {
+        if let StreamKind::Salaried { salary } = self {
+            salary.validate()?;
+            require!(
+                accrual_mode == AccrualMode::WallClock,
+                ErrorCode::SalariedAccrualMode
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Where the funds for a bonus come from.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BonusSource {
+    /// A fresh deposit from the employer's token account.
+    Employer,
+    /// Vault funding the employee has not yet earned.
+    VaultSurplus,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub struct PendingEmployeeRecovery {
+    pub new_employee: Pubkey,
+    pub executable_at: i64,
+}
+
+/// A delegated key that may only call `refresh_activity`, never `withdraw`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub struct SessionKey {
+    pub key: Pubkey,
+    pub expires_at: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub struct PendingPayoutDestination {
+    pub token_account: Pubkey,
+    pub effective_at: i64,
+}
+
+/// Routes `share_bps` of every withdrawal to `destination`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub struct PayoutSplit {
+    pub destination: Pubkey,
+    pub share_bps: u16,
+}
+
+/// Employer-mandated tax withholding applied to every withdrawal.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub struct WithholdingRule {
+    pub rate_bps: u16,
+    pub destination: Pubkey,
+}
+
+impl WithholdingRule {
+    /// Withholds `rate_bps` of every withdrawal into `destination`, a token
+    /// account that must hold the stream's mint.
+    pub fn new(
+        rate_bps: u16,
+        destination: Pubkey,
+        destination_mint: Pubkey,
+        stream_mint: Pubkey,
+    ) -> Result<Self> {
+        require!(
+            rate_bps > 0 && rate_bps <= MAX_WITHHOLDING_RATE_BPS,
+            ErrorCode::InvalidWithholdingRate
+        );
+        require_keys_eq!(
+            destination_mint,
+            stream_mint,
+            ErrorCode::InvalidTokenAccount
+        );
+        Ok(Self {
+            rate_bps,
+            destination,
+        })
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub enum DeductionBasis {
+    /// Share of every withdrawal.
+    Percent { rate_bps: u16 },
+    /// Tokens per hour of wages withdrawn, pro-rated by `hourly_rate`.
+    FixedPerHour { amount: u64 },
+}
+
+/// A pre-agreed payroll deduction such as a retirement contribution.
+/// `employer_match_bps` of the deducted amount is paid on top from the vault.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub struct Deduction {
+    pub destination: Pubkey,
+    pub basis: DeductionBasis,
+    pub employer_match_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace, PartialEq, Eq)]
+pub struct PendingPayoutSplits {
+    #[max_len(MAX_PAYOUT_SPLITS)]
+    pub splits: Vec<PayoutSplit>,
+    pub effective_at: i64,
+}
+
 #[account]
-#[derive(InitSpace)]
+#[derive(Default, InitSpace)]
 pub struct PaymentStream {
+    /// Current employer authority. May change through an employer transfer.
     pub employer: Pubkey,
+    /// Employer at creation. Used in the stream PDA seeds and never changes.
+    pub original_employer: Pubkey,
+    pub pending_employer: Option<Pubkey>,
+    /// Current employee. May change through rotation or recovery.
     pub employee: Pubkey,
+    /// Employee at creation. Used in the stream PDA seeds and never changes.
+    pub original_employee: Pubkey,
+    pub pending_employee_recovery: Option<PendingEmployeeRecovery>,
+    pub session_key: Option<SessionKey>,
     pub mint: Pubkey,
     pub vault: Pubkey,
+    /// Token account `withdraw` must pay into. `None` means any token account
+    /// owned by the employee.
+    pub payout_destination: Option<Pubkey>,
+    pub pending_payout_destination: Option<PendingPayoutDestination>,
+    #[max_len(MAX_PAYOUT_SPLITS)]
+    pub payout_splits: Vec<PayoutSplit>,
+    pub pending_payout_splits: Option<PendingPayoutSplits>,
+    pub withholding: Option<WithholdingRule>,
+    /// Cumulative amount withheld over the life of the stream. Included in
+    /// `withdrawn_amount`, since it left the vault on the employee's behalf.
+    pub total_withheld: u64,
+    /// Calendar year (UTC) that `withheld_this_year` covers.
+    pub withholding_year: i64,
+    /// Amount withheld during `withholding_year`, for year-end reporting.
+    /// Starts again from zero with the first withdrawal of a new year.
+    pub withheld_this_year: u64,
+    #[max_len(MAX_DEDUCTIONS)]
+    pub deductions: Vec<Deduction>,
+    /// Cumulative employee-side deductions. Included in `withdrawn_amount`.
+    pub total_deducted: u64,
+    /// Cumulative employer match paid out of the vault. Not part of
+    /// `withdrawn_amount`, since it never counted towards the employee's wages.
+    pub total_employer_matched: u64,
+    /// Only this key may issue garnishment orders against the stream.
+    pub garnishment_issuer: Option<Pubkey>,
+    /// The order `withdraw` must honour. At most one is active at a time.
+    pub active_garnishment: Option<Pubkey>,
+    pub garnishment_count: u32,
+    /// Cumulative garnished amount. Included in `withdrawn_amount`.
+    pub total_garnished: u64,
+    /// Cumulative protocol fees charged on this stream's deposits and
+    /// withdrawals, for reporting. Deposit fees never reach `total_deposited`;
+    /// withdrawal fees are included in `withdrawn_amount`.
+    pub total_protocol_fees: u64,
+    /// Employer-approved ceiling on wages the employee may draw ahead of accrual.
+    pub advance_limit: u64,
+    /// Amount drawn beyond what has accrued. Repaid automatically as wages
+    /// accrue, since `withdraw` only pays out earnings above `withdrawn_amount`.
+    pub outstanding_advance: u64,
+    pub total_advanced: u64,
+    /// One-off bonuses, withdrawable as soon as they are awarded.
+    pub total_bonus_awarded: u64,
+    /// Number of milestones ever funded; the next Milestone PDA index.
+    pub milestone_count: u32,
+    /// Funded milestones not yet approved. Held in the vault but not part of
+    /// `total_deposited` until released.
+    pub milestone_escrow: u64,
+    /// Approved milestones, withdrawable as soon as they are released.
+    pub total_milestones_released: u64,
+    /// Number of expense claims ever submitted; the next ExpenseClaim PDA index.
+    pub expense_claim_count: u32,
+    /// Cumulative reimbursements, from the vault or the employer.
+    pub total_expenses_reimbursed: u64,
+    /// Reimbursements paid out of vault funding rather than as wages.
+    pub expenses_paid_from_vault: u64,
+    pub kind: StreamKind,
+    /// Salary periods since `accrual_started_at` already banked in
+    /// `accrued_amount`. Period boundaries are always counted from
+    /// `accrual_started_at`, so monthly pay keeps its original day of month.
+    pub salary_periods_paid: u64,
+    pub accrual_mode: AccrualMode,
+    /// Earnings banked so far: approved timesheets, plus wall-clock accrual
+    /// carried over when the accrual mode changes.
+    pub accrued_amount: u64,
+    /// May submit timesheets in addition to the employer.
+    pub timesheet_approver: Option<Pubkey>,
+    /// Timesheet periods must be submitted in order and may not overlap.
+    pub last_timesheet_period_end: i64,
+    /// Longest a single shift can accrue; forgotten clock-ins stop here.
+    pub max_shift_seconds: i64,
+    /// Start of the open shift, if the employee is clocked in.
+    pub clocked_in_at: Option<i64>,
+    /// Number of shifts ever started; the next shift's PDA index.
+    pub shift_count: u32,
+    /// Closed, non-voided shift time not yet banked in `accrued_amount`.
+    pub shift_seconds: u64,
+    /// Overtime and night premiums for clock-in streams.
+    pub pay_multipliers: Option<PayMultipliers>,
+    /// Workweek that `workweek_seconds` counts towards.
+    pub overtime_workweek: i64,
+    pub workweek_seconds: u64,
+    /// Premiums for closed shifts not yet banked in `accrued_amount`.
+    pub shift_premium_amount: u64,
+    /// Cumulative premiums earned, for reporting.
+    pub total_premium_earned: u64,
+    pub weekly_schedule: WeeklySchedule,
     pub hourly_rate: u64,
     pub total_deposited: u64,
     pub withdrawn_amount: u64,
     pub created_at: i64,
+    /// Earnings accrue from this timestamp: creation time, or acceptance time
+    /// for streams created with `require_acceptance`.
+    pub accrual_started_at: i64,
+    pub awaiting_acceptance: bool,
     pub employee_last_activity_at: i64,
+    pub inactivity_threshold_seconds: i64,
+    pub emergency_withdraw_announced_at: Option<i64>,
     pub is_active: bool,
     pub bump: u8,
 }
 
 impl PaymentStream {
-    pub fn assert_accounting_invariant(&self) -> Result<()> {
+    pub fn validate_inactivity_threshold(inactivity_threshold_seconds: i64) -> Result<()> {
         require!(
-            self.withdrawn_amount <= self.total_deposited,
-            ErrorCode::InvalidStreamAccounting
+            (MIN_INACTIVITY_THRESHOLD_SECONDS..=MAX_INACTIVITY_THRESHOLD_SECONDS)
+                .contains(&inactivity_threshold_seconds),
+            ErrorCode::InvalidInactivityThreshold
         );
         Ok(())
     }
 
-    pub fn expected_vault_balance(&self) -> Result<u64> {
+    /// Records employee activity and cancels any pending emergency withdrawal.
+    /// Returns the announcement timestamp of the cancelled withdrawal, if any.
+    pub fn record_employee_activity(&mut self, timestamp: i64) -> Option<i64> {
+        self.employee_last_activity_at = timestamp;
+        self.emergency_withdraw_announced_at.take()
+    }
+
+    /// Hands the stream to a new employee key and returns the previous one.
+    /// Payout routing and the session key were chosen by the old key, so they
+    /// are cleared rather than inherited; so is any pending recovery.
+    pub fn replace_employee(&mut self, new_employee: Pubkey) -> Pubkey {
+        self.pending_employee_recovery = None;
+        self.session_key = None;
+        self.payout_destination = None;
+        self.pending_payout_destination = None;
+        self.payout_splits.clear();
+        self.pending_payout_splits = None;
+        std::mem::replace(&mut self.employee, new_employee)
+    }
+
+    /// Starts accrual on the employee's acceptance. Acceptance counts as
+    /// activity, so the announcement timestamp of any cancelled emergency
+    /// withdrawal is returned.
+    pub fn accept(&mut self, timestamp: i64) -> Result<Option<i64>> {
+        require!(self.awaiting_acceptance, ErrorCode::StreamAlreadyAccepted);
+        self.awaiting_acceptance = false;
+        self.accrual_started_at = timestamp;
+        Ok(self.record_employee_activity(timestamp))
+    }
+
+    /// Nominates a new employer. Proposing again replaces the nominee;
+    /// proposing the current employer cancels the transfer.
+    pub fn propose_employer(&mut self, new_employer: Pubkey) {
+        self.pending_employer = (new_employer != self.employer).then_some(new_employer);
+    }
+
+    /// Hands the stream to the pending nominee and returns the previous employer.
+    pub fn accept_employer(&mut self, new_employer: Pubkey) -> Result<Pubkey> {
+        require!(
+            self.pending_employer == Some(new_employer),
+            ErrorCode::UnauthorizedPendingEmployer
+        );
+        let previous_employer = self.employer;
+        self.employer = new_employer;
+        self.pending_employer = None;
+        Ok(previous_employer)
+    }
+
+    pub fn is_valid_session_key(&self, key: &Pubkey, current_timestamp: i64) -> bool {
+        self.session_key.is_some_and(|session_key| {
+            session_key.key == *key && current_timestamp < session_key.expires_at
+        })
+    }
+
+    /// Promotes a pending payout destination once its time lock has elapsed
+    /// and returns the destination currently in force.
+    pub fn apply_pending_payout_destination(&mut self, current_timestamp: i64) -> Option<Pubkey> {
+        if let Some(pending) = self.pending_payout_destination {
+            if current_timestamp >= pending.effective_at {
+                self.payout_destination = Some(pending.token_account);
+                self.pending_payout_destination = None;
+            }
+        }
+        self.payout_destination
+    }
+
+    pub fn validate_payout_splits(splits: &[PayoutSplit]) -> Result<()> {
+        require!(
+            splits.len() <= MAX_PAYOUT_SPLITS,
+            ErrorCode::InvalidPayoutSplits
+        );
+        let mut total_share_bps: u16 = 0;
+        for (index, split) in splits.iter().enumerate() {
+            require!(split.share_bps > 0, ErrorCode::InvalidPayoutSplits);
+            require!(
+                !splits[..index]
+                    .iter()
+                    .any(|other| other.destination == split.destination),
+                ErrorCode::InvalidPayoutSplits
+            );
+            total_share_bps = total_share_bps
+                .checked_add(split.share_bps)
+                .ok_or(ErrorCode::InvalidPayoutSplits)?;
+        }
+        require!(
+            total_share_bps <= BASIS_POINTS_DENOMINATOR,
+            ErrorCode::InvalidPayoutSplits
+        );
+        Ok(())
+    }
+
+    /// Promotes pending payout splits once their time lock has elapsed.
+    pub fn apply_pending_payout_splits(&mut self, current_timestamp: i64) {
+        if let Some(pending) = &self.pending_payout_splits {
+            if current_timestamp >= pending.effective_at {
+                self.payout_splits = pending.splits.clone();
+                self.pending_payout_splits = None;
+            }
+        }
+    }
+
+    /// Per-hour deductions are pro-rated by `hourly_rate`, which salaried
+    /// streams do not use, so they are only allowed on hourly streams.
+    pub fn validate_deductions(
+        deductions: &[Deduction],
+        kind: &StreamKind,
+        hourly_rate: u64,
+    ) -> Result<()> {
+        require!(
+            deductions.len() <= MAX_DEDUCTIONS,
+            ErrorCode::InvalidDeductions
+        );
+        for (index, deduction) in deductions.iter().enumerate() {
+            if matches!(deduction.basis, DeductionBasis::FixedPerHour { .. }) {
+                require!(
+                    *kind == StreamKind::Hourly,
+                    ErrorCode::HourlyDeductionOnSalariedStream
+                );
+            }
+            let valid_basis = match deduction.basis {
+                DeductionBasis::Percent { rate_bps } => {
+                    rate_bps > 0 && rate_bps <= BASIS_POINTS_DENOMINATOR
+                }
+                DeductionBasis::FixedPerHour { amount } => amount > 0 && amount <= hourly_rate,
+            };
+            require!(valid_basis, ErrorCode::InvalidDeductions);
+            require!(
+                deduction.employer_match_bps <= MAX_EMPLOYER_MATCH_BPS,
+                ErrorCode::InvalidDeductions
+            );
+            require!(
+                !deductions[..index]
+                    .iter()
+                    .any(|other| other.destination == deduction.destination),
+                ErrorCode::InvalidDeductions
+            );
+        }
+        Ok(())
+    }
+
+    /// Pay earned so far: banked earnings plus whatever the accrual mode has
+    /// accrued since, capped at the deposits left for wages once lump sums and
+    /// vault-paid reimbursements are set aside, plus every bonus and released
+    /// milestone.
+    pub fn earned_amount(&self, current_timestamp: i64) -> Result<u64> {
+        let earned_uncapped = self
+            .accrued_amount
+            .checked_add(self.live_accrual(current_timestamp)?)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let lump_sums = self
+            .total_bonus_awarded
+            .checked_add(self.total_milestones_released)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let wage_cap = self
+            .wage_funding()?
+            .checked_sub(lump_sums)
+            .and_then(|cap| cap.checked_sub(self.expenses_paid_from_vault))
+            .ok_or(ErrorCode::InvalidStreamAccounting)?;
+        earned_uncapped
+            .min(wage_cap)
+            .checked_add(lump_sums)
+            .ok_or(ErrorCode::MathOverflow.into())
+    }
+
+    /// Moves an approved milestone out of escrow and into the employee's
+    /// withdrawable balance.
+    pub fn release_milestone(&mut self, amount: u64) -> Result<()> {
+        self.milestone_escrow = self
+            .milestone_escrow
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InvalidStreamAccounting)?;
+        self.total_deposited = self
+            .total_deposited
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.total_milestones_released = self
+            .total_milestones_released
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Returns a cancelled milestone's escrow to the employer.
+    pub fn refund_milestone(&mut self, amount: u64) -> Result<()> {
+        self.milestone_escrow = self
+            .milestone_escrow
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InvalidStreamAccounting)?;
+        Ok(())
+    }
+
+    /// Escrow belongs to the contractor until each milestone is approved or
+    /// cancelled, so it must be resolved before the vault is emptied.
+    pub fn assert_milestones_resolved(&self) -> Result<()> {
+        require!(self.milestone_escrow == 0, ErrorCode::MilestonesOutstanding);
+        Ok(())
+    }
+
+    /// Funding the employee has neither earned nor withdrawn.
+    pub fn unearned_funding(&self, current_timestamp: i64) -> Result<u64> {
+        let claimed = self
+            .earned_amount(current_timestamp)?
+            .max(self.withdrawn_amount);
+        self.wage_funding()?
+            .checked_sub(self.expenses_paid_from_vault)
+            .and_then(|funding| funding.checked_sub(claimed))
+            .ok_or(ErrorCode::InvalidStreamAccounting.into())
+    }
+
+    /// Records a reimbursement paid out of the vault. Only funding the
+    /// employee has not yet earned may be spent, so earned wages are never
+    /// reduced.
+    pub fn pay_expense_from_vault(&mut self, amount: u64, current_timestamp: i64) -> Result<()> {
+        require!(
+            amount <= self.unearned_funding(current_timestamp)?,
+            ErrorCode::InsufficientVaultSurplus
+        );
+        self.expenses_paid_from_vault = self
+            .expenses_paid_from_vault
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Accrual not yet banked in `accrued_amount`. Wall-clock streams pay
+    /// whole hours since `accrual_started_at`.
+    fn live_accrual(&self, current_timestamp: i64) -> Result<u64> {
+        // Nothing accrues before the employee accepts the stream.
+        if self.awaiting_acceptance {
+            return Ok(0);
+        }
+        if let StreamKind::Salaried { salary } = self.kind {
+            let banked = self
+                .salary_periods_paid
+                .checked_mul(salary.period_amount)
+                .ok_or(ErrorCode::MathOverflow)?;
+            return salary
+                .vested_between(self.accrual_started_at, current_timestamp)?
+                .checked_sub(banked)
+                .ok_or(ErrorCode::InvalidStreamAccounting.into());
+        }
+        match self.accrual_mode {
+            AccrualMode::WallClock => {
+                let seconds_elapsed = current_timestamp
+                    .checked_sub(self.accrual_started_at)
+                    .ok_or(ErrorCode::InvalidTimestamp)?;
+                let hours_elapsed = seconds_elapsed / SECONDS_PER_HOUR;
+                (hours_elapsed as u64)
+                    .checked_mul(self.hourly_rate)
+                    .ok_or(ErrorCode::MathOverflow.into())
+            }
+            AccrualMode::Timesheet => Ok(0),
+            AccrualMode::ClockInOut => {
+                let open_shift_seconds = match self.clocked_in_at {
+                    Some(clocked_in_at) => self.shift_duration(clocked_in_at, current_timestamp)?,
+                    None => 0,
+                };
+                self.wages_for_seconds(
+                    self.shift_seconds
+                        .checked_add(open_shift_seconds)
+                        .ok_or(ErrorCode::MathOverflow)?,
+                )?
+                .checked_add(self.shift_premium_amount)
+                .ok_or(ErrorCode::MathOverflow.into())
+            }
+            AccrualMode::Schedule => self.wages_for_seconds(
+                self.weekly_schedule
+                    .scheduled_seconds_between(self.accrual_started_at, current_timestamp)?,
+            ),
+        }
+    }
+
+    /// Adds a closed shift's paid time to the stream and prices its premiums
+    /// against the running total of its workweek.
+    pub fn record_closed_shift(
+        &mut self,
+        clocked_in_at: i64,
+        duration_seconds: u64,
+    ) -> Result<ShiftPremium> {
+        self.shift_seconds = self
+            .shift_seconds
+            .checked_add(duration_seconds)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        let Some(pay_multipliers) = self.pay_multipliers else {
+            return Ok(ShiftPremium::default());
+        };
+        let workweek = pay_multipliers.workweek(clocked_in_at);
+        if workweek != self.overtime_workweek {
+            self.overtime_workweek = workweek;
+            self.workweek_seconds = 0;
+        }
+        let premium = pay_multipliers.price_shift(
+            clocked_in_at,
+            duration_seconds,
+            self.workweek_seconds,
+            self.hourly_rate,
+        )?;
+        self.workweek_seconds = self
+            .workweek_seconds
+            .checked_add(duration_seconds)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.shift_premium_amount = self
+            .shift_premium_amount
+            .checked_add(premium.premium_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.total_premium_earned = self
+            .total_premium_earned
+            .checked_add(premium.premium_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(premium)
+    }
+
+    /// Paid length of a shift, capped at `max_shift_seconds`.
+    pub fn shift_duration(&self, clocked_in_at: i64, clocked_out_at: i64) -> Result<u64> {
+        let seconds = clocked_out_at
+            .checked_sub(clocked_in_at)
+            .ok_or(ErrorCode::InvalidTimestamp)?
+            .min(self.max_shift_seconds);
+        u64::try_from(seconds).map_err(|_| ErrorCode::InvalidTimestamp.into())
+    }
+
+    /// Banks live accrual into `accrued_amount` and restarts it from
+    /// `current_timestamp`, keeping any partial wall-clock hour.
+    pub fn checkpoint_accrual(&mut self, current_timestamp: i64) -> Result<()> {
+        require!(self.clocked_in_at.is_none(), ErrorCode::ShiftInProgress);
+        if let StreamKind::Salaried { salary } = self.kind {
+            // Bank whole pay periods only, so the current period keeps its
+            // progress. The anchor stays put and later periods stay on the
+            // same boundaries.
+            let completed = if self.awaiting_acceptance {
+                0
+            } else {
+                salary.completed_periods(self.accrual_started_at, current_timestamp)?
+            };
+            let periods = completed.saturating_sub(self.salary_periods_paid);
+            self.accrued_amount = periods
+                .checked_mul(salary.period_amount)
+                .and_then(|vested| self.accrued_amount.checked_add(vested))
+                .ok_or(ErrorCode::MathOverflow)?;
+            self.salary_periods_paid = self.salary_periods_paid.max(completed);
+            return Ok(());
+        }
+        let live_accrual = self.live_accrual(current_timestamp)?;
+        self.accrued_amount = self
+            .accrued_amount
+            .checked_add(live_accrual)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.shift_seconds = 0;
+        self.shift_premium_amount = 0;
+        if self.accrual_mode == AccrualMode::WallClock {
+            let seconds_elapsed = current_timestamp
+                .checked_sub(self.accrual_started_at)
+                .ok_or(ErrorCode::InvalidTimestamp)?;
+            self.accrual_started_at = current_timestamp - seconds_elapsed % SECONDS_PER_HOUR;
+        } else {
+            self.accrual_started_at = current_timestamp;
+        }
+        Ok(())
+    }
+
+    /// Banks earnings under the current kind and switches to `kind`. A salary
+    /// that keeps its pay period keeps its anchor and banked periods, so a
+    /// raise applies from the current period on. Otherwise accrual restarts
+    /// at the start of the current period, which is paid under the new kind.
+    pub fn set_kind(&mut self, kind: StreamKind, current_timestamp: i64) -> Result<()> {
+        self.checkpoint_accrual(current_timestamp)?;
+        if let StreamKind::Salaried { salary } = self.kind {
+            let keeps_pay_period = matches!(
+                kind,
+                StreamKind::Salaried { salary: new_salary }
+                    if new_salary.pay_period == salary.pay_period
+            );
+            if !keeps_pay_period {
+                self.accrual_started_at =
+                    salary.period_start(self.accrual_started_at, self.salary_periods_paid)?;
+                self.salary_periods_paid = 0;
+            }
+        }
+        self.kind = kind;
+        Ok(())
+    }
+
+    /// Pay for `minutes` at `hourly_rate`, rounded down.
+    pub fn wages_for_minutes(&self, minutes: u32) -> Result<u64> {
+        self.wages_for_seconds(u64::from(minutes) * SECONDS_PER_MINUTE as u64)
+    }
+
+    /// Pay for `seconds` at `hourly_rate`, rounded down.
+    pub fn wages_for_seconds(&self, seconds: u64) -> Result<u64> {
+        let wages = u128::from(seconds)
+            .checked_mul(u128::from(self.hourly_rate))
+            .ok_or(ErrorCode::MathOverflow)?
+            / SECONDS_PER_HOUR as u128;
+        u64::try_from(wages).map_err(|_| ErrorCode::MathOverflow.into())
+    }
+
+    /// Recomputes the outstanding advance against `total_earned` and returns
+    /// how much of it accrued wages have repaid since the last settlement.
+    pub fn settle_outstanding_advance(&mut self, total_earned: u64) -> u64 {
+        let outstanding_advance = self.withdrawn_amount.saturating_sub(total_earned);
+        let repaid = self.outstanding_advance.saturating_sub(outstanding_advance);
+        self.outstanding_advance = outstanding_advance;
+        repaid
+    }
+
+    /// Adds a withdrawal's withholding to the lifetime and current-year
+    /// totals, rolling the year total over when the calendar year changes.
+    pub fn record_withholding(
+        &mut self,
+        withheld_amount: u64,
+        current_timestamp: i64,
+    ) -> Result<()> {
+        let year = utc_year(current_timestamp);
+        if year != self.withholding_year {
+            self.withholding_year = year;
+            self.withheld_this_year = 0;
+        }
+        self.withheld_this_year = self
+            .withheld_this_year
+            .checked_add(withheld_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.total_withheld = self
+            .total_withheld
+            .checked_add(withheld_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Deposits available for wages, i.e. everything not spent on employer
+    /// match.
+    pub fn wage_funding(&self) -> Result<u64> {
         self.total_deposited
-            .checked_sub(self.withdrawn_amount)
+            .checked_sub(self.total_employer_matched)
+            .ok_or(ErrorCode::InvalidStreamAccounting.into())
+    }
+
+    /// Withdrawals and vault-paid reimbursements never exceed wage funding.
+    pub fn assert_accounting_invariant(&self) -> Result<()> {
+        self.expected_vault_balance().map(|_| ())
+    }
+
+    pub fn expected_vault_balance(&self) -> Result<u64> {
+        self.wage_funding()?
+            .checked_sub(self.expenses_paid_from_vault)
+            .and_then(|balance| balance.checked_sub(self.withdrawn_amount))
+            .and_then(|balance| balance.checked_add(self.milestone_escrow))
             .ok_or(ErrorCode::InvalidStreamAccounting.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AccrualMode, Deduction, DeductionBasis, PayMultipliers, PaymentStream, PayoutSplit,
+        PendingEmployeeRecovery, PendingPayoutDestination, PendingPayoutSplits, SalarySchedule,
+        SessionKey, StreamKind, WithholdingRule, MAX_INACTIVITY_THRESHOLD_SECONDS,
+        MAX_WITHHOLDING_RATE_BPS, MIN_INACTIVITY_THRESHOLD_SECONDS, SECONDS_PER_HOUR,
+    };
+    use crate::errors::ErrorCode;
+    use crate::state::{PayPeriod, SalaryVesting};
+    use anchor_lang::prelude::Pubkey;
+
+    #[test]
+    fn inactivity_threshold_bounds_are_inclusive() {
+        let day = 24 * SECONDS_PER_HOUR;
+        assert_eq!(MIN_INACTIVITY_THRESHOLD_SECONDS, 7 * day);
+        assert_eq!(MAX_INACTIVITY_THRESHOLD_SECONDS, 180 * day);
+
+        assert!(PaymentStream::validate_inactivity_threshold(7 * day).is_ok());
+        assert!(PaymentStream::validate_inactivity_threshold(180 * day).is_ok());
+        assert!(PaymentStream::validate_inactivity_threshold(7 * day - 1).is_err());
+        assert!(PaymentStream::validate_inactivity_threshold(180 * day + 1).is_err());
+    }
+
+    #[test]
+    fn employee_activity_cancels_pending_emergency_withdraw() {
+        let mut stream = PaymentStream {
+            employee_last_activity_at: 0,
+            emergency_withdraw_announced_at: Some(100),
+            is_active: true,
+            ..Default::default()
+        };
+
+        assert_eq!(stream.record_employee_activity(200), Some(100));
+        assert_eq!(stream.employee_last_activity_at, 200);
+        assert_eq!(stream.emergency_withdraw_announced_at, None);
+        assert_eq!(stream.record_employee_activity(300), None);
+    }
+
+    #[test]
+    fn nothing_accrues_until_the_stream_is_accepted() {
+        let mut stream = PaymentStream {
+            hourly_rate: 100,
+            total_deposited: 10_000,
+            accrual_started_at: 0,
+            awaiting_acceptance: true,
+            emergency_withdraw_announced_at: Some(50),
+            is_active: true,
+            ..Default::default()
+        };
+        assert_eq!(stream.earned_amount(10 * SECONDS_PER_HOUR).unwrap(), 0);
+
+        let accepted_at = 10 * SECONDS_PER_HOUR;
+        assert_eq!(stream.accept(accepted_at).unwrap(), Some(50));
+        assert_eq!(stream.accrual_started_at, accepted_at);
+        assert_eq!(stream.employee_last_activity_at, accepted_at);
+        assert_eq!(stream.emergency_withdraw_announced_at, None);
+        // Hours spent awaiting acceptance are not paid.
+        assert_eq!(stream.earned_amount(accepted_at).unwrap(), 0);
+        assert_eq!(
+            stream
+                .earned_amount(accepted_at + 2 * SECONDS_PER_HOUR)
+                .unwrap(),
+            200
+        );
+        assert!(stream.accept(accepted_at + 1).is_err());
+    }
+
+    #[test]
+    fn replacing_the_employee_clears_their_payout_routing() {
+        let previous_employee = Pubkey::new_unique();
+        let new_employee = Pubkey::new_unique();
+        let splits = vec![PayoutSplit {
+            destination: Pubkey::new_unique(),
+            share_bps: 5_000,
+        }];
+        let mut stream = PaymentStream {
+            employee: previous_employee,
+            pending_employee_recovery: Some(PendingEmployeeRecovery {
+                new_employee,
+                executable_at: 0,
+            }),
+            session_key: Some(SessionKey {
+                key: Pubkey::new_unique(),
+                expires_at: 1_000,
+            }),
+            payout_destination: Some(Pubkey::new_unique()),
+            pending_payout_destination: Some(PendingPayoutDestination {
+                token_account: Pubkey::new_unique(),
+                effective_at: 1_000,
+            }),
+            payout_splits: splits.clone(),
+            pending_payout_splits: Some(PendingPayoutSplits {
+                splits,
+                effective_at: 1_000,
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(stream.replace_employee(new_employee), previous_employee);
+        assert_eq!(stream.employee, new_employee);
+        assert_eq!(stream.pending_employee_recovery, None);
+        assert_eq!(stream.session_key, None);
+        assert_eq!(stream.payout_destination, None);
+        assert_eq!(stream.pending_payout_destination, None);
+        assert!(stream.payout_splits.is_empty());
+        assert_eq!(stream.pending_payout_splits, None);
+    }
+
+    #[test]
+    fn only_the_latest_nominee_can_accept_an_employer_transfer() {
+        let employer = Pubkey::new_unique();
+        let first_nominee = Pubkey::new_unique();
+        let second_nominee = Pubkey::new_unique();
+        let mut stream = PaymentStream {
+            employer,
+            ..Default::default()
+        };
+
+        stream.propose_employer(first_nominee);
+        stream.propose_employer(second_nominee);
+        assert_eq!(stream.pending_employer, Some(second_nominee));
+        assert!(stream.accept_employer(first_nominee).is_err());
+        assert!(stream.accept_employer(employer).is_err());
+
+        assert_eq!(stream.accept_employer(second_nominee).unwrap(), employer);
+        assert_eq!(stream.employer, second_nominee);
+        assert_eq!(stream.pending_employer, None);
+        assert!(stream.accept_employer(second_nominee).is_err());
+    }
+
+    #[test]
+    fn proposing_the_current_employer_cancels_the_transfer() {
+        let employer = Pubkey::new_unique();
+        let nominee = Pubkey::new_unique();
+        let mut stream = PaymentStream {
+            employer,
+            ..Default::default()
+        };
+
+        stream.propose_employer(nominee);
+        stream.propose_employer(employer);
+        assert_eq!(stream.pending_employer, None);
+        assert!(stream.accept_employer(nominee).is_err());
+    }
+
+    #[test]
+    fn session_key_is_valid_only_for_its_key_until_expiry() {
+        let session_key = Pubkey::new_unique();
+        let stream = PaymentStream {
+            session_key: Some(SessionKey {
+                key: session_key,
+                expires_at: 1_000,
+            }),
+            ..Default::default()
+        };
+
+        assert!(stream.is_valid_session_key(&session_key, 999));
+        assert!(!stream.is_valid_session_key(&session_key, 1_000));
+        assert!(!stream.is_valid_session_key(&Pubkey::new_unique(), 999));
+        assert!(!PaymentStream::default().is_valid_session_key(&session_key, 0));
+    }
+
+    #[test]
+    fn promotes_pending_payout_destination_after_time_lock() {
+        let current_destination = Pubkey::new_unique();
+        let new_destination = Pubkey::new_unique();
+        let mut stream = PaymentStream {
+            payout_destination: Some(current_destination),
+            pending_payout_destination: Some(PendingPayoutDestination {
+                token_account: new_destination,
+                effective_at: 1_000,
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            stream.apply_pending_payout_destination(999),
+            Some(current_destination)
+        );
+        assert!(stream.pending_payout_destination.is_some());
+
+        assert_eq!(
+            stream.apply_pending_payout_destination(1_000),
+            Some(new_destination)
+        );
+        assert_eq!(stream.pending_payout_destination, None);
+    }
+
+    #[test]
+    fn validates_payout_split_shares() {
+        let split = |share_bps| PayoutSplit {
+            destination: Pubkey::new_unique(),
+            share_bps,
+        };
+
+        assert!(PaymentStream::validate_payout_splits(&[split(2_500), split(7_500)]).is_ok());
+        assert!(PaymentStream::validate_payout_splits(&[split(5_000), split(5_001)]).is_err());
+        assert!(PaymentStream::validate_payout_splits(&[split(0)]).is_err());
+
+        let duplicate = split(1_000);
+        assert!(PaymentStream::validate_payout_splits(&[duplicate, duplicate]).is_err());
+    }
+
+    #[test]
+    fn accrued_wages_repay_an_outstanding_advance() {
+        let mut stream = PaymentStream {
+            hourly_rate: 10,
+            total_deposited: 1_000,
+            withdrawn_amount: 50,
+            outstanding_advance: 50,
+            ..Default::default()
+        };
+
+        let earned = stream.earned_amount(3 * SECONDS_PER_HOUR + 59).unwrap();
+        assert_eq!(earned, 30);
+        assert_eq!(stream.settle_outstanding_advance(earned), 30);
+        assert_eq!(stream.outstanding_advance, 20);
+
+        let earned = stream.earned_amount(200 * SECONDS_PER_HOUR).unwrap();
+        assert_eq!(earned, 1_000);
+        assert_eq!(stream.settle_outstanding_advance(earned), 20);
+        assert_eq!(stream.outstanding_advance, 0);
+    }
+
+    #[test]
+    fn switching_accrual_mode_banks_wall_clock_earnings() {
+        let mut stream = PaymentStream {
+            hourly_rate: 60,
+            total_deposited: 10_000,
+            ..Default::default()
+        };
+
+        stream
+            .checkpoint_accrual(2 * SECONDS_PER_HOUR + 1_800)
+            .unwrap();
+        assert_eq!(stream.accrued_amount, 120);
+        assert_eq!(stream.accrual_started_at, 2 * SECONDS_PER_HOUR);
+        assert_eq!(stream.earned_amount(3 * SECONDS_PER_HOUR).unwrap(), 180);
+
+        stream.checkpoint_accrual(3 * SECONDS_PER_HOUR).unwrap();
+        stream.accrual_mode = AccrualMode::Timesheet;
+        assert_eq!(stream.earned_amount(100 * SECONDS_PER_HOUR).unwrap(), 180);
+        assert_eq!(stream.wages_for_minutes(90).unwrap(), 90);
+    }
+
+    #[test]
+    fn open_shift_accrues_only_up_to_the_maximum_shift_length() {
+        let stream = PaymentStream {
+            hourly_rate: 3_600,
+            total_deposited: u64::MAX,
+            accrual_mode: AccrualMode::ClockInOut,
+            max_shift_seconds: 8 * SECONDS_PER_HOUR,
+            shift_seconds: 90,
+            clocked_in_at: Some(1_000),
+            ..Default::default()
+        };
+
+        assert_eq!(stream.earned_amount(1_010).unwrap(), 100);
+        // A forgotten clock-in stops accruing after 8 hours.
+        assert_eq!(
+            stream.earned_amount(1_000 + 30 * SECONDS_PER_HOUR).unwrap(),
+            90 + 8 * 3_600
+        );
+        assert!(stream.clone().checkpoint_accrual(2_000).is_err());
+    }
+
+    #[test]
+    fn closed_shifts_accrue_overtime_within_their_workweek() {
+        // Monday 2024-01-01 00:00 UTC.
+        let monday = 1_704_067_200;
+        let mut stream = PaymentStream {
+            hourly_rate: 100,
+            total_deposited: u64::MAX,
+            accrual_mode: AccrualMode::ClockInOut,
+            pay_multipliers: Some(PayMultipliers {
+                overtime_threshold_seconds: 40 * 60 * 60,
+                overtime_multiplier_bps: 15_000,
+                night_multiplier_bps: 10_000,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let ten_hours = 10 * SECONDS_PER_HOUR as u64;
+
+        for day in 0..5 {
+            stream
+                .record_closed_shift(monday + day * 24 * SECONDS_PER_HOUR, ten_hours)
+                .unwrap();
+        }
+        // 50 hours: 10 of them at time-and-a-half.
+        assert_eq!(stream.shift_premium_amount, 10 * 50);
+        assert_eq!(stream.earned_amount(monday).unwrap(), 50 * 100 + 10 * 50);
+
+        // The next week starts from zero again.
+        let premium = stream
+            .record_closed_shift(monday + 7 * 24 * SECONDS_PER_HOUR, ten_hours)
+            .unwrap();
+        assert_eq!(premium.overtime_seconds, 0);
+        assert_eq!(stream.workweek_seconds, ten_hours);
+    }
+
+    #[test]
+    fn salaried_kinds_need_a_salary_and_wall_clock_accrual() {
+        let salary = SalarySchedule {
+            period_amount: 7_000,
+            pay_period: PayPeriod::Weekly,
+            vesting: SalaryVesting::Linear,
+        };
+        let salaried = StreamKind::Salaried { salary };
+
+        assert!(StreamKind::Hourly.validate(AccrualMode::Timesheet).is_ok());
+        assert!(salaried.validate(AccrualMode::WallClock).is_ok());
+        assert!(salaried.validate(AccrualMode::ClockInOut).is_err());
+        assert!(StreamKind::Salaried {
+            salary: SalarySchedule {
+                period_amount: 0,
+                ..salary
+            },
+        }
+        .validate(AccrualMode::WallClock)
+        .is_err());
+    }
+
+    #[test]
+    fn salaried_checkpoints_bank_whole_periods_only() {
+        let mut stream = PaymentStream {
+            hourly_rate: 100,
+            total_deposited: u64::MAX,
+            kind: StreamKind::Salaried {
+                salary: SalarySchedule {
+                    period_amount: 7_000,
+                    pay_period: PayPeriod::Weekly,
+                    vesting: SalaryVesting::Linear,
+                },
+            },
+            ..Default::default()
+        };
+        let day = 24 * SECONDS_PER_HOUR;
+
+        assert_eq!(stream.earned_amount(10 * day).unwrap(), 10_000);
+        stream.checkpoint_accrual(10 * day).unwrap();
+        assert_eq!(stream.accrued_amount, 7_000);
+        assert_eq!(stream.accrual_started_at, 0);
+        assert_eq!(stream.salary_periods_paid, 1);
+        assert_eq!(stream.earned_amount(10 * day).unwrap(), 10_000);
+        stream.checkpoint_accrual(12 * day).unwrap();
+        assert_eq!(stream.accrued_amount, 7_000);
+
+        // Switching to hourly pays the rest of the period by the hour.
+        stream.set_kind(StreamKind::Hourly, 10 * day).unwrap();
+        assert_eq!(stream.accrual_started_at, 7 * day);
+        assert_eq!(stream.salary_periods_paid, 0);
+        assert_eq!(stream.earned_amount(10 * day).unwrap(), 7_000 + 72 * 100);
+    }
+
+    #[test]
+    fn monthly_checkpoints_keep_the_original_pay_day() {
+        // 2024-01-31 09:00 UTC.
+        let jan_31 = 1_706_691_600;
+        let day = 24 * SECONDS_PER_HOUR;
+        let salary = SalarySchedule {
+            period_amount: 3_000,
+            pay_period: PayPeriod::Monthly,
+            vesting: SalaryVesting::PeriodEnd,
+        };
+        let mut stream = PaymentStream {
+            total_deposited: u64::MAX,
+            kind: StreamKind::Salaried { salary },
+            accrual_started_at: jan_31,
+            ..Default::default()
+        };
+        let feb_29 = salary.period_start(jan_31, 1).unwrap();
+        let mar_31 = salary.period_start(jan_31, 2).unwrap();
+        assert_eq!(mar_31 - feb_29, 31 * day);
+
+        // Banking in March, after the clamped February pay day, must not
+        // move the March pay day to the 29th.
+        stream.checkpoint_accrual(feb_29 + day).unwrap();
+        assert_eq!(stream.accrued_amount, 3_000);
+        assert_eq!(stream.earned_amount(mar_31 - 1).unwrap(), 3_000);
+        assert_eq!(stream.earned_amount(mar_31).unwrap(), 6_000);
+
+        stream.checkpoint_accrual(mar_31).unwrap();
+        assert_eq!(stream.accrued_amount, 6_000);
+        assert_eq!(stream.salary_periods_paid, 2);
+        assert_eq!(stream.earned_amount(mar_31).unwrap(), 6_000);
+
+        // A raise keeps the pay period, so it keeps the pay day as well.
+        let raise = StreamKind::Salaried {
+            salary: SalarySchedule {
+                period_amount: 4_000,
+                ..salary
+            },
+        };
+        stream.set_kind(raise, mar_31 + day).unwrap();
+        assert_eq!(stream.accrual_started_at, jan_31);
+        let apr_30 = salary.period_start(jan_31, 3).unwrap();
+        assert_eq!(stream.earned_amount(apr_30).unwrap(), 10_000);
+    }
+
+    #[test]
+    fn bonuses_are_earned_immediately_and_set_aside_from_wages() {
+        let mut stream = PaymentStream {
+            hourly_rate: 100,
+            total_deposited: 1_000,
+            ..Default::default()
+        };
+        let hour = SECONDS_PER_HOUR;
+
+        assert_eq!(stream.earned_amount(3 * hour).unwrap(), 300);
+        assert_eq!(stream.unearned_funding(3 * hour).unwrap(), 700);
+
+        // A bonus from surplus is withdrawable at once and shrinks what is
+        // left for wages.
+        stream.total_bonus_awarded = 500;
+        assert_eq!(stream.earned_amount(3 * hour).unwrap(), 800);
+        assert_eq!(stream.earned_amount(20 * hour).unwrap(), 1_000);
+        assert_eq!(stream.unearned_funding(3 * hour).unwrap(), 200);
+
+        stream.withdrawn_amount = 900;
+        assert_eq!(stream.unearned_funding(3 * hour).unwrap(), 100);
+        assert!(stream.assert_accounting_invariant().is_ok());
+    }
+
+    #[test]
+    fn released_milestones_leave_escrow_and_become_withdrawable() {
+        let mut stream = PaymentStream {
+            hourly_rate: 100,
+            total_deposited: 1_000,
+            milestone_escrow: 2_000,
+            ..Default::default()
+        };
+
+        // Escrow is in the vault but earns nothing until released.
+        assert_eq!(stream.expected_vault_balance().unwrap(), 3_000);
+        assert_eq!(stream.earned_amount(0).unwrap(), 0);
+
+        stream.release_milestone(1_500).unwrap();
+        assert_eq!(stream.milestone_escrow, 500);
+        assert_eq!(stream.earned_amount(0).unwrap(), 1_500);
+        // Wages still accrue against the original 1_000 only.
+        assert_eq!(stream.earned_amount(30 * SECONDS_PER_HOUR).unwrap(), 2_500);
+        assert_eq!(stream.expected_vault_balance().unwrap(), 3_000);
+        assert!(stream.release_milestone(501).is_err());
+    }
+
+    #[test]
+    fn cancelled_milestones_leave_the_vault_and_resolve_escrow() {
+        let mut stream = PaymentStream {
+            total_deposited: 1_000,
+            milestone_escrow: 2_000,
+            ..Default::default()
+        };
+        assert!(stream.assert_milestones_resolved().is_err());
+
+        stream.release_milestone(1_500).unwrap();
+        assert!(stream.refund_milestone(501).is_err());
+        stream.refund_milestone(500).unwrap();
+        assert!(stream.assert_milestones_resolved().is_ok());
+        assert_eq!(stream.expected_vault_balance().unwrap(), 2_500);
+    }
+
+    #[test]
+    fn vault_reimbursements_come_out_of_unearned_funding() {
+        let mut stream = PaymentStream {
+            hourly_rate: 100,
+            total_deposited: 1_000,
+            withdrawn_amount: 200,
+            ..Default::default()
+        };
+        assert_eq!(stream.unearned_funding(3 * SECONDS_PER_HOUR).unwrap(), 700);
+
+        assert_eq!(
+            stream.pay_expense_from_vault(701, 3 * SECONDS_PER_HOUR),
+            Err(ErrorCode::InsufficientVaultSurplus.into())
+        );
+        stream
+            .pay_expense_from_vault(700, 3 * SECONDS_PER_HOUR)
+            .unwrap();
+        assert_eq!(stream.expenses_paid_from_vault, 700);
+        assert_eq!(stream.wage_funding().unwrap(), 1_000);
+        assert_eq!(stream.unearned_funding(3 * SECONDS_PER_HOUR).unwrap(), 0);
+        assert_eq!(stream.expected_vault_balance().unwrap(), 100);
+        assert!(stream.assert_accounting_invariant().is_ok());
+
+        // Later accrual is capped at what the reimbursement left behind.
+        assert_eq!(stream.earned_amount(10 * SECONDS_PER_HOUR).unwrap(), 300);
+        assert!(stream
+            .pay_expense_from_vault(1, 10 * SECONDS_PER_HOUR)
+            .is_err());
+    }
+
+    #[test]
+    fn withholding_pays_into_the_stream_mint_at_a_capped_rate() {
+        let (destination, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        let rule = WithholdingRule::new(2_000, destination, mint, mint).unwrap();
+        assert_eq!(rule.rate_bps, 2_000);
+        assert_eq!(rule.destination, destination);
+        assert!(WithholdingRule::new(MAX_WITHHOLDING_RATE_BPS, destination, mint, mint).is_ok());
+        assert_eq!(
+            WithholdingRule::new(2_000, destination, Pubkey::new_unique(), mint),
+            Err(ErrorCode::InvalidTokenAccount.into())
+        );
+        assert_eq!(
+            WithholdingRule::new(0, destination, mint, mint),
+            Err(ErrorCode::InvalidWithholdingRate.into())
+        );
+        assert_eq!(
+            WithholdingRule::new(MAX_WITHHOLDING_RATE_BPS + 1, destination, mint, mint),
+            Err(ErrorCode::InvalidWithholdingRate.into())
+        );
+    }
+
+    #[test]
+    fn withholding_totals_roll_over_each_calendar_year() {
+        // 2023-12-31 23:59:59 and 2024-01-01 00:00:00 UTC.
+        let (new_years_eve, new_year) = (1_704_067_199, 1_704_067_200);
+        let mut stream = PaymentStream::default();
+
+        stream
+            .record_withholding(100, new_years_eve - 1_000)
+            .unwrap();
+        stream.record_withholding(50, new_years_eve).unwrap();
+        assert_eq!(stream.withholding_year, 2023);
+        assert_eq!(stream.withheld_this_year, 150);
+
+        stream.record_withholding(30, new_year).unwrap();
+        assert_eq!(stream.withholding_year, 2024);
+        assert_eq!(stream.withheld_this_year, 30);
+        assert_eq!(stream.total_withheld, 180);
+
+        // A withdrawal without withholding still starts the new year.
+        stream
+            .record_withholding(0, new_year + 366 * 24 * SECONDS_PER_HOUR)
+            .unwrap();
+        assert_eq!(stream.withholding_year, 2025);
+        assert_eq!(stream.withheld_this_year, 0);
+    }
+
+    #[test]
+    fn validates_deductions() {
+        let deduction = |basis, employer_match_bps| Deduction {
+            destination: Pubkey::new_unique(),
+            basis,
+            employer_match_bps,
+        };
+        let retirement = deduction(DeductionBasis::Percent { rate_bps: 500 }, 10_000);
+        let insurance = deduction(DeductionBasis::FixedPerHour { amount: 2 }, 0);
+
+        let hourly = StreamKind::Hourly;
+
+        assert!(PaymentStream::validate_deductions(&[retirement, insurance], &hourly, 10).is_ok());
+        assert!(PaymentStream::validate_deductions(&[insurance], &hourly, 1).is_err());
+        assert!(
+            PaymentStream::validate_deductions(&[retirement, retirement], &hourly, 10).is_err()
+        );
+        assert!(PaymentStream::validate_deductions(
+            &[deduction(DeductionBasis::Percent { rate_bps: 0 }, 0)],
+            &hourly,
+            10
+        )
+        .is_err());
+        assert!(PaymentStream::validate_deductions(
+            &[deduction(DeductionBasis::Percent { rate_bps: 100 }, 10_001)],
+            &hourly,
+            10
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn salaried_streams_only_take_percentage_deductions() {
+        let salaried = StreamKind::Salaried {
+            salary: SalarySchedule {
+                period_amount: 4_000,
+                pay_period: PayPeriod::Monthly,
+                vesting: SalaryVesting::Linear,
+            },
+        };
+        let deduction = |basis| Deduction {
+            destination: Pubkey::new_unique(),
+            basis,
+            employer_match_bps: 0,
+        };
+
+        assert!(PaymentStream::validate_deductions(
+            &[deduction(DeductionBasis::Percent { rate_bps: 500 })],
+            &salaried,
+            0
+        )
+        .is_ok());
+        assert_eq!(
+            PaymentStream::validate_deductions(
+                &[deduction(DeductionBasis::FixedPerHour { amount: 2 })],
+                &salaried,
+                10
+            ),
+            Err(ErrorCode::HourlyDeductionOnSalariedStream.into())
+        );
+    }
+
+    #[test]
+    fn employer_match_reduces_wage_funding_and_vault_balance() {
+        let stream = PaymentStream {
+            total_deposited: 100,
+            withdrawn_amount: 40,
+            total_employer_matched: 10,
+            ..Default::default()
+        };
+
+        assert_eq!(stream.wage_funding().unwrap(), 90);
+        assert_eq!(stream.expected_vault_balance().unwrap(), 50);
+        assert!(stream.assert_accounting_invariant().is_ok());
+    }
+}
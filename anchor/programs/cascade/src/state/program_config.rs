@@ -0,0 +1,250 @@
+use crate::errors::ErrorCode;
+use crate::payout::basis_points_of;
+use anchor_lang::prelude::*;
+
+pub const MAX_ALLOWED_MINTS: usize = 16;
+pub const MAX_PROTOCOL_FEE_BPS: u16 = 500;
+
+pub const PAUSE_CREATE_STREAM: u8 = 1 << 0;
+pub const PAUSE_TOP_UP_STREAM: u8 = 1 << 1;
+pub const PAUSE_WITHDRAW: u8 = 1 << 2;
+pub const PAUSE_ALL: u8 = PAUSE_CREATE_STREAM | PAUSE_TOP_UP_STREAM | PAUSE_WITHDRAW;
+
+/// Protocol fees charged on stream deposits and withdrawals and paid into the
+/// per-mint fee vault PDA.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace, PartialEq, Eq,
+)]
+pub struct FeeSchedule {
+    pub deposit_fee_bps: u16,
+    pub withdraw_fee_bps: u16,
+    /// Upper bound on any single fee. `None` leaves fees uncapped.
+    pub max_fee: Option<u64>,
+}
+
+impl FeeSchedule {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.deposit_fee_bps <= MAX_PROTOCOL_FEE_BPS
+                && self.withdraw_fee_bps <= MAX_PROTOCOL_FEE_BPS,
+            ErrorCode::InvalidFeeSchedule
+        );
+        Ok(())
+    }
+
+    pub fn deposit_fee(&self, amount: u64) -> Result<u64> {
+        self.fee_for(amount, self.deposit_fee_bps)
+    }
+
+    pub fn withdraw_fee(&self, amount: u64) -> Result<u64> {
+        self.fee_for(amount, self.withdraw_fee_bps)
+    }
+
+    /// Rounds down and applies the cap, so the fee is always a deterministic
+    /// function of `amount`.
+    fn fee_for(&self, amount: u64, fee_bps: u16) -> Result<u64> {
+        let fee = basis_points_of(amount, fee_bps)?;
+        Ok(self.max_fee.map_or(fee, |max_fee| fee.min(max_fee)))
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramConfig {
+    pub admin: Pubkey,
+    /// Nominee who may accept the admin role.
+    pub pending_admin: Option<Pubkey>,
+    pub guardian: Pubkey,
+    #[max_len(MAX_ALLOWED_MINTS)]
+    pub allowed_mints: Vec<Pubkey>,
+    /// Bitmask of `PAUSE_*` flags. Exits such as `close_stream` are never pausable.
+    pub paused_operations: u8,
+    pub fee_schedule: FeeSchedule,
+    pub bump: u8,
+}
+
+impl ProgramConfig {
+    pub fn is_mint_allowed(&self, mint: &Pubkey) -> bool {
+        self.allowed_mints.contains(mint)
+    }
+
+    pub fn allow_mint(&mut self, mint: Pubkey) -> Result<()> {
+        require!(!self.is_mint_allowed(&mint), ErrorCode::MintAlreadyAllowed);
+        require!(
+            self.allowed_mints.len() < MAX_ALLOWED_MINTS,
+            ErrorCode::MintAllowlistFull
+        );
+        self.allowed_mints.push(mint);
+        Ok(())
+    }
+
+    pub fn disallow_mint(&mut self, mint: &Pubkey) -> Result<()> {
+        let position = self
+            .allowed_mints
+            .iter()
+            .position(|allowed_mint| allowed_mint == mint)
+            .ok_or(ErrorCode::MintNotAllowed)?;
+        self.allowed_mints.swap_remove(position);
+        Ok(())
+    }
+
+    pub fn propose_admin(&mut self, new_admin: Pubkey) {
+        self.pending_admin = (new_admin != self.admin).then_some(new_admin);
+    }
+
+    /// Hands the admin role to the pending nominee and returns the previous admin.
+    pub fn accept_admin(&mut self, new_admin: Pubkey) -> Result<Pubkey> {
+        require!(
+            self.pending_admin == Some(new_admin),
+            ErrorCode::UnauthorizedPendingAdmin
+        );
+        let previous_admin = self.admin;
+        self.admin = new_admin;
+        self.pending_admin = None;
+        Ok(previous_admin)
+    }
+
+    pub fn assert_not_paused(&self, operation: u8) -> Result<()> {
+        require!(
+            self.paused_operations & operation == 0,
+            ErrorCode::OperationPaused
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        FeeSchedule, ProgramConfig, MAX_ALLOWED_MINTS, MAX_PROTOCOL_FEE_BPS, PAUSE_ALL,
+        PAUSE_CREATE_STREAM, PAUSE_TOP_UP_STREAM, PAUSE_WITHDRAW,
+    };
+    use anchor_lang::prelude::Pubkey;
+
+    fn config_with_flags(paused_operations: u8) -> ProgramConfig {
+        ProgramConfig {
+            admin: Pubkey::new_unique(),
+            pending_admin: None,
+            guardian: Pubkey::new_unique(),
+            allowed_mints: Vec::new(),
+            paused_operations,
+            fee_schedule: FeeSchedule::default(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn only_flagged_operations_are_paused() {
+        let config = config_with_flags(PAUSE_WITHDRAW);
+
+        assert!(config.assert_not_paused(PAUSE_CREATE_STREAM).is_ok());
+        assert!(config.assert_not_paused(PAUSE_TOP_UP_STREAM).is_ok());
+        assert!(config.assert_not_paused(PAUSE_WITHDRAW).is_err());
+    }
+
+    #[test]
+    fn pause_all_blocks_every_pausable_operation() {
+        let config = config_with_flags(PAUSE_ALL);
+
+        assert!(config.assert_not_paused(PAUSE_CREATE_STREAM).is_err());
+        assert!(config.assert_not_paused(PAUSE_TOP_UP_STREAM).is_err());
+        assert!(config.assert_not_paused(PAUSE_WITHDRAW).is_err());
+    }
+
+    #[test]
+    fn allowlist_rejects_duplicates_and_overflow() {
+        let mut config = config_with_flags(0);
+        let mint = Pubkey::new_unique();
+
+        config.allow_mint(mint).unwrap();
+        assert!(config.is_mint_allowed(&mint));
+        assert!(config.allow_mint(mint).is_err());
+
+        for _ in 1..MAX_ALLOWED_MINTS {
+            config.allow_mint(Pubkey::new_unique()).unwrap();
+        }
+        assert_eq!(config.allowed_mints.len(), MAX_ALLOWED_MINTS);
+        assert!(config.allow_mint(Pubkey::new_unique()).is_err());
+    }
+
+    #[test]
+    fn removing_a_mint_frees_its_slot() {
+        let mut config = config_with_flags(0);
+        let mints: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        for mint in &mints {
+            config.allow_mint(*mint).unwrap();
+        }
+
+        config.disallow_mint(&mints[0]).unwrap();
+        assert!(!config.is_mint_allowed(&mints[0]));
+        assert!(config.is_mint_allowed(&mints[1]));
+        assert!(config.is_mint_allowed(&mints[2]));
+        assert!(config.disallow_mint(&mints[0]).is_err());
+        assert!(config.disallow_mint(&Pubkey::new_unique()).is_err());
+
+        config.allow_mint(mints[0]).unwrap();
+        assert!(config.is_mint_allowed(&mints[0]));
+    }
+
+    #[test]
+    fn fee_schedule_bounds_are_inclusive() {
+        let at_maximum = FeeSchedule {
+            deposit_fee_bps: MAX_PROTOCOL_FEE_BPS,
+            withdraw_fee_bps: MAX_PROTOCOL_FEE_BPS,
+            max_fee: None,
+        };
+        assert!(at_maximum.validate().is_ok());
+        assert!(FeeSchedule {
+            deposit_fee_bps: MAX_PROTOCOL_FEE_BPS + 1,
+            ..at_maximum
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn only_the_proposed_admin_can_accept() {
+        let mut config = config_with_flags(0);
+        let admin = config.admin;
+        let first_nominee = Pubkey::new_unique();
+        let second_nominee = Pubkey::new_unique();
+
+        assert!(config.accept_admin(first_nominee).is_err());
+
+        config.propose_admin(first_nominee);
+        config.propose_admin(second_nominee);
+        assert!(config.accept_admin(first_nominee).is_err());
+        assert_eq!(config.accept_admin(second_nominee).unwrap(), admin);
+        assert_eq!(config.admin, second_nominee);
+        assert_eq!(config.pending_admin, None);
+
+        // Proposing the current admin cancels a pending handover.
+        config.propose_admin(first_nominee);
+        config.propose_admin(second_nominee);
+        assert!(config.accept_admin(first_nominee).is_err());
+    }
+
+    #[test]
+    fn fees_round_down_and_respect_the_cap() {
+        let fee_schedule = FeeSchedule {
+            deposit_fee_bps: 100,
+            withdraw_fee_bps: 25,
+            max_fee: Some(500),
+        };
+
+        assert_eq!(fee_schedule.deposit_fee(10_099).unwrap(), 100);
+        assert_eq!(fee_schedule.deposit_fee(1_000_000).unwrap(), 500);
+        assert_eq!(fee_schedule.withdraw_fee(399).unwrap(), 0);
+        assert_eq!(FeeSchedule::default().deposit_fee(1_000_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn rejects_fees_above_the_protocol_maximum() {
+        let fee_schedule = FeeSchedule {
+            withdraw_fee_bps: MAX_PROTOCOL_FEE_BPS + 1,
+            ..FeeSchedule::default()
+        };
+
+        assert!(fee_schedule.validate().is_err());
+    }
+}
@@ -0,0 +1,204 @@
+use crate::errors::ErrorCode;
+use crate::state::{civil_from_days, days_from_civil, SECONDS_PER_DAY, SECONDS_PER_WEEK};
+use anchor_lang::prelude::*;
+
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace, PartialEq, Eq,
+)]
+pub enum PayPeriod {
+    #[default]
+    Weekly,
+    Biweekly,
+    /// Calendar months (UTC) from the period anchor, with the day of month
+    /// clamped to shorter months.
+    Monthly,
+}
+
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace, PartialEq, Eq,
+)]
+pub enum SalaryVesting {
+    /// The period's pay vests evenly, second by second.
+    #[default]
+    Linear,
+    /// The whole period's pay vests when the period ends.
+    PeriodEnd,
+}
+
+/// A fixed amount per pay period. Periods are counted from the stream's
+/// `accrual_started_at`.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace, PartialEq, Eq,
+)]
+pub struct SalarySchedule {
+    pub period_amount: u64,
+    pub pay_period: PayPeriod,
+    pub vesting: SalaryVesting,
+}
+
+impl SalarySchedule {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.period_amount > 0, ErrorCode::InvalidSalarySchedule);
+        Ok(())
+    }
+
+    /// Start of the `period`-th pay period after `anchor`.
+    pub fn period_start(&self, anchor: i64, period: u64) -> Result<i64> {
+        match self.pay_period {
+            PayPeriod::Weekly => fixed_period_start(anchor, period, SECONDS_PER_WEEK),
+            PayPeriod::Biweekly => fixed_period_start(anchor, period, 2 * SECONDS_PER_WEEK),
+            PayPeriod::Monthly => add_months(anchor, period),
+        }
+    }
+
+    /// Number of pay periods fully elapsed between `anchor` and `now`.
+    pub fn completed_periods(&self, anchor: i64, now: i64) -> Result<u64> {
+        if now <= anchor {
+            return Ok(0);
+        }
+        let periods = match self.pay_period {
+            PayPeriod::Weekly => (now - anchor) / SECONDS_PER_WEEK,
+            PayPeriod::Biweekly => (now - anchor) / (2 * SECONDS_PER_WEEK),
+            PayPeriod::Monthly => {
+                let (anchor_year, anchor_month, _) =
+                    civil_from_days(anchor.div_euclid(SECONDS_PER_DAY));
+                let (year, month, _) = civil_from_days(now.div_euclid(SECONDS_PER_DAY));
+                let months = (year - anchor_year) * 12 + i64::from(month) - i64::from(anchor_month);
+                // The anchor's day and time of day may not have come round yet.
+                if add_months(anchor, months as u64)? > now {
+                    months - 1
+                } else {
+                    months
+                }
+            }
+        };
+        Ok(periods as u64)
+    }
+
+    /// Salary vested between `anchor` and `now`.
+    pub fn vested_between(&self, anchor: i64, now: i64) -> Result<u64> {
+        let periods = self.completed_periods(anchor, now)?;
+        let mut vested = u128::from(periods) * u128::from(self.period_amount);
+        if self.vesting == SalaryVesting::Linear && now > anchor {
+            let start = self.period_start(anchor, periods)?;
+            let end = self.period_start(anchor, periods + 1)?;
+            vested +=
+                u128::from(self.period_amount) * (now - start) as u128 / (end - start) as u128;
+        }
+        u64::try_from(vested).map_err(|_| ErrorCode::MathOverflow.into())
+    }
+}
+
+fn fixed_period_start(anchor: i64, period: u64, period_seconds: i64) -> Result<i64> {
+    i64::try_from(period)
+        .ok()
+        .and_then(|period| period.checked_mul(period_seconds))
+        .and_then(|offset| anchor.checked_add(offset))
+        .ok_or(ErrorCode::MathOverflow.into())
+}
+
+/// `timestamp` moved forward by `months` calendar months, keeping the time of
+/// day and clamping the day of month.
+fn add_months(timestamp: i64, months: u64) -> Result<i64> {
+    let days = timestamp.div_euclid(SECONDS_PER_DAY);
+    let second_of_day = timestamp.rem_euclid(SECONDS_PER_DAY);
+    let (year, month, day) = civil_from_days(days);
+
+    let month_index = i64::try_from(months)
+        .ok()
+        .and_then(|months| (i64::from(month) - 1).checked_add(months))
+        .ok_or(ErrorCode::MathOverflow)?;
+    let year = year + month_index.div_euclid(12);
+    let month = (month_index.rem_euclid(12) + 1) as u32;
+    let day = day.min(days_in_month(year, month));
+
+    days_from_civil(year, month, day)
+        .checked_mul(SECONDS_PER_DAY)
+        .and_then(|seconds| seconds.checked_add(second_of_day))
+        .ok_or(ErrorCode::MathOverflow.into())
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_months, PayPeriod, SalarySchedule, SalaryVesting};
+    use crate::state::{civil_from_days, days_from_civil, SECONDS_PER_DAY};
+
+    // 2024-01-31 09:00 UTC.
+    const JAN_31: i64 = 1_706_691_600;
+
+    fn monthly(vesting: SalaryVesting) -> SalarySchedule {
+        SalarySchedule {
+            period_amount: 3_000,
+            pay_period: PayPeriod::Monthly,
+            vesting,
+        }
+    }
+
+    #[test]
+    fn clamps_months_to_their_last_day() {
+        assert_eq!(civil_from_days(JAN_31 / SECONDS_PER_DAY), (2024, 1, 31));
+        assert_eq!(
+            add_months(JAN_31, 1).unwrap(),
+            days_from_civil(2024, 2, 29) * SECONDS_PER_DAY + 9 * 60 * 60
+        );
+        assert_eq!(
+            add_months(JAN_31, 2).unwrap(),
+            days_from_civil(2024, 3, 31) * SECONDS_PER_DAY + 9 * 60 * 60
+        );
+        assert_eq!(
+            add_months(JAN_31, 13).unwrap(),
+            days_from_civil(2025, 2, 28) * SECONDS_PER_DAY + 9 * 60 * 60
+        );
+    }
+
+    #[test]
+    fn vests_monthly_salary_at_period_end() {
+        let salary = monthly(SalaryVesting::PeriodEnd);
+        let first_end = add_months(JAN_31, 1).unwrap();
+
+        assert_eq!(salary.vested_between(JAN_31, first_end - 1).unwrap(), 0);
+        assert_eq!(salary.vested_between(JAN_31, first_end).unwrap(), 3_000);
+        assert_eq!(
+            salary
+                .vested_between(JAN_31, add_months(JAN_31, 12).unwrap())
+                .unwrap(),
+            36_000
+        );
+    }
+
+    #[test]
+    fn vests_linearly_within_each_period() {
+        let salary = monthly(SalaryVesting::Linear);
+        // The second period runs from 29 February to 31 March; ten of its
+        // 31 days have elapsed.
+        let start = add_months(JAN_31, 1).unwrap();
+        assert_eq!(
+            salary
+                .vested_between(JAN_31, start + 10 * SECONDS_PER_DAY)
+                .unwrap(),
+            3_000 + 3_000 * 10 / 31
+        );
+
+        let biweekly = SalarySchedule {
+            period_amount: 1_400,
+            pay_period: PayPeriod::Biweekly,
+            vesting: SalaryVesting::Linear,
+        };
+        assert_eq!(
+            biweekly
+                .vested_between(JAN_31, JAN_31 + 21 * SECONDS_PER_DAY)
+                .unwrap(),
+            2_100
+        );
+        assert_eq!(biweekly.vested_between(JAN_31, JAN_31 - 1).unwrap(), 0);
+    }
+}
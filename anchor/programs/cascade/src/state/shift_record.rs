@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+/// How long after clock-out the employer may void a shift. Once it lapses the
+/// shift is final and anyone may close its record.
+pub const SHIFT_REVIEW_SECONDS: i64 = 14 * 24 * 60 * 60;
+
+/// One clock-in/clock-out interval of a clock-in stream.
+#[account]
+#[derive(InitSpace)]
+pub struct ShiftRecord {
+    pub stream: Pubkey,
+    pub index: u32,
+    /// Paid the record's rent at clock-in and receives it when the record is
+    /// closed.
+    pub payer: Pubkey,
+    pub clocked_in_at: i64,
+    pub clocked_out_at: Option<i64>,
+    /// Paid length of the shift, capped at the stream's maximum shift length.
+    pub duration_seconds: u64,
+    /// Workweek the shift counts towards for overtime.
+    pub workweek: i64,
+    pub overtime_seconds: u64,
+    pub night_seconds: u64,
+    /// Overtime and night premium paid on top of the base rate.
+    pub premium_amount: u64,
+    /// Set when the shift ran past the maximum length and was cut off.
+    pub auto_closed: bool,
+    pub bump: u8,
+}
+
+impl ShiftRecord {
+    /// Whether the employer's review window after clock-out has lapsed. Open
+    /// shifts are never final.
+    pub fn is_final(&self, current_timestamp: i64) -> bool {
+        self.clocked_out_at.is_some_and(|clocked_out_at| {
+            current_timestamp.saturating_sub(clocked_out_at) >= SHIFT_REVIEW_SECONDS
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ShiftRecord, SHIFT_REVIEW_SECONDS};
+    use anchor_lang::prelude::Pubkey;
+
+    #[test]
+    fn shifts_become_final_once_the_review_window_lapses() {
+        let mut shift = ShiftRecord {
+            stream: Pubkey::new_unique(),
+            index: 0,
+            payer: Pubkey::new_unique(),
+            clocked_in_at: 0,
+            clocked_out_at: None,
+            duration_seconds: 0,
+            workweek: 0,
+            overtime_seconds: 0,
+            night_seconds: 0,
+            premium_amount: 0,
+            auto_closed: false,
+            bump: 255,
+        };
+        assert!(!shift.is_final(SHIFT_REVIEW_SECONDS * 2));
+
+        shift.clocked_out_at = Some(100);
+        assert!(!shift.is_final(99 + SHIFT_REVIEW_SECONDS));
+        assert!(shift.is_final(100 + SHIFT_REVIEW_SECONDS));
+    }
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Hours approved for one period of a timesheet-mode stream. Written once by
+/// the employer or timesheet approver; the employee can read but not alter it.
+#[account]
+#[derive(InitSpace)]
+pub struct TimesheetPeriod {
+    pub stream: Pubkey,
+    pub period_start: i64,
+    pub period_end: i64,
+    pub approved_minutes: u32,
+    /// Wages credited to the stream for this period.
+    pub earned_amount: u64,
+    pub submitted_by: Pubkey,
+    pub submitted_at: i64,
+    pub bump: u8,
+}
@@ -0,0 +1,207 @@
+use crate::errors::ErrorCode;
+use crate::state::{EPOCH_TO_MONDAY_SECONDS, SECONDS_PER_DAY, SECONDS_PER_WEEK};
+use anchor_lang::prelude::*;
+
+pub const MINUTES_PER_DAY: u16 = 24 * 60;
+pub const MAX_UTC_OFFSET_MINUTES: i16 = 14 * 60;
+
+/// Working hours for one weekday as minutes since local midnight. An empty
+/// window (`start_minute == end_minute`) is a day off.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace, PartialEq, Eq,
+)]
+pub struct DayWindow {
+    pub start_minute: u16,
+    pub end_minute: u16,
+}
+
+impl DayWindow {
+    fn seconds(&self) -> i64 {
+        i64::from(self.end_minute - self.start_minute) * 60
+    }
+
+    /// Scheduled seconds between local midnight and `second_of_day`.
+    fn seconds_until(&self, second_of_day: i64) -> i64 {
+        let start = i64::from(self.start_minute) * 60;
+        let end = i64::from(self.end_minute) * 60;
+        second_of_day.clamp(start, end) - start
+    }
+}
+
+/// A repeating weekly schedule, Monday first, in a fixed UTC offset.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace, PartialEq, Eq,
+)]
+pub struct WeeklySchedule {
+    pub days: [DayWindow; 7],
+    pub utc_offset_minutes: i16,
+}
+
+impl WeeklySchedule {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.utc_offset_minutes.abs() <= MAX_UTC_OFFSET_MINUTES,
+            ErrorCode::InvalidWeeklySchedule
+        );
+        for day in &self.days {
+            require!(
+                day.start_minute <= day.end_minute && day.end_minute <= MINUTES_PER_DAY,
+                ErrorCode::InvalidWeeklySchedule
+            );
+        }
+        Ok(())
+    }
+
+    /// Scheduled seconds between `from` and `to`, in constant time however
+    /// long the interval is.
+    pub fn scheduled_seconds_between(&self, from: i64, to: i64) -> Result<u64> {
+        let seconds = self
+            .scheduled_seconds_since_origin(to)?
+            .checked_sub(self.scheduled_seconds_since_origin(from)?)
+            .ok_or(ErrorCode::InvalidTimestamp)?;
+        u64::try_from(seconds).map_err(|_| ErrorCode::InvalidTimestamp.into())
+    }
+
+    /// Scheduled seconds from a fixed Monday-midnight origin up to `timestamp`:
+    /// whole weeks in closed form, then the days of the current week.
+    fn scheduled_seconds_since_origin(&self, timestamp: i64) -> Result<i64> {
+        let local_seconds = timestamp
+            .checked_add(i64::from(self.utc_offset_minutes) * 60 + EPOCH_TO_MONDAY_SECONDS)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let weeks = local_seconds.div_euclid(SECONDS_PER_WEEK);
+        let second_of_week = local_seconds.rem_euclid(SECONDS_PER_WEEK);
+        let weekday = (second_of_week / SECONDS_PER_DAY) as usize;
+
+        let seconds_per_week: i64 = self.days.iter().map(DayWindow::seconds).sum();
+        let earlier_days: i64 = self.days[..weekday].iter().map(DayWindow::seconds).sum();
+        let today = self.days[weekday].seconds_until(second_of_week % SECONDS_PER_DAY);
+
+        weeks
+            .checked_mul(seconds_per_week)
+            .and_then(|seconds| seconds.checked_add(earlier_days + today))
+            .ok_or(ErrorCode::MathOverflow.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DayWindow, WeeklySchedule};
+    use crate::state::{SECONDS_PER_DAY, SECONDS_PER_WEEK};
+
+    const NINE_TO_FIVE: DayWindow = DayWindow {
+        start_minute: 9 * 60,
+        end_minute: 17 * 60,
+    };
+    const DAY_OFF: DayWindow = DayWindow {
+        start_minute: 0,
+        end_minute: 0,
+    };
+    // Monday 2024-01-01 00:00 UTC.
+    const MONDAY: i64 = 1_704_067_200;
+
+    fn office_hours(utc_offset_minutes: i16) -> WeeklySchedule {
+        WeeklySchedule {
+            days: [
+                NINE_TO_FIVE,
+                NINE_TO_FIVE,
+                NINE_TO_FIVE,
+                NINE_TO_FIVE,
+                NINE_TO_FIVE,
+                DAY_OFF,
+                DAY_OFF,
+            ],
+            utc_offset_minutes,
+        }
+    }
+
+    #[test]
+    fn counts_only_scheduled_time() {
+        let schedule = office_hours(0);
+        let hour = 60 * 60;
+
+        assert_eq!(
+            schedule
+                .scheduled_seconds_between(MONDAY, MONDAY + SECONDS_PER_WEEK)
+                .unwrap(),
+            40 * hour as u64
+        );
+        assert_eq!(
+            schedule
+                .scheduled_seconds_between(MONDAY + 10 * hour, MONDAY + SECONDS_PER_DAY + 10 * hour)
+                .unwrap(),
+            8 * hour as u64
+        );
+        assert_eq!(
+            schedule
+                .scheduled_seconds_between(
+                    MONDAY + 5 * SECONDS_PER_DAY,
+                    MONDAY + 7 * SECONDS_PER_DAY
+                )
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn applies_the_utc_offset() {
+        // 09:00-17:00 at UTC-5 is 14:00-22:00 UTC.
+        let schedule = office_hours(-5 * 60);
+        let hour = 60 * 60;
+
+        assert_eq!(
+            schedule
+                .scheduled_seconds_between(MONDAY, MONDAY + 15 * hour)
+                .unwrap(),
+            hour as u64
+        );
+    }
+
+    /// Independent minute-by-minute reference: is the minute starting at
+    /// `timestamp` inside the schedule?
+    fn is_scheduled_minute(schedule: &WeeklySchedule, timestamp: i64) -> bool {
+        let local = timestamp + i64::from(schedule.utc_offset_minutes) * 60;
+        let weekday = (local.div_euclid(SECONDS_PER_DAY) + 3).rem_euclid(7) as usize;
+        let minute_of_day = (local.rem_euclid(SECONDS_PER_DAY) / 60) as u16;
+        let window = schedule.days[weekday];
+        (window.start_minute..window.end_minute).contains(&minute_of_day)
+    }
+
+    #[test]
+    fn matches_minute_by_minute_integration_over_long_ranges() {
+        let schedule = office_hours(90);
+        let from = MONDAY - 3 * SECONDS_PER_DAY + 20 * 60;
+        let to = from + 10 * SECONDS_PER_WEEK + 94 * 60;
+
+        let expected: u64 = (from..to)
+            .step_by(60)
+            .filter(|timestamp| is_scheduled_minute(&schedule, *timestamp))
+            .map(|_| 60)
+            .sum();
+
+        assert_eq!(
+            schedule.scheduled_seconds_between(from, to).unwrap(),
+            expected
+        );
+        assert_eq!(
+            schedule
+                .scheduled_seconds_between(MONDAY, MONDAY + 520 * SECONDS_PER_WEEK)
+                .unwrap(),
+            520 * 40 * 60 * 60
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_windows_and_offsets() {
+        let mut schedule = office_hours(0);
+        assert!(schedule.validate().is_ok());
+
+        schedule.days[2] = DayWindow {
+            start_minute: 17 * 60,
+            end_minute: 9 * 60,
+        };
+        assert!(schedule.validate().is_err());
+
+        let schedule = office_hours(15 * 60);
+        assert!(schedule.validate().is_err());
+    }
+}
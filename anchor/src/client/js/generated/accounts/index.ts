@@ -6,4 +6,14 @@
  * @see https://github.com/codama-idl/codama
  */
 
-export * from './paymentStream';
\ No newline at end of file
+export * from './expenseClaim';
+export * from './garnishmentOrder';
+export * from './milestone';
+export * from './multisig';
+export * from './multisigProposal';
+export * from './organization';
+export * from './organizationMember';
+export * from './paymentStream';
+export * from './programConfig';
+export * from './shiftRecord';
+export * from './timesheetPeriod';
\ No newline at end of file
@@ -6,28 +6,29 @@
  * @see https://github.com/codama-idl/codama
  */
 
-import { assertAccountExists, assertAccountsExist, combineCodec, decodeAccount, fetchEncodedAccount, fetchEncodedAccounts, fixDecoderSize, fixEncoderSize, getAddressDecoder, getAddressEncoder, getBooleanDecoder, getBooleanEncoder, getBytesDecoder, getBytesEncoder, getI64Decoder, getI64Encoder, getStructDecoder, getStructEncoder, getU64Decoder, getU64Encoder, getU8Decoder, getU8Encoder, transformEncoder, type Account, type Address, type EncodedAccount, type FetchAccountConfig, type FetchAccountsConfig, type FixedSizeCodec, type FixedSizeDecoder, type FixedSizeEncoder, type MaybeAccount, type MaybeEncodedAccount, type ReadonlyUint8Array } from 'gill';
+import { assertAccountExists, assertAccountsExist, combineCodec, decodeAccount, fetchEncodedAccount, fetchEncodedAccounts, fixDecoderSize, fixEncoderSize, getAddressDecoder, getAddressEncoder, getArrayDecoder, getArrayEncoder, getBooleanDecoder, getBooleanEncoder, getBytesDecoder, getBytesEncoder, getI64Decoder, getI64Encoder, getOptionDecoder, getOptionEncoder, getStructDecoder, getStructEncoder, getU32Decoder, getU32Encoder, getU64Decoder, getU64Encoder, getU8Decoder, getU8Encoder, transformEncoder, type Account, type Address, type Codec, type Decoder, type EncodedAccount, type Encoder, type FetchAccountConfig, type FetchAccountsConfig, type MaybeAccount, type MaybeEncodedAccount, type Option, type OptionOrNullable, type ReadonlyUint8Array } from 'gill';
+import { getAccrualModeDecoder, getAccrualModeEncoder, getDeductionDecoder, getDeductionEncoder, getPayMultipliersDecoder, getPayMultipliersEncoder, getPayoutSplitDecoder, getPayoutSplitEncoder, getPendingEmployeeRecoveryDecoder, getPendingEmployeeRecoveryEncoder, getPendingPayoutDestinationDecoder, getPendingPayoutDestinationEncoder, getPendingPayoutSplitsDecoder, getPendingPayoutSplitsEncoder, getSessionKeyDecoder, getSessionKeyEncoder, getStreamKindDecoder, getStreamKindEncoder, getWeeklyScheduleDecoder, getWeeklyScheduleEncoder, getWithholdingRuleDecoder, getWithholdingRuleEncoder, type AccrualMode, type AccrualModeArgs, type Deduction, type DeductionArgs, type PayMultipliers, type PayMultipliersArgs, type PayoutSplit, type PayoutSplitArgs, type PendingEmployeeRecovery, type PendingEmployeeRecoveryArgs, type PendingPayoutDestination, type PendingPayoutDestinationArgs, type PendingPayoutSplits, type PendingPayoutSplitsArgs, type SessionKey, type SessionKeyArgs, type StreamKind, type StreamKindArgs, type WeeklySchedule, type WeeklyScheduleArgs, type WithholdingRule, type WithholdingRuleArgs } from '../types';
 
 export const PAYMENT_STREAM_DISCRIMINATOR = new Uint8Array([124, 85, 193, 22, 93, 1, 143, 75]);
 
 export function getPaymentStreamDiscriminatorBytes() { return fixEncoderSize(getBytesEncoder(), 8).encode(PAYMENT_STREAM_DISCRIMINATOR); }
 
-export type PaymentStream = { discriminator: ReadonlyUint8Array; employer: Address; employee: Address; mint: Address; vault: Address; hourlyRate: bigint; totalDeposited: bigint; withdrawnAmount: bigint; createdAt: bigint; employeeLastActivityAt: bigint; isActive: boolean; bump: number;  };
+export type PaymentStream = { discriminator: ReadonlyUint8Array; employer: Address; originalEmployer: Address; pendingEmployer: Option<Address>; employee: Address; originalEmployee: Address; pendingEmployeeRecovery: Option<PendingEmployeeRecovery>; sessionKey: Option<SessionKey>; mint: Address; vault: Address; payoutDestination: Option<Address>; pendingPayoutDestination: Option<PendingPayoutDestination>; payoutSplits: Array<PayoutSplit>; pendingPayoutSplits: Option<PendingPayoutSplits>; withholding: Option<WithholdingRule>; totalWithheld: bigint; withholdingYear: bigint; withheldThisYear: bigint; deductions: Array<Deduction>; totalDeducted: bigint; totalEmployerMatched: bigint; garnishmentIssuer: Option<Address>; activeGarnishment: Option<Address>; garnishmentCount: number; totalGarnished: bigint; totalProtocolFees: bigint; advanceLimit: bigint; outstandingAdvance: bigint; totalAdvanced: bigint; totalBonusAwarded: bigint; milestoneCount: number; milestoneEscrow: bigint; totalMilestonesReleased: bigint; expenseClaimCount: number; totalExpensesReimbursed: bigint; expensesPaidFromVault: bigint; kind: StreamKind; salaryPeriodsPaid: bigint; accrualMode: AccrualMode; accruedAmount: bigint; timesheetApprover: Option<Address>; lastTimesheetPeriodEnd: bigint; maxShiftSeconds: bigint; clockedInAt: Option<bigint>; shiftCount: number; shiftSeconds: bigint; payMultipliers: Option<PayMultipliers>; overtimeWorkweek: bigint; workweekSeconds: bigint; shiftPremiumAmount: bigint; totalPremiumEarned: bigint; weeklySchedule: WeeklySchedule; hourlyRate: bigint; totalDeposited: bigint; withdrawnAmount: bigint; createdAt: bigint; accrualStartedAt: bigint; awaitingAcceptance: boolean; employeeLastActivityAt: bigint; inactivityThresholdSeconds: bigint; emergencyWithdrawAnnouncedAt: Option<bigint>; isActive: boolean; bump: number;  };
 
-export type PaymentStreamArgs = { employer: Address; employee: Address; mint: Address; vault: Address; hourlyRate: number | bigint; totalDeposited: number | bigint; withdrawnAmount: number | bigint; createdAt: number | bigint; employeeLastActivityAt: number | bigint; isActive: boolean; bump: number;  };
+export type PaymentStreamArgs = { employer: Address; originalEmployer: Address; pendingEmployer: OptionOrNullable<Address>; employee: Address; originalEmployee: Address; pendingEmployeeRecovery: OptionOrNullable<PendingEmployeeRecoveryArgs>; sessionKey: OptionOrNullable<SessionKeyArgs>; mint: Address; vault: Address; payoutDestination: OptionOrNullable<Address>; pendingPayoutDestination: OptionOrNullable<PendingPayoutDestinationArgs>; payoutSplits: Array<PayoutSplitArgs>; pendingPayoutSplits: OptionOrNullable<PendingPayoutSplitsArgs>; withholding: OptionOrNullable<WithholdingRuleArgs>; totalWithheld: number | bigint; withholdingYear: number | bigint; withheldThisYear: number | bigint; deductions: Array<DeductionArgs>; totalDeducted: number | bigint; totalEmployerMatched: number | bigint; garnishmentIssuer: OptionOrNullable<Address>; activeGarnishment: OptionOrNullable<Address>; garnishmentCount: number; totalGarnished: number | bigint; totalProtocolFees: number | bigint; advanceLimit: number | bigint; outstandingAdvance: number | bigint; totalAdvanced: number | bigint; totalBonusAwarded: number | bigint; milestoneCount: number; milestoneEscrow: number | bigint; totalMilestonesReleased: number | bigint; expenseClaimCount: number; totalExpensesReimbursed: number | bigint; expensesPaidFromVault: number | bigint; kind: StreamKindArgs; salaryPeriodsPaid: number | bigint; accrualMode: AccrualModeArgs; accruedAmount: number | bigint; timesheetApprover: OptionOrNullable<Address>; lastTimesheetPeriodEnd: number | bigint; maxShiftSeconds: number | bigint; clockedInAt: OptionOrNullable<number | bigint>; shiftCount: number; shiftSeconds: number | bigint; payMultipliers: OptionOrNullable<PayMultipliersArgs>; overtimeWorkweek: number | bigint; workweekSeconds: number | bigint; shiftPremiumAmount: number | bigint; totalPremiumEarned: number | bigint; weeklySchedule: WeeklyScheduleArgs; hourlyRate: number | bigint; totalDeposited: number | bigint; withdrawnAmount: number | bigint; createdAt: number | bigint; accrualStartedAt: number | bigint; awaitingAcceptance: boolean; employeeLastActivityAt: number | bigint; inactivityThresholdSeconds: number | bigint; emergencyWithdrawAnnouncedAt: OptionOrNullable<number | bigint>; isActive: boolean; bump: number;  };
 
 /** Gets the encoder for {@link PaymentStreamArgs} account data. */
-export function getPaymentStreamEncoder(): FixedSizeEncoder<PaymentStreamArgs> {
-    return transformEncoder(getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)], ['employer', getAddressEncoder()], ['employee', getAddressEncoder()], ['mint', getAddressEncoder()], ['vault', getAddressEncoder()], ['hourlyRate', getU64Encoder()], ['totalDeposited', getU64Encoder()], ['withdrawnAmount', getU64Encoder()], ['createdAt', getI64Encoder()], ['employeeLastActivityAt', getI64Encoder()], ['isActive', getBooleanEncoder()], ['bump', getU8Encoder()]]), (value) => ({ ...value, discriminator: PAYMENT_STREAM_DISCRIMINATOR }));
+export function getPaymentStreamEncoder(): Encoder<PaymentStreamArgs> {
+    return transformEncoder(getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)], ['employer', getAddressEncoder()], ['originalEmployer', getAddressEncoder()], ['pendingEmployer', getOptionEncoder(getAddressEncoder())], ['employee', getAddressEncoder()], ['originalEmployee', getAddressEncoder()], ['pendingEmployeeRecovery', getOptionEncoder(getPendingEmployeeRecoveryEncoder())], ['sessionKey', getOptionEncoder(getSessionKeyEncoder())], ['mint', getAddressEncoder()], ['vault', getAddressEncoder()], ['payoutDestination', getOptionEncoder(getAddressEncoder())], ['pendingPayoutDestination', getOptionEncoder(getPendingPayoutDestinationEncoder())], ['payoutSplits', getArrayEncoder(getPayoutSplitEncoder())], ['pendingPayoutSplits', getOptionEncoder(getPendingPayoutSplitsEncoder())], ['withholding', getOptionEncoder(getWithholdingRuleEncoder())], ['totalWithheld', getU64Encoder()], ['withholdingYear', getI64Encoder()], ['withheldThisYear', getU64Encoder()], ['deductions', getArrayEncoder(getDeductionEncoder())], ['totalDeducted', getU64Encoder()], ['totalEmployerMatched', getU64Encoder()], ['garnishmentIssuer', getOptionEncoder(getAddressEncoder())], ['activeGarnishment', getOptionEncoder(getAddressEncoder())], ['garnishmentCount', getU32Encoder()], ['totalGarnished', getU64Encoder()], ['totalProtocolFees', getU64Encoder()], ['advanceLimit', getU64Encoder()], ['outstandingAdvance', getU64Encoder()], ['totalAdvanced', getU64Encoder()], ['totalBonusAwarded', getU64Encoder()], ['milestoneCount', getU32Encoder()], ['milestoneEscrow', getU64Encoder()], ['totalMilestonesReleased', getU64Encoder()], ['expenseClaimCount', getU32Encoder()], ['totalExpensesReimbursed', getU64Encoder()], ['expensesPaidFromVault', getU64Encoder()], ['kind', getStreamKindEncoder()], ['salaryPeriodsPaid', getU64Encoder()], ['accrualMode', getAccrualModeEncoder()], ['accruedAmount', getU64Encoder()], ['timesheetApprover', getOptionEncoder(getAddressEncoder())], ['lastTimesheetPeriodEnd', getI64Encoder()], ['maxShiftSeconds', getI64Encoder()], ['clockedInAt', getOptionEncoder(getI64Encoder())], ['shiftCount', getU32Encoder()], ['shiftSeconds', getU64Encoder()], ['payMultipliers', getOptionEncoder(getPayMultipliersEncoder())], ['overtimeWorkweek', getI64Encoder()], ['workweekSeconds', getU64Encoder()], ['shiftPremiumAmount', getU64Encoder()], ['totalPremiumEarned', getU64Encoder()], ['weeklySchedule', getWeeklyScheduleEncoder()], ['hourlyRate', getU64Encoder()], ['totalDeposited', getU64Encoder()], ['withdrawnAmount', getU64Encoder()], ['createdAt', getI64Encoder()], ['accrualStartedAt', getI64Encoder()], ['awaitingAcceptance', getBooleanEncoder()], ['employeeLastActivityAt', getI64Encoder()], ['inactivityThresholdSeconds', getI64Encoder()], ['emergencyWithdrawAnnouncedAt', getOptionEncoder(getI64Encoder())], ['isActive', getBooleanEncoder()], ['bump', getU8Encoder()]]), (value) => ({ ...value, discriminator: PAYMENT_STREAM_DISCRIMINATOR }));
 }
 
 /** Gets the decoder for {@link PaymentStream} account data. */
-export function getPaymentStreamDecoder(): FixedSizeDecoder<PaymentStream> {
-    return getStructDecoder([['discriminator', fixDecoderSize(getBytesDecoder(), 8)], ['employer', getAddressDecoder()], ['employee', getAddressDecoder()], ['mint', getAddressDecoder()], ['vault', getAddressDecoder()], ['hourlyRate', getU64Decoder()], ['totalDeposited', getU64Decoder()], ['withdrawnAmount', getU64Decoder()], ['createdAt', getI64Decoder()], ['employeeLastActivityAt', getI64Decoder()], ['isActive', getBooleanDecoder()], ['bump', getU8Decoder()]]);
+export function getPaymentStreamDecoder(): Decoder<PaymentStream> {
+    return getStructDecoder([['discriminator', fixDecoderSize(getBytesDecoder(), 8)], ['employer', getAddressDecoder()], ['originalEmployer', getAddressDecoder()], ['pendingEmployer', getOptionDecoder(getAddressDecoder())], ['employee', getAddressDecoder()], ['originalEmployee', getAddressDecoder()], ['pendingEmployeeRecovery', getOptionDecoder(getPendingEmployeeRecoveryDecoder())], ['sessionKey', getOptionDecoder(getSessionKeyDecoder())], ['mint', getAddressDecoder()], ['vault', getAddressDecoder()], ['payoutDestination', getOptionDecoder(getAddressDecoder())], ['pendingPayoutDestination', getOptionDecoder(getPendingPayoutDestinationDecoder())], ['payoutSplits', getArrayDecoder(getPayoutSplitDecoder())], ['pendingPayoutSplits', getOptionDecoder(getPendingPayoutSplitsDecoder())], ['withholding', getOptionDecoder(getWithholdingRuleDecoder())], ['totalWithheld', getU64Decoder()], ['withholdingYear', getI64Decoder()], ['withheldThisYear', getU64Decoder()], ['deductions', getArrayDecoder(getDeductionDecoder())], ['totalDeducted', getU64Decoder()], ['totalEmployerMatched', getU64Decoder()], ['garnishmentIssuer', getOptionDecoder(getAddressDecoder())], ['activeGarnishment', getOptionDecoder(getAddressDecoder())], ['garnishmentCount', getU32Decoder()], ['totalGarnished', getU64Decoder()], ['totalProtocolFees', getU64Decoder()], ['advanceLimit', getU64Decoder()], ['outstandingAdvance', getU64Decoder()], ['totalAdvanced', getU64Decoder()], ['totalBonusAwarded', getU64Decoder()], ['milestoneCount', getU32Decoder()], ['milestoneEscrow', getU64Decoder()], ['totalMilestonesReleased', getU64Decoder()], ['expenseClaimCount', getU32Decoder()], ['totalExpensesReimbursed', getU64Decoder()], ['expensesPaidFromVault', getU64Decoder()], ['kind', getStreamKindDecoder()], ['salaryPeriodsPaid', getU64Decoder()], ['accrualMode', getAccrualModeDecoder()], ['accruedAmount', getU64Decoder()], ['timesheetApprover', getOptionDecoder(getAddressDecoder())], ['lastTimesheetPeriodEnd', getI64Decoder()], ['maxShiftSeconds', getI64Decoder()], ['clockedInAt', getOptionDecoder(getI64Decoder())], ['shiftCount', getU32Decoder()], ['shiftSeconds', getU64Decoder()], ['payMultipliers', getOptionDecoder(getPayMultipliersDecoder())], ['overtimeWorkweek', getI64Decoder()], ['workweekSeconds', getU64Decoder()], ['shiftPremiumAmount', getU64Decoder()], ['totalPremiumEarned', getU64Decoder()], ['weeklySchedule', getWeeklyScheduleDecoder()], ['hourlyRate', getU64Decoder()], ['totalDeposited', getU64Decoder()], ['withdrawnAmount', getU64Decoder()], ['createdAt', getI64Decoder()], ['accrualStartedAt', getI64Decoder()], ['awaitingAcceptance', getBooleanDecoder()], ['employeeLastActivityAt', getI64Decoder()], ['inactivityThresholdSeconds', getI64Decoder()], ['emergencyWithdrawAnnouncedAt', getOptionDecoder(getI64Decoder())], ['isActive', getBooleanDecoder()], ['bump', getU8Decoder()]]);
 }
 
 /** Gets the codec for {@link PaymentStream} account data. */
-export function getPaymentStreamCodec(): FixedSizeCodec<PaymentStreamArgs, PaymentStream> {
+export function getPaymentStreamCodec(): Codec<PaymentStreamArgs, PaymentStream> {
     return combineCodec(getPaymentStreamEncoder(), getPaymentStreamDecoder());
 }
 
@@ -73,8 +74,4 @@ export async function fetchAllMaybePaymentStream(
 ): Promise<MaybeAccount<PaymentStream>[]> {
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodePaymentStream(maybeAccount));
-}
-
-export function getPaymentStreamSize(): number {
-  return 178;
 }
\ No newline at end of file
@@ -36,12 +36,138 @@ export const CASCADE_ERROR__INVALID_TOKEN_ACCOUNT = 0x177a; // 6010
 export const CASCADE_ERROR__INVALID_STREAM_ACCOUNTING = 0x177b; // 6011
 /** VaultBalanceInvariantViolated: Vault balance is lower than stream accounting expects */
 export const CASCADE_ERROR__VAULT_BALANCE_INVARIANT_VIOLATED = 0x177c; // 6012
+/** UnauthorizedAdmin: Only the config admin can perform this action */
+export const CASCADE_ERROR__UNAUTHORIZED_ADMIN = 0x177d; // 6013
+/** MintNotAllowed: Mint is not on the program allowlist */
+export const CASCADE_ERROR__MINT_NOT_ALLOWED = 0x177e; // 6014
+/** MintAlreadyAllowed: Mint is already on the program allowlist */
+export const CASCADE_ERROR__MINT_ALREADY_ALLOWED = 0x177f; // 6015
+/** MintAllowlistFull: Mint allowlist is full */
+export const CASCADE_ERROR__MINT_ALLOWLIST_FULL = 0x1780; // 6016
+/** UnauthorizedGuardian: Only the config guardian can perform this action */
+export const CASCADE_ERROR__UNAUTHORIZED_GUARDIAN = 0x1781; // 6017
+/** OperationPaused: This operation is paused by the guardian */
+export const CASCADE_ERROR__OPERATION_PAUSED = 0x1782; // 6018
+/** InvalidPauseFlags: Unknown pause flags */
+export const CASCADE_ERROR__INVALID_PAUSE_FLAGS = 0x1783; // 6019
+/** InvalidInactivityThreshold: Inactivity threshold is outside the allowed range */
+export const CASCADE_ERROR__INVALID_INACTIVITY_THRESHOLD = 0x1784; // 6020
+/** EmergencyWithdrawAlreadyAnnounced: An emergency withdrawal has already been announced */
+export const CASCADE_ERROR__EMERGENCY_WITHDRAW_ALREADY_ANNOUNCED = 0x1785; // 6021
+/** EmergencyWithdrawNotAnnounced: No emergency withdrawal has been announced */
+export const CASCADE_ERROR__EMERGENCY_WITHDRAW_NOT_ANNOUNCED = 0x1786; // 6022
+/** EmergencyWithdrawNoticeActive: Emergency withdrawal notice period has not elapsed yet */
+export const CASCADE_ERROR__EMERGENCY_WITHDRAW_NOTICE_ACTIVE = 0x1787; // 6023
+/** MissingOrganizationRole: Member lacks the organization role required for this action */
+export const CASCADE_ERROR__MISSING_ORGANIZATION_ROLE = 0x1788; // 6024
+/** InvalidOrganizationRoles: Unknown organization role flags */
+export const CASCADE_ERROR__INVALID_ORGANIZATION_ROLES = 0x1789; // 6025
+/** LastOrganizationAdmin: An organization must keep at least one admin */
+export const CASCADE_ERROR__LAST_ORGANIZATION_ADMIN = 0x178a; // 6026
+/** UnsupportedEmployerInstruction: Instruction cannot be executed on behalf of an employer */
+export const CASCADE_ERROR__UNSUPPORTED_EMPLOYER_INSTRUCTION = 0x178b; // 6027
+/** InvalidMultisigSigners: Multisig signers must be unique and within the supported count */
+export const CASCADE_ERROR__INVALID_MULTISIG_SIGNERS = 0x178c; // 6028
+/** InvalidMultisigThreshold: Multisig threshold must be between one and the number of signers */
+export const CASCADE_ERROR__INVALID_MULTISIG_THRESHOLD = 0x178d; // 6029
+/** UnauthorizedMultisigSigner: Signer is not a member of this multisig */
+export const CASCADE_ERROR__UNAUTHORIZED_MULTISIG_SIGNER = 0x178e; // 6030
+/** ProposalTooLarge: Proposal instruction data or account list is too large */
+export const CASCADE_ERROR__PROPOSAL_TOO_LARGE = 0x178f; // 6031
+/** ProposalAlreadyApproved: Signer has already approved this proposal */
+export const CASCADE_ERROR__PROPOSAL_ALREADY_APPROVED = 0x1790; // 6032
+/** ProposalNotApproved: Proposal has not reached the approval threshold */
+export const CASCADE_ERROR__PROPOSAL_NOT_APPROVED = 0x1791; // 6033
+/** ProposalAccountsMismatch: Provided accounts do not match the approved proposal */
+export const CASCADE_ERROR__PROPOSAL_ACCOUNTS_MISMATCH = 0x1792; // 6034
+/** UnauthorizedPendingEmployer: Signer is not the pending employer for this stream */
+export const CASCADE_ERROR__UNAUTHORIZED_PENDING_EMPLOYER = 0x1793; // 6035
+/** EmployeeRecoveryAlreadyPending: An employee recovery is already pending */
+export const CASCADE_ERROR__EMPLOYEE_RECOVERY_ALREADY_PENDING = 0x1794; // 6036
+/** EmployeeRecoveryNotPending: No employee recovery is pending */
+export const CASCADE_ERROR__EMPLOYEE_RECOVERY_NOT_PENDING = 0x1795; // 6037
+/** EmployeeRecoveryTimeLockActive: Employee recovery time lock has not elapsed yet */
+export const CASCADE_ERROR__EMPLOYEE_RECOVERY_TIME_LOCK_ACTIVE = 0x1796; // 6038
+/** InvalidNewEmployee: New employee must differ from the current employee */
+export const CASCADE_ERROR__INVALID_NEW_EMPLOYEE = 0x1797; // 6039
+/** InvalidSessionKeyExpiry: Session key expiry must be in the future and within the maximum duration */
+export const CASCADE_ERROR__INVALID_SESSION_KEY_EXPIRY = 0x1798; // 6040
+/** StreamAwaitingAcceptance: Stream is waiting for the employee to accept it */
+export const CASCADE_ERROR__STREAM_AWAITING_ACCEPTANCE = 0x1799; // 6041
+/** StreamAlreadyAccepted: Stream does not require acceptance */
+export const CASCADE_ERROR__STREAM_ALREADY_ACCEPTED = 0x179a; // 6042
+/** InvalidPayoutSplits: Payout splits must be unique, non-zero and total at most 10000 basis points */
+export const CASCADE_ERROR__INVALID_PAYOUT_SPLITS = 0x179b; // 6043
+/** MissingPayoutAccount: A payout destination token account was not provided */
+export const CASCADE_ERROR__MISSING_PAYOUT_ACCOUNT = 0x179c; // 6044
+/** InvalidWithholdingRate: Withholding rate must be between 1 and 5000 basis points */
+export const CASCADE_ERROR__INVALID_WITHHOLDING_RATE = 0x179d; // 6045
+/** InvalidDeductions: Deductions must be unique, non-zero, within the hourly rate and match at most 100% */
+export const CASCADE_ERROR__INVALID_DEDUCTIONS = 0x179e; // 6046
+/** UnauthorizedGarnishmentIssuer: Signer is not the stream's garnishment issuer */
+export const CASCADE_ERROR__UNAUTHORIZED_GARNISHMENT_ISSUER = 0x179f; // 6047
+/** InvalidGarnishmentOrder: Garnishment rate must be between 1 and 2500 basis points and the ordered amount non-zero */
+export const CASCADE_ERROR__INVALID_GARNISHMENT_ORDER = 0x17a0; // 6048
+/** GarnishmentAlreadyActive: A garnishment order is already active on this stream */
+export const CASCADE_ERROR__GARNISHMENT_ALREADY_ACTIVE = 0x17a1; // 6049
+/** MissingGarnishmentOrder: The stream's active garnishment order was not provided */
+export const CASCADE_ERROR__MISSING_GARNISHMENT_ORDER = 0x17a2; // 6050
+/** GarnishmentOrderInactive: Garnishment order is not active */
+export const CASCADE_ERROR__GARNISHMENT_ORDER_INACTIVE = 0x17a3; // 6051
+/** InvalidFeeSchedule: Protocol fees must not exceed 500 basis points */
+export const CASCADE_ERROR__INVALID_FEE_SCHEDULE = 0x17a4; // 6052
+/** MissingFeeVault: The fee vault for this mint was not provided */
+export const CASCADE_ERROR__MISSING_FEE_VAULT = 0x17a5; // 6053
+/** UnauthorizedTimesheetSubmitter: Signer may not submit timesheets for this stream */
+export const CASCADE_ERROR__UNAUTHORIZED_TIMESHEET_SUBMITTER = 0x17a6; // 6054
+/** NotTimesheetStream: Stream does not accrue from timesheets */
+export const CASCADE_ERROR__NOT_TIMESHEET_STREAM = 0x17a7; // 6055
+/** InvalidTimesheetPeriod: Timesheet period must be in the past, follow the previous period and fit the approved minutes */
+export const CASCADE_ERROR__INVALID_TIMESHEET_PERIOD = 0x17a8; // 6056
+/** NotClockInStream: Stream does not accrue from clock-ins */
+export const CASCADE_ERROR__NOT_CLOCK_IN_STREAM = 0x17a9; // 6057
+/** ShiftInProgress: Employee is clocked in */
+export const CASCADE_ERROR__SHIFT_IN_PROGRESS = 0x17aa; // 6058
+/** NotClockedIn: Employee is not clocked in */
+export const CASCADE_ERROR__NOT_CLOCKED_IN = 0x17ab; // 6059
+/** InvalidMaxShiftLength: Maximum shift length must be between 1 and 24 hours */
+export const CASCADE_ERROR__INVALID_MAX_SHIFT_LENGTH = 0x17ac; // 6060
+/** ShiftNotVoidable: Shift cannot be voided */
+export const CASCADE_ERROR__SHIFT_NOT_VOIDABLE = 0x17ad; // 6061
+/** InvalidWeeklySchedule: Schedule windows must end after they start within the day and the UTC offset be at most 14 hours */
+export const CASCADE_ERROR__INVALID_WEEKLY_SCHEDULE = 0x17ae; // 6062
+/** InvalidPayMultipliers: Pay multipliers must be between 1x and 3x and the night window within the day */
+export const CASCADE_ERROR__INVALID_PAY_MULTIPLIERS = 0x17af; // 6063
+/** InvalidSalarySchedule: Salary must pay a non-zero amount per period */
+export const CASCADE_ERROR__INVALID_SALARY_SCHEDULE = 0x17b0; // 6064
+/** SalariedAccrualMode: Salaried streams accrue on the wall clock only */
+export const CASCADE_ERROR__SALARIED_ACCRUAL_MODE = 0x17b1; // 6065
+/** MissingEmployerTokenAccount: The employer token account was not provided */
+export const CASCADE_ERROR__MISSING_EMPLOYER_TOKEN_ACCOUNT = 0x17b2; // 6066
+/** InsufficientVaultSurplus: Bonus exceeds the vault funding the employee has not yet earned */
+export const CASCADE_ERROR__INSUFFICIENT_VAULT_SURPLUS = 0x17b3; // 6067
+/** InvalidMilestoneStatus: Milestone is not in the required state */
+export const CASCADE_ERROR__INVALID_MILESTONE_STATUS = 0x17b4; // 6068
+/** MilestoneReviewLapsed: Milestone review window has lapsed */
+export const CASCADE_ERROR__MILESTONE_REVIEW_LAPSED = 0x17b5; // 6069
+/** InvalidExpenseClaim: Expense claims must be for a non-zero amount */
+export const CASCADE_ERROR__INVALID_EXPENSE_CLAIM = 0x17b6; // 6070
+/** ExpenseClaimNotPending: Expense claim has already been reviewed */
+export const CASCADE_ERROR__EXPENSE_CLAIM_NOT_PENDING = 0x17b7; // 6071
+/** UnauthorizedPendingAdmin: Signer is not the pending config admin */
+export const CASCADE_ERROR__UNAUTHORIZED_PENDING_ADMIN = 0x17b8; // 6072
+/** ShiftNotFinal: Shift is still open or within its review window */
+export const CASCADE_ERROR__SHIFT_NOT_FINAL = 0x17b9; // 6073
+/** MilestonesOutstanding: Funded milestones must be approved or cancelled first */
+export const CASCADE_ERROR__MILESTONES_OUTSTANDING = 0x17ba; // 6074
+/** HourlyDeductionOnSalariedStream: Per-hour deductions are only available on hourly streams */
+export const CASCADE_ERROR__HOURLY_DEDUCTION_ON_SALARIED_STREAM = 0x17bb; // 6075
 
-export type CascadeError = typeof CASCADE_ERROR__EMPLOYEE_STILL_ACTIVE | typeof CASCADE_ERROR__EMPLOYER_LOCK_ACTIVE | typeof CASCADE_ERROR__INSUFFICIENT_BALANCE | typeof CASCADE_ERROR__INVALID_STREAM_ACCOUNTING | typeof CASCADE_ERROR__INVALID_TIMESTAMP | typeof CASCADE_ERROR__INVALID_TOKEN_ACCOUNT | typeof CASCADE_ERROR__MATH_OVERFLOW | typeof CASCADE_ERROR__STREAM_INACTIVE | typeof CASCADE_ERROR__STREAM_STILL_ACTIVE | typeof CASCADE_ERROR__UNAUTHORIZED_EMPLOYEE | typeof CASCADE_ERROR__UNAUTHORIZED_EMPLOYER | typeof CASCADE_ERROR__UNSUPPORTED_MINT_DECIMALS | typeof CASCADE_ERROR__VAULT_BALANCE_INVARIANT_VIOLATED;
+export type CascadeError = typeof CASCADE_ERROR__EMERGENCY_WITHDRAW_ALREADY_ANNOUNCED | typeof CASCADE_ERROR__EMERGENCY_WITHDRAW_NOTICE_ACTIVE | typeof CASCADE_ERROR__EMERGENCY_WITHDRAW_NOT_ANNOUNCED | typeof CASCADE_ERROR__EMPLOYEE_RECOVERY_ALREADY_PENDING | typeof CASCADE_ERROR__EMPLOYEE_RECOVERY_NOT_PENDING | typeof CASCADE_ERROR__EMPLOYEE_RECOVERY_TIME_LOCK_ACTIVE | typeof CASCADE_ERROR__EMPLOYEE_STILL_ACTIVE | typeof CASCADE_ERROR__EMPLOYER_LOCK_ACTIVE | typeof CASCADE_ERROR__EXPENSE_CLAIM_NOT_PENDING | typeof CASCADE_ERROR__GARNISHMENT_ALREADY_ACTIVE | typeof CASCADE_ERROR__GARNISHMENT_ORDER_INACTIVE | typeof CASCADE_ERROR__HOURLY_DEDUCTION_ON_SALARIED_STREAM | typeof CASCADE_ERROR__INSUFFICIENT_BALANCE | typeof CASCADE_ERROR__INSUFFICIENT_VAULT_SURPLUS | typeof CASCADE_ERROR__INVALID_DEDUCTIONS | typeof CASCADE_ERROR__INVALID_EXPENSE_CLAIM | typeof CASCADE_ERROR__INVALID_FEE_SCHEDULE | typeof CASCADE_ERROR__INVALID_GARNISHMENT_ORDER | typeof CASCADE_ERROR__INVALID_INACTIVITY_THRESHOLD | typeof CASCADE_ERROR__INVALID_MAX_SHIFT_LENGTH | typeof CASCADE_ERROR__INVALID_MILESTONE_STATUS | typeof CASCADE_ERROR__INVALID_MULTISIG_SIGNERS | typeof CASCADE_ERROR__INVALID_MULTISIG_THRESHOLD | typeof CASCADE_ERROR__INVALID_NEW_EMPLOYEE | typeof CASCADE_ERROR__INVALID_ORGANIZATION_ROLES | typeof CASCADE_ERROR__INVALID_PAUSE_FLAGS | typeof CASCADE_ERROR__INVALID_PAYOUT_SPLITS | typeof CASCADE_ERROR__INVALID_PAY_MULTIPLIERS | typeof CASCADE_ERROR__INVALID_SALARY_SCHEDULE | typeof CASCADE_ERROR__INVALID_SESSION_KEY_EXPIRY | typeof CASCADE_ERROR__INVALID_STREAM_ACCOUNTING | typeof CASCADE_ERROR__INVALID_TIMESHEET_PERIOD | typeof CASCADE_ERROR__INVALID_TIMESTAMP | typeof CASCADE_ERROR__INVALID_TOKEN_ACCOUNT | typeof CASCADE_ERROR__INVALID_WEEKLY_SCHEDULE | typeof CASCADE_ERROR__INVALID_WITHHOLDING_RATE | typeof CASCADE_ERROR__LAST_ORGANIZATION_ADMIN | typeof CASCADE_ERROR__MATH_OVERFLOW | typeof CASCADE_ERROR__MILESTONES_OUTSTANDING | typeof CASCADE_ERROR__MILESTONE_REVIEW_LAPSED | typeof CASCADE_ERROR__MINT_ALLOWLIST_FULL | typeof CASCADE_ERROR__MINT_ALREADY_ALLOWED | typeof CASCADE_ERROR__MINT_NOT_ALLOWED | typeof CASCADE_ERROR__MISSING_EMPLOYER_TOKEN_ACCOUNT | typeof CASCADE_ERROR__MISSING_FEE_VAULT | typeof CASCADE_ERROR__MISSING_GARNISHMENT_ORDER | typeof CASCADE_ERROR__MISSING_ORGANIZATION_ROLE | typeof CASCADE_ERROR__MISSING_PAYOUT_ACCOUNT | typeof CASCADE_ERROR__NOT_CLOCKED_IN | typeof CASCADE_ERROR__NOT_CLOCK_IN_STREAM | typeof CASCADE_ERROR__NOT_TIMESHEET_STREAM | typeof CASCADE_ERROR__OPERATION_PAUSED | typeof CASCADE_ERROR__PROPOSAL_ACCOUNTS_MISMATCH | typeof CASCADE_ERROR__PROPOSAL_ALREADY_APPROVED | typeof CASCADE_ERROR__PROPOSAL_NOT_APPROVED | typeof CASCADE_ERROR__PROPOSAL_TOO_LARGE | typeof CASCADE_ERROR__SALARIED_ACCRUAL_MODE | typeof CASCADE_ERROR__SHIFT_IN_PROGRESS | typeof CASCADE_ERROR__SHIFT_NOT_FINAL | typeof CASCADE_ERROR__SHIFT_NOT_VOIDABLE | typeof CASCADE_ERROR__STREAM_ALREADY_ACCEPTED | typeof CASCADE_ERROR__STREAM_AWAITING_ACCEPTANCE | typeof CASCADE_ERROR__STREAM_INACTIVE | typeof CASCADE_ERROR__STREAM_STILL_ACTIVE | typeof CASCADE_ERROR__UNAUTHORIZED_ADMIN | typeof CASCADE_ERROR__UNAUTHORIZED_EMPLOYEE | typeof CASCADE_ERROR__UNAUTHORIZED_EMPLOYER | typeof CASCADE_ERROR__UNAUTHORIZED_GARNISHMENT_ISSUER | typeof CASCADE_ERROR__UNAUTHORIZED_GUARDIAN | typeof CASCADE_ERROR__UNAUTHORIZED_MULTISIG_SIGNER | typeof CASCADE_ERROR__UNAUTHORIZED_PENDING_ADMIN | typeof CASCADE_ERROR__UNAUTHORIZED_PENDING_EMPLOYER | typeof CASCADE_ERROR__UNAUTHORIZED_TIMESHEET_SUBMITTER | typeof CASCADE_ERROR__UNSUPPORTED_EMPLOYER_INSTRUCTION | typeof CASCADE_ERROR__UNSUPPORTED_MINT_DECIMALS | typeof CASCADE_ERROR__VAULT_BALANCE_INVARIANT_VIOLATED;
 
 let cascadeErrorMessages: Record<CascadeError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
-  cascadeErrorMessages = { [CASCADE_ERROR__EMPLOYEE_STILL_ACTIVE]: `Employee is still active, cannot perform emergency withdrawal`, [CASCADE_ERROR__EMPLOYER_LOCK_ACTIVE]: `Employer lock period has not expired yet`, [CASCADE_ERROR__INSUFFICIENT_BALANCE]: `Insufficient balance available for withdrawal`, [CASCADE_ERROR__INVALID_STREAM_ACCOUNTING]: `Stream accounting invariant violated`, [CASCADE_ERROR__INVALID_TIMESTAMP]: `Invalid timestamp detected`, [CASCADE_ERROR__INVALID_TOKEN_ACCOUNT]: `Provided token account does not match expected owner or mint`, [CASCADE_ERROR__MATH_OVERFLOW]: `Mathematical operation overflow`, [CASCADE_ERROR__STREAM_INACTIVE]: `This stream is no longer active`, [CASCADE_ERROR__STREAM_STILL_ACTIVE]: `Stream is still active and cannot be closed`, [CASCADE_ERROR__UNAUTHORIZED_EMPLOYEE]: `Only the employee can perform this action`, [CASCADE_ERROR__UNAUTHORIZED_EMPLOYER]: `Only the employer can perform this action`, [CASCADE_ERROR__UNSUPPORTED_MINT_DECIMALS]: `Only 6-decimal stablecoin mints are supported`, [CASCADE_ERROR__VAULT_BALANCE_INVARIANT_VIOLATED]: `Vault balance is lower than stream accounting expects` };
+  cascadeErrorMessages = { [CASCADE_ERROR__EMERGENCY_WITHDRAW_ALREADY_ANNOUNCED]: `An emergency withdrawal has already been announced`, [CASCADE_ERROR__EMERGENCY_WITHDRAW_NOTICE_ACTIVE]: `Emergency withdrawal notice period has not elapsed yet`, [CASCADE_ERROR__EMERGENCY_WITHDRAW_NOT_ANNOUNCED]: `No emergency withdrawal has been announced`, [CASCADE_ERROR__EMPLOYEE_RECOVERY_ALREADY_PENDING]: `An employee recovery is already pending`, [CASCADE_ERROR__EMPLOYEE_RECOVERY_NOT_PENDING]: `No employee recovery is pending`, [CASCADE_ERROR__EMPLOYEE_RECOVERY_TIME_LOCK_ACTIVE]: `Employee recovery time lock has not elapsed yet`, [CASCADE_ERROR__EMPLOYEE_STILL_ACTIVE]: `Employee is still active, cannot perform emergency withdrawal`, [CASCADE_ERROR__EMPLOYER_LOCK_ACTIVE]: `Employer lock period has not expired yet`, [CASCADE_ERROR__EXPENSE_CLAIM_NOT_PENDING]: `Expense claim has already been reviewed`, [CASCADE_ERROR__GARNISHMENT_ALREADY_ACTIVE]: `A garnishment order is already active on this stream`, [CASCADE_ERROR__GARNISHMENT_ORDER_INACTIVE]: `Garnishment order is not active`, [CASCADE_ERROR__HOURLY_DEDUCTION_ON_SALARIED_STREAM]: `Per-hour deductions are only available on hourly streams`, [CASCADE_ERROR__INSUFFICIENT_BALANCE]: `Insufficient balance available for withdrawal`, [CASCADE_ERROR__INSUFFICIENT_VAULT_SURPLUS]: `Bonus exceeds the vault funding the employee has not yet earned`, [CASCADE_ERROR__INVALID_DEDUCTIONS]: `Deductions must be unique, non-zero, within the hourly rate and match at most 100%`, [CASCADE_ERROR__INVALID_EXPENSE_CLAIM]: `Expense claims must be for a non-zero amount`, [CASCADE_ERROR__INVALID_FEE_SCHEDULE]: `Protocol fees must not exceed 500 basis points`, [CASCADE_ERROR__INVALID_GARNISHMENT_ORDER]: `Garnishment rate must be between 1 and 2500 basis points and the ordered amount non-zero`, [CASCADE_ERROR__INVALID_INACTIVITY_THRESHOLD]: `Inactivity threshold is outside the allowed range`, [CASCADE_ERROR__INVALID_MAX_SHIFT_LENGTH]: `Maximum shift length must be between 1 and 24 hours`, [CASCADE_ERROR__INVALID_MILESTONE_STATUS]: `Milestone is not in the required state`, [CASCADE_ERROR__INVALID_MULTISIG_SIGNERS]: `Multisig signers must be unique and within the supported count`, [CASCADE_ERROR__INVALID_MULTISIG_THRESHOLD]: `Multisig threshold must be between one and the number of signers`, [CASCADE_ERROR__INVALID_NEW_EMPLOYEE]: `New employee must differ from the current employee`, [CASCADE_ERROR__INVALID_ORGANIZATION_ROLES]: `Unknown organization role flags`, [CASCADE_ERROR__INVALID_PAUSE_FLAGS]: `Unknown pause flags`, [CASCADE_ERROR__INVALID_PAYOUT_SPLITS]: `Payout splits must be unique, non-zero and total at most 10000 basis points`, [CASCADE_ERROR__INVALID_PAY_MULTIPLIERS]: `Pay multipliers must be between 1x and 3x and the night window within the day`, [CASCADE_ERROR__INVALID_SALARY_SCHEDULE]: `Salary must pay a non-zero amount per period`, [CASCADE_ERROR__INVALID_SESSION_KEY_EXPIRY]: `Session key expiry must be in the future and within the maximum duration`, [CASCADE_ERROR__INVALID_STREAM_ACCOUNTING]: `Stream accounting invariant violated`, [CASCADE_ERROR__INVALID_TIMESHEET_PERIOD]: `Timesheet period must be in the past, follow the previous period and fit the approved minutes`, [CASCADE_ERROR__INVALID_TIMESTAMP]: `Invalid timestamp detected`, [CASCADE_ERROR__INVALID_TOKEN_ACCOUNT]: `Provided token account does not match expected owner or mint`, [CASCADE_ERROR__INVALID_WEEKLY_SCHEDULE]: `Schedule windows must end after they start within the day and the UTC offset be at most 14 hours`, [CASCADE_ERROR__INVALID_WITHHOLDING_RATE]: `Withholding rate must be between 1 and 5000 basis points`, [CASCADE_ERROR__LAST_ORGANIZATION_ADMIN]: `An organization must keep at least one admin`, [CASCADE_ERROR__MATH_OVERFLOW]: `Mathematical operation overflow`, [CASCADE_ERROR__MILESTONES_OUTSTANDING]: `Funded milestones must be approved or cancelled first`, [CASCADE_ERROR__MILESTONE_REVIEW_LAPSED]: `Milestone review window has lapsed`, [CASCADE_ERROR__MINT_ALLOWLIST_FULL]: `Mint allowlist is full`, [CASCADE_ERROR__MINT_ALREADY_ALLOWED]: `Mint is already on the program allowlist`, [CASCADE_ERROR__MINT_NOT_ALLOWED]: `Mint is not on the program allowlist`, [CASCADE_ERROR__MISSING_EMPLOYER_TOKEN_ACCOUNT]: `The employer token account was not provided`, [CASCADE_ERROR__MISSING_FEE_VAULT]: `The fee vault for this mint was not provided`, [CASCADE_ERROR__MISSING_GARNISHMENT_ORDER]: `The stream's active garnishment order was not provided`, [CASCADE_ERROR__MISSING_ORGANIZATION_ROLE]: `Member lacks the organization role required for this action`, [CASCADE_ERROR__MISSING_PAYOUT_ACCOUNT]: `A payout destination token account was not provided`, [CASCADE_ERROR__NOT_CLOCKED_IN]: `Employee is not clocked in`, [CASCADE_ERROR__NOT_CLOCK_IN_STREAM]: `Stream does not accrue from clock-ins`, [CASCADE_ERROR__NOT_TIMESHEET_STREAM]: `Stream does not accrue from timesheets`, [CASCADE_ERROR__OPERATION_PAUSED]: `This operation is paused by the guardian`, [CASCADE_ERROR__PROPOSAL_ACCOUNTS_MISMATCH]: `Provided accounts do not match the approved proposal`, [CASCADE_ERROR__PROPOSAL_ALREADY_APPROVED]: `Signer has already approved this proposal`, [CASCADE_ERROR__PROPOSAL_NOT_APPROVED]: `Proposal has not reached the approval threshold`, [CASCADE_ERROR__PROPOSAL_TOO_LARGE]: `Proposal instruction data or account list is too large`, [CASCADE_ERROR__SALARIED_ACCRUAL_MODE]: `Salaried streams accrue on the wall clock only`, [CASCADE_ERROR__SHIFT_IN_PROGRESS]: `Employee is clocked in`, [CASCADE_ERROR__SHIFT_NOT_FINAL]: `Shift is still open or within its review window`, [CASCADE_ERROR__SHIFT_NOT_VOIDABLE]: `Shift cannot be voided`, [CASCADE_ERROR__STREAM_ALREADY_ACCEPTED]: `Stream does not require acceptance`, [CASCADE_ERROR__STREAM_AWAITING_ACCEPTANCE]: `Stream is waiting for the employee to accept it`, [CASCADE_ERROR__STREAM_INACTIVE]: `This stream is no longer active`, [CASCADE_ERROR__STREAM_STILL_ACTIVE]: `Stream is still active and cannot be closed`, [CASCADE_ERROR__UNAUTHORIZED_ADMIN]: `Only the config admin can perform this action`, [CASCADE_ERROR__UNAUTHORIZED_EMPLOYEE]: `Only the employee can perform this action`, [CASCADE_ERROR__UNAUTHORIZED_EMPLOYER]: `Only the employer can perform this action`, [CASCADE_ERROR__UNAUTHORIZED_GARNISHMENT_ISSUER]: `Signer is not the stream's garnishment issuer`, [CASCADE_ERROR__UNAUTHORIZED_GUARDIAN]: `Only the config guardian can perform this action`, [CASCADE_ERROR__UNAUTHORIZED_MULTISIG_SIGNER]: `Signer is not a member of this multisig`, [CASCADE_ERROR__UNAUTHORIZED_PENDING_ADMIN]: `Signer is not the pending config admin`, [CASCADE_ERROR__UNAUTHORIZED_PENDING_EMPLOYER]: `Signer is not the pending employer for this stream`, [CASCADE_ERROR__UNAUTHORIZED_TIMESHEET_SUBMITTER]: `Signer may not submit timesheets for this stream`, [CASCADE_ERROR__UNSUPPORTED_EMPLOYER_INSTRUCTION]: `Instruction cannot be executed on behalf of an employer`, [CASCADE_ERROR__UNSUPPORTED_MINT_DECIMALS]: `Only 6-decimal stablecoin mints are supported`, [CASCADE_ERROR__VAULT_BALANCE_INVARIANT_VIOLATED]: `Vault balance is lower than stream accounting expects` };
 }
 
 export function getCascadeErrorMessage(code: CascadeError): string {
@@ -9,4 +9,5 @@
 export * from './accounts';
 export * from './errors';
 export * from './instructions';
-export * from './programs';
\ No newline at end of file
+export * from './programs';
+export * from './types';
\ No newline at end of file
@@ -8,52 +8,64 @@
 
 import { SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS, SolanaError } from '@solana/errors';
 import { getAccountMetaFactory, getAddressFromResolvedInstructionAccount, type ResolvedInstructionAccount } from '@solana/program-client-core';
-import { combineCodec, fixDecoderSize, fixEncoderSize, getAddressEncoder, getBytesDecoder, getBytesEncoder, getProgramDerivedAddress, getStructDecoder, getStructEncoder, getU64Decoder, getU64Encoder, transformEncoder, type AccountMeta, type AccountSignerMeta, type Address, type FixedSizeCodec, type FixedSizeDecoder, type FixedSizeEncoder, type Instruction, type InstructionWithAccounts, type InstructionWithData, type ReadonlyAccount, type ReadonlyUint8Array, type TransactionSigner, type WritableAccount, type WritableSignerAccount } from 'gill';
+import { combineCodec, fixDecoderSize, fixEncoderSize, getAddressEncoder, getBooleanDecoder, getBooleanEncoder, getBytesDecoder, getBytesEncoder, getI64Decoder, getI64Encoder, getProgramDerivedAddress, getStructDecoder, getStructEncoder, getU64Decoder, getU64Encoder, transformEncoder, type AccountMeta, type AccountSignerMeta, type Address, type Codec, type Decoder, type Encoder, type Instruction, type InstructionWithAccounts, type InstructionWithData, type ReadonlyAccount, type ReadonlySignerAccount, type ReadonlyUint8Array, type TransactionSigner, type WritableAccount, type WritableSignerAccount } from 'gill';
 import { CASCADE_PROGRAM_ADDRESS } from '../programs';
+import { getStreamKindDecoder, getStreamKindEncoder, type StreamKind, type StreamKindArgs } from '../types';
 
 export const CREATE_STREAM_DISCRIMINATOR = new Uint8Array([71, 188, 111, 127, 108, 40, 229, 158]);
 
 export function getCreateStreamDiscriminatorBytes() { return fixEncoderSize(getBytesEncoder(), 8).encode(CREATE_STREAM_DISCRIMINATOR); }
 
-export type CreateStreamInstruction<TProgram extends string = typeof CASCADE_PROGRAM_ADDRESS, TAccountEmployer extends string | AccountMeta<string> = string, TAccountEmployee extends string | AccountMeta<string> = string, TAccountMint extends string | AccountMeta<string> = string, TAccountStream extends string | AccountMeta<string> = string, TAccountVault extends string | AccountMeta<string> = string, TAccountEmployerTokenAccount extends string | AccountMeta<string> = string, TAccountTokenProgram extends string | AccountMeta<string> = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA", TAccountSystemProgram extends string | AccountMeta<string> = "11111111111111111111111111111111", TAccountRent extends string | AccountMeta<string> = "SysvarRent111111111111111111111111111111111", TRemainingAccounts extends readonly AccountMeta<string>[] = []> =
-Instruction<TProgram> & InstructionWithData<ReadonlyUint8Array> & InstructionWithAccounts<[TAccountEmployer extends string ? WritableSignerAccount<TAccountEmployer> & AccountSignerMeta<TAccountEmployer> : TAccountEmployer, TAccountEmployee extends string ? ReadonlyAccount<TAccountEmployee> : TAccountEmployee, TAccountMint extends string ? ReadonlyAccount<TAccountMint> : TAccountMint, TAccountStream extends string ? WritableAccount<TAccountStream> : TAccountStream, TAccountVault extends string ? WritableAccount<TAccountVault> : TAccountVault, TAccountEmployerTokenAccount extends string ? WritableAccount<TAccountEmployerTokenAccount> : TAccountEmployerTokenAccount, TAccountTokenProgram extends string ? ReadonlyAccount<TAccountTokenProgram> : TAccountTokenProgram, TAccountSystemProgram extends string ? ReadonlyAccount<TAccountSystemProgram> : TAccountSystemProgram, TAccountRent extends string ? ReadonlyAccount<TAccountRent> : TAccountRent, ...TRemainingAccounts]>;
+export type CreateStreamInstruction<TProgram extends string = typeof CASCADE_PROGRAM_ADDRESS, TAccountEmployer extends string | AccountMeta<string> = string, TAccountPayer extends string | AccountMeta<string> = string, TAccountEmployee extends string | AccountMeta<string> = string, TAccountMint extends string | AccountMeta<string> = string, TAccountConfig extends string | AccountMeta<string> = string, TAccountStream extends string | AccountMeta<string> = string, TAccountVault extends string | AccountMeta<string> = string, TAccountEmployerTokenAccount extends string | AccountMeta<string> = string, TAccountFeeVault extends string | AccountMeta<string> = string, TAccountTokenProgram extends string | AccountMeta<string> = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA", TAccountSystemProgram extends string | AccountMeta<string> = "11111111111111111111111111111111", TAccountRent extends string | AccountMeta<string> = "SysvarRent111111111111111111111111111111111", TRemainingAccounts extends readonly AccountMeta<string>[] = []> =
+Instruction<TProgram> & InstructionWithData<ReadonlyUint8Array> & InstructionWithAccounts<[TAccountEmployer extends string ? ReadonlySignerAccount<TAccountEmployer> & AccountSignerMeta<TAccountEmployer> : TAccountEmployer, TAccountPayer extends string ? WritableSignerAccount<TAccountPayer> & AccountSignerMeta<TAccountPayer> : TAccountPayer, TAccountEmployee extends string ? ReadonlyAccount<TAccountEmployee> : TAccountEmployee, TAccountMint extends string ? ReadonlyAccount<TAccountMint> : TAccountMint, TAccountConfig extends string ? ReadonlyAccount<TAccountConfig> : TAccountConfig, TAccountStream extends string ? WritableAccount<TAccountStream> : TAccountStream, TAccountVault extends string ? WritableAccount<TAccountVault> : TAccountVault, TAccountEmployerTokenAccount extends string ? WritableAccount<TAccountEmployerTokenAccount> : TAccountEmployerTokenAccount, TAccountFeeVault extends string ? WritableAccount<TAccountFeeVault> : TAccountFeeVault, TAccountTokenProgram extends string ? ReadonlyAccount<TAccountTokenProgram> : TAccountTokenProgram, TAccountSystemProgram extends string ? ReadonlyAccount<TAccountSystemProgram> : TAccountSystemProgram, TAccountRent extends string ? ReadonlyAccount<TAccountRent> : TAccountRent, ...TRemainingAccounts]>;
 
-export type CreateStreamInstructionData = { discriminator: ReadonlyUint8Array; hourlyRate: bigint; totalDeposit: bigint;  };
+export type CreateStreamInstructionData = { discriminator: ReadonlyUint8Array; hourlyRate: bigint; totalDeposit: bigint; inactivityThresholdSeconds: bigint; requireAcceptance: boolean; kind: StreamKind;  };
 
-export type CreateStreamInstructionDataArgs = { hourlyRate: number | bigint; totalDeposit: number | bigint;  };
+export type CreateStreamInstructionDataArgs = { hourlyRate: number | bigint; totalDeposit: number | bigint; inactivityThresholdSeconds: number | bigint; requireAcceptance: boolean; kind: StreamKindArgs;  };
 
-export function getCreateStreamInstructionDataEncoder(): FixedSizeEncoder<CreateStreamInstructionDataArgs> {
-    return transformEncoder(getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)], ['hourlyRate', getU64Encoder()], ['totalDeposit', getU64Encoder()]]), (value) => ({ ...value, discriminator: CREATE_STREAM_DISCRIMINATOR }));
+export function getCreateStreamInstructionDataEncoder(): Encoder<CreateStreamInstructionDataArgs> {
+    return transformEncoder(getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)], ['hourlyRate', getU64Encoder()], ['totalDeposit', getU64Encoder()], ['inactivityThresholdSeconds', getI64Encoder()], ['requireAcceptance', getBooleanEncoder()], ['kind', getStreamKindEncoder()]]), (value) => ({ ...value, discriminator: CREATE_STREAM_DISCRIMINATOR }));
 }
 
-export function getCreateStreamInstructionDataDecoder(): FixedSizeDecoder<CreateStreamInstructionData> {
-    return getStructDecoder([['discriminator', fixDecoderSize(getBytesDecoder(), 8)], ['hourlyRate', getU64Decoder()], ['totalDeposit', getU64Decoder()]]);
+export function getCreateStreamInstructionDataDecoder(): Decoder<CreateStreamInstructionData> {
+    return getStructDecoder([['discriminator', fixDecoderSize(getBytesDecoder(), 8)], ['hourlyRate', getU64Decoder()], ['totalDeposit', getU64Decoder()], ['inactivityThresholdSeconds', getI64Decoder()], ['requireAcceptance', getBooleanDecoder()], ['kind', getStreamKindDecoder()]]);
 }
 
-export function getCreateStreamInstructionDataCodec(): FixedSizeCodec<CreateStreamInstructionDataArgs, CreateStreamInstructionData> {
+export function getCreateStreamInstructionDataCodec(): Codec<CreateStreamInstructionDataArgs, CreateStreamInstructionData> {
     return combineCodec(getCreateStreamInstructionDataEncoder(), getCreateStreamInstructionDataDecoder());
 }
 
-export type CreateStreamAsyncInput<TAccountEmployer extends string = string, TAccountEmployee extends string = string, TAccountMint extends string = string, TAccountStream extends string = string, TAccountVault extends string = string, TAccountEmployerTokenAccount extends string = string, TAccountTokenProgram extends string = string, TAccountSystemProgram extends string = string, TAccountRent extends string = string> =  {
+export type CreateStreamAsyncInput<TAccountEmployer extends string = string, TAccountPayer extends string = string, TAccountEmployee extends string = string, TAccountMint extends string = string, TAccountConfig extends string = string, TAccountStream extends string = string, TAccountVault extends string = string, TAccountEmployerTokenAccount extends string = string, TAccountFeeVault extends string = string, TAccountTokenProgram extends string = string, TAccountSystemProgram extends string = string, TAccountRent extends string = string> =  {
   employer: TransactionSigner<TAccountEmployer>;
+/**
+ * Pays rent for the stream and vault. Separate from `employer` so PDA
+ * employers such as organizations can create streams.
+ */
+payer: TransactionSigner<TAccountPayer>;
 employee: Address<TAccountEmployee>;
 mint: Address<TAccountMint>;
+config?: Address<TAccountConfig>;
 stream?: Address<TAccountStream>;
 vault?: Address<TAccountVault>;
 employerTokenAccount: Address<TAccountEmployerTokenAccount>;
+/** The mint's fee vault. Required whenever a non-zero protocol fee applies. */
+feeVault?: Address<TAccountFeeVault>;
 tokenProgram?: Address<TAccountTokenProgram>;
 systemProgram?: Address<TAccountSystemProgram>;
 rent?: Address<TAccountRent>;
 hourlyRate: CreateStreamInstructionDataArgs["hourlyRate"];
 totalDeposit: CreateStreamInstructionDataArgs["totalDeposit"];
+inactivityThresholdSeconds: CreateStreamInstructionDataArgs["inactivityThresholdSeconds"];
+requireAcceptance: CreateStreamInstructionDataArgs["requireAcceptance"];
+kind: CreateStreamInstructionDataArgs["kind"];
 }
 
-export async function getCreateStreamInstructionAsync<TAccountEmployer extends string, TAccountEmployee extends string, TAccountMint extends string, TAccountStream extends string, TAccountVault extends string, TAccountEmployerTokenAccount extends string, TAccountTokenProgram extends string, TAccountSystemProgram extends string, TAccountRent extends string, TProgramAddress extends Address = typeof CASCADE_PROGRAM_ADDRESS>(input: CreateStreamAsyncInput<TAccountEmployer, TAccountEmployee, TAccountMint, TAccountStream, TAccountVault, TAccountEmployerTokenAccount, TAccountTokenProgram, TAccountSystemProgram, TAccountRent>, config?: { programAddress?: TProgramAddress } ): Promise<CreateStreamInstruction<TProgramAddress, TAccountEmployer, TAccountEmployee, TAccountMint, TAccountStream, TAccountVault, TAccountEmployerTokenAccount, TAccountTokenProgram, TAccountSystemProgram, TAccountRent>> {
+export async function getCreateStreamInstructionAsync<TAccountEmployer extends string, TAccountPayer extends string, TAccountEmployee extends string, TAccountMint extends string, TAccountConfig extends string, TAccountStream extends string, TAccountVault extends string, TAccountEmployerTokenAccount extends string, TAccountFeeVault extends string, TAccountTokenProgram extends string, TAccountSystemProgram extends string, TAccountRent extends string, TProgramAddress extends Address = typeof CASCADE_PROGRAM_ADDRESS>(input: CreateStreamAsyncInput<TAccountEmployer, TAccountPayer, TAccountEmployee, TAccountMint, TAccountConfig, TAccountStream, TAccountVault, TAccountEmployerTokenAccount, TAccountFeeVault, TAccountTokenProgram, TAccountSystemProgram, TAccountRent>, config?: { programAddress?: TProgramAddress } ): Promise<CreateStreamInstruction<TProgramAddress, TAccountEmployer, TAccountPayer, TAccountEmployee, TAccountMint, TAccountConfig, TAccountStream, TAccountVault, TAccountEmployerTokenAccount, TAccountFeeVault, TAccountTokenProgram, TAccountSystemProgram, TAccountRent>> {
   // Program address.
 const programAddress = config?.programAddress ?? CASCADE_PROGRAM_ADDRESS;
 
  // Original accounts.
-const originalAccounts = { employer: { value: input.employer ?? null, isWritable: true }, employee: { value: input.employee ?? null, isWritable: false }, mint: { value: input.mint ?? null, isWritable: false }, stream: { value: input.stream ?? null, isWritable: true }, vault: { value: input.vault ?? null, isWritable: true }, employerTokenAccount: { value: input.employerTokenAccount ?? null, isWritable: true }, tokenProgram: { value: input.tokenProgram ?? null, isWritable: false }, systemProgram: { value: input.systemProgram ?? null, isWritable: false }, rent: { value: input.rent ?? null, isWritable: false } }
+const originalAccounts = { employer: { value: input.employer ?? null, isWritable: false }, payer: { value: input.payer ?? null, isWritable: true }, employee: { value: input.employee ?? null, isWritable: false }, mint: { value: input.mint ?? null, isWritable: false }, config: { value: input.config ?? null, isWritable: false }, stream: { value: input.stream ?? null, isWritable: true }, vault: { value: input.vault ?? null, isWritable: true }, employerTokenAccount: { value: input.employerTokenAccount ?? null, isWritable: true }, feeVault: { value: input.feeVault ?? null, isWritable: true }, tokenProgram: { value: input.tokenProgram ?? null, isWritable: false }, systemProgram: { value: input.systemProgram ?? null, isWritable: false }, rent: { value: input.rent ?? null, isWritable: false } }
 const accounts = originalAccounts as Record<keyof typeof originalAccounts, ResolvedInstructionAccount>;
 
 
@@ -62,12 +74,18 @@ const args = { ...input,  };
 
 
 // Resolve default values.
+if (!accounts.config.value) {
+accounts.config.value = await getProgramDerivedAddress({ programAddress, seeds: [getBytesEncoder().encode(new Uint8Array([99, 111, 110, 102, 105, 103]))] });
+}
 if (!accounts.stream.value) {
 accounts.stream.value = await getProgramDerivedAddress({ programAddress, seeds: [getBytesEncoder().encode(new Uint8Array([115, 116, 114, 101, 97, 109])), getAddressEncoder().encode(getAddressFromResolvedInstructionAccount("employer", accounts.employer.value)), getAddressEncoder().encode(getAddressFromResolvedInstructionAccount("employee", accounts.employee.value))] });
 }
 if (!accounts.vault.value) {
 accounts.vault.value = await getProgramDerivedAddress({ programAddress, seeds: [getBytesEncoder().encode(new Uint8Array([118, 97, 117, 108, 116])), getAddressEncoder().encode(getAddressFromResolvedInstructionAccount("stream", accounts.stream.value))] });
 }
+if (!accounts.feeVault.value) {
+accounts.feeVault.value = await getProgramDerivedAddress({ programAddress, seeds: [getBytesEncoder().encode(new Uint8Array([102, 101, 101, 95, 118, 97, 117, 108, 116])), getAddressEncoder().encode(getAddressFromResolvedInstructionAccount("mint", accounts.mint.value))] });
+}
 if (!accounts.tokenProgram.value) {
 accounts.tokenProgram.value = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
 }
@@ -79,29 +97,40 @@ accounts.rent.value = 'SysvarRent111111111111111111111111111111111' as Address<'
 }
 
 const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
-return Object.freeze({ accounts: [getAccountMeta("employer", accounts.employer), getAccountMeta("employee", accounts.employee), getAccountMeta("mint", accounts.mint), getAccountMeta("stream", accounts.stream), getAccountMeta("vault", accounts.vault), getAccountMeta("employerTokenAccount", accounts.employerTokenAccount), getAccountMeta("tokenProgram", accounts.tokenProgram), getAccountMeta("systemProgram", accounts.systemProgram), getAccountMeta("rent", accounts.rent)], data: getCreateStreamInstructionDataEncoder().encode(args as CreateStreamInstructionDataArgs), programAddress } as CreateStreamInstruction<TProgramAddress, TAccountEmployer, TAccountEmployee, TAccountMint, TAccountStream, TAccountVault, TAccountEmployerTokenAccount, TAccountTokenProgram, TAccountSystemProgram, TAccountRent>);
+return Object.freeze({ accounts: [getAccountMeta("employer", accounts.employer), getAccountMeta("payer", accounts.payer), getAccountMeta("employee", accounts.employee), getAccountMeta("mint", accounts.mint), getAccountMeta("config", accounts.config), getAccountMeta("stream", accounts.stream), getAccountMeta("vault", accounts.vault), getAccountMeta("employerTokenAccount", accounts.employerTokenAccount), getAccountMeta("feeVault", accounts.feeVault), getAccountMeta("tokenProgram", accounts.tokenProgram), getAccountMeta("systemProgram", accounts.systemProgram), getAccountMeta("rent", accounts.rent)], data: getCreateStreamInstructionDataEncoder().encode(args as CreateStreamInstructionDataArgs), programAddress } as CreateStreamInstruction<TProgramAddress, TAccountEmployer, TAccountPayer, TAccountEmployee, TAccountMint, TAccountConfig, TAccountStream, TAccountVault, TAccountEmployerTokenAccount, TAccountFeeVault, TAccountTokenProgram, TAccountSystemProgram, TAccountRent>);
 }
 
-export type CreateStreamInput<TAccountEmployer extends string = string, TAccountEmployee extends string = string, TAccountMint extends string = string, TAccountStream extends string = string, TAccountVault extends string = string, TAccountEmployerTokenAccount extends string = string, TAccountTokenProgram extends string = string, TAccountSystemProgram extends string = string, TAccountRent extends string = string> =  {
+export type CreateStreamInput<TAccountEmployer extends string = string, TAccountPayer extends string = string, TAccountEmployee extends string = string, TAccountMint extends string = string, TAccountConfig extends string = string, TAccountStream extends string = string, TAccountVault extends string = string, TAccountEmployerTokenAccount extends string = string, TAccountFeeVault extends string = string, TAccountTokenProgram extends string = string, TAccountSystemProgram extends string = string, TAccountRent extends string = string> =  {
   employer: TransactionSigner<TAccountEmployer>;
+/**
+ * Pays rent for the stream and vault. Separate from `employer` so PDA
+ * employers such as organizations can create streams.
+ */
+payer: TransactionSigner<TAccountPayer>;
 employee: Address<TAccountEmployee>;
 mint: Address<TAccountMint>;
+config: Address<TAccountConfig>;
 stream: Address<TAccountStream>;
 vault: Address<TAccountVault>;
 employerTokenAccount: Address<TAccountEmployerTokenAccount>;
+/** The mint's fee vault. Required whenever a non-zero protocol fee applies. */
+feeVault?: Address<TAccountFeeVault>;
 tokenProgram?: Address<TAccountTokenProgram>;
 systemProgram?: Address<TAccountSystemProgram>;
 rent?: Address<TAccountRent>;
 hourlyRate: CreateStreamInstructionDataArgs["hourlyRate"];
 totalDeposit: CreateStreamInstructionDataArgs["totalDeposit"];
+inactivityThresholdSeconds: CreateStreamInstructionDataArgs["inactivityThresholdSeconds"];
+requireAcceptance: CreateStreamInstructionDataArgs["requireAcceptance"];
+kind: CreateStreamInstructionDataArgs["kind"];
 }
 
-export function getCreateStreamInstruction<TAccountEmployer extends string, TAccountEmployee extends string, TAccountMint extends string, TAccountStream extends string, TAccountVault extends string, TAccountEmployerTokenAccount extends string, TAccountTokenProgram extends string, TAccountSystemProgram extends string, TAccountRent extends string, TProgramAddress extends Address = typeof CASCADE_PROGRAM_ADDRESS>(input: CreateStreamInput<TAccountEmployer, TAccountEmployee, TAccountMint, TAccountStream, TAccountVault, TAccountEmployerTokenAccount, TAccountTokenProgram, TAccountSystemProgram, TAccountRent>, config?: { programAddress?: TProgramAddress } ): CreateStreamInstruction<TProgramAddress, TAccountEmployer, TAccountEmployee, TAccountMint, TAccountStream, TAccountVault, TAccountEmployerTokenAccount, TAccountTokenProgram, TAccountSystemProgram, TAccountRent> {
+export function getCreateStreamInstruction<TAccountEmployer extends string, TAccountPayer extends string, TAccountEmployee extends string, TAccountMint extends string, TAccountConfig extends string, TAccountStream extends string, TAccountVault extends string, TAccountEmployerTokenAccount extends string, TAccountFeeVault extends string, TAccountTokenProgram extends string, TAccountSystemProgram extends string, TAccountRent extends string, TProgramAddress extends Address = typeof CASCADE_PROGRAM_ADDRESS>(input: CreateStreamInput<TAccountEmployer, TAccountPayer, TAccountEmployee, TAccountMint, TAccountConfig, TAccountStream, TAccountVault, TAccountEmployerTokenAccount, TAccountFeeVault, TAccountTokenProgram, TAccountSystemProgram, TAccountRent>, config?: { programAddress?: TProgramAddress } ): CreateStreamInstruction<TProgramAddress, TAccountEmployer, TAccountPayer, TAccountEmployee, TAccountMint, TAccountConfig, TAccountStream, TAccountVault, TAccountEmployerTokenAccount, TAccountFeeVault, TAccountTokenProgram, TAccountSystemProgram, TAccountRent> {
   // Program address.
 const programAddress = config?.programAddress ?? CASCADE_PROGRAM_ADDRESS;
 
  // Original accounts.
-const originalAccounts = { employer: { value: input.employer ?? null, isWritable: true }, employee: { value: input.employee ?? null, isWritable: false }, mint: { value: input.mint ?? null, isWritable: false }, stream: { value: input.stream ?? null, isWritable: true }, vault: { value: input.vault ?? null, isWritable: true }, employerTokenAccount: { value: input.employerTokenAccount ?? null, isWritable: true }, tokenProgram: { value: input.tokenProgram ?? null, isWritable: false }, systemProgram: { value: input.systemProgram ?? null, isWritable: false }, rent: { value: input.rent ?? null, isWritable: false } }
+const originalAccounts = { employer: { value: input.employer ?? null, isWritable: false }, payer: { value: input.payer ?? null, isWritable: true }, employee: { value: input.employee ?? null, isWritable: false }, mint: { value: input.mint ?? null, isWritable: false }, config: { value: input.config ?? null, isWritable: false }, stream: { value: input.stream ?? null, isWritable: true }, vault: { value: input.vault ?? null, isWritable: true }, employerTokenAccount: { value: input.employerTokenAccount ?? null, isWritable: true }, feeVault: { value: input.feeVault ?? null, isWritable: true }, tokenProgram: { value: input.tokenProgram ?? null, isWritable: false }, systemProgram: { value: input.systemProgram ?? null, isWritable: false }, rent: { value: input.rent ?? null, isWritable: false } }
 const accounts = originalAccounts as Record<keyof typeof originalAccounts, ResolvedInstructionAccount>;
 
 
@@ -121,26 +150,34 @@ accounts.rent.value = 'SysvarRent111111111111111111111111111111111' as Address<'
 }
 
 const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
-return Object.freeze({ accounts: [getAccountMeta("employer", accounts.employer), getAccountMeta("employee", accounts.employee), getAccountMeta("mint", accounts.mint), getAccountMeta("stream", accounts.stream), getAccountMeta("vault", accounts.vault), getAccountMeta("employerTokenAccount", accounts.employerTokenAccount), getAccountMeta("tokenProgram", accounts.tokenProgram), getAccountMeta("systemProgram", accounts.systemProgram), getAccountMeta("rent", accounts.rent)], data: getCreateStreamInstructionDataEncoder().encode(args as CreateStreamInstructionDataArgs), programAddress } as CreateStreamInstruction<TProgramAddress, TAccountEmployer, TAccountEmployee, TAccountMint, TAccountStream, TAccountVault, TAccountEmployerTokenAccount, TAccountTokenProgram, TAccountSystemProgram, TAccountRent>);
+return Object.freeze({ accounts: [getAccountMeta("employer", accounts.employer), getAccountMeta("payer", accounts.payer), getAccountMeta("employee", accounts.employee), getAccountMeta("mint", accounts.mint), getAccountMeta("config", accounts.config), getAccountMeta("stream", accounts.stream), getAccountMeta("vault", accounts.vault), getAccountMeta("employerTokenAccount", accounts.employerTokenAccount), getAccountMeta("feeVault", accounts.feeVault), getAccountMeta("tokenProgram", accounts.tokenProgram), getAccountMeta("systemProgram", accounts.systemProgram), getAccountMeta("rent", accounts.rent)], data: getCreateStreamInstructionDataEncoder().encode(args as CreateStreamInstructionDataArgs), programAddress } as CreateStreamInstruction<TProgramAddress, TAccountEmployer, TAccountPayer, TAccountEmployee, TAccountMint, TAccountConfig, TAccountStream, TAccountVault, TAccountEmployerTokenAccount, TAccountFeeVault, TAccountTokenProgram, TAccountSystemProgram, TAccountRent>);
 }
 
 export type ParsedCreateStreamInstruction<TProgram extends string = typeof CASCADE_PROGRAM_ADDRESS, TAccountMetas extends readonly AccountMeta[] = readonly AccountMeta[]> = { programAddress: Address<TProgram>;
 accounts: {
 employer: TAccountMetas[0];
-employee: TAccountMetas[1];
-mint: TAccountMetas[2];
-stream: TAccountMetas[3];
-vault: TAccountMetas[4];
-employerTokenAccount: TAccountMetas[5];
-tokenProgram: TAccountMetas[6];
-systemProgram: TAccountMetas[7];
-rent: TAccountMetas[8];
+/**
+ * Pays rent for the stream and vault. Separate from `employer` so PDA
+ * employers such as organizations can create streams.
+ */
+payer: TAccountMetas[1];
+employee: TAccountMetas[2];
+mint: TAccountMetas[3];
+config: TAccountMetas[4];
+stream: TAccountMetas[5];
+vault: TAccountMetas[6];
+employerTokenAccount: TAccountMetas[7];
+/** The mint's fee vault. Required whenever a non-zero protocol fee applies. */
+feeVault?: TAccountMetas[8] | undefined;
+tokenProgram: TAccountMetas[9];
+systemProgram: TAccountMetas[10];
+rent: TAccountMetas[11];
 };
 data: CreateStreamInstructionData; };
 
 export function parseCreateStreamInstruction<TProgram extends string, TAccountMetas extends readonly AccountMeta[]>(instruction: Instruction<TProgram> & InstructionWithAccounts<TAccountMetas> & InstructionWithData<ReadonlyUint8Array>): ParsedCreateStreamInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
-  throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS, { actualAccountMetas: instruction.accounts.length, expectedAccountMetas: 9 });
+  if (instruction.accounts.length < 12) {
+  throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS, { actualAccountMetas: instruction.accounts.length, expectedAccountMetas: 12 });
 }
 let accountIndex = 0;
 const getNextAccount = () => {
@@ -148,5 +185,9 @@ const getNextAccount = () => {
   accountIndex += 1;
   return accountMeta;
 }
-  return { programAddress: instruction.programAddress, accounts: { employer: getNextAccount(), employee: getNextAccount(), mint: getNextAccount(), stream: getNextAccount(), vault: getNextAccount(), employerTokenAccount: getNextAccount(), tokenProgram: getNextAccount(), systemProgram: getNextAccount(), rent: getNextAccount() }, data: getCreateStreamInstructionDataDecoder().decode(instruction.data) };
+const getNextOptionalAccount = () => {
+  const accountMeta = getNextAccount();
+  return accountMeta.address === CASCADE_PROGRAM_ADDRESS ? undefined : accountMeta;
+};
+  return { programAddress: instruction.programAddress, accounts: { employer: getNextAccount(), payer: getNextAccount(), employee: getNextAccount(), mint: getNextAccount(), config: getNextAccount(), stream: getNextAccount(), vault: getNextAccount(), employerTokenAccount: getNextAccount(), feeVault: getNextOptionalAccount(), tokenProgram: getNextAccount(), systemProgram: getNextAccount(), rent: getNextAccount() }, data: getCreateStreamInstructionDataDecoder().decode(instruction.data) };
 }
\ No newline at end of file
@@ -11,30 +11,30 @@ import { getAccountMetaFactory, type ResolvedInstructionAccount } from '@solana/
 import { combineCodec, fixDecoderSize, fixEncoderSize, getBytesDecoder, getBytesEncoder, getStructDecoder, getStructEncoder, transformEncoder, type AccountMeta, type AccountSignerMeta, type Address, type FixedSizeCodec, type FixedSizeDecoder, type FixedSizeEncoder, type Instruction, type InstructionWithAccounts, type InstructionWithData, type ReadonlyAccount, type ReadonlyUint8Array, type TransactionSigner, type WritableAccount, type WritableSignerAccount } from 'gill';
 import { CASCADE_PROGRAM_ADDRESS } from '../programs';
 
-export const EMPLOYER_EMERGENCY_WITHDRAW_DISCRIMINATOR = new Uint8Array([53, 243, 175, 174, 220, 22, 246, 211]);
+export const EXECUTE_EMERGENCY_WITHDRAW_DISCRIMINATOR = new Uint8Array([229, 189, 185, 75, 199, 173, 142, 132]);
 
-export function getEmployerEmergencyWithdrawDiscriminatorBytes() { return fixEncoderSize(getBytesEncoder(), 8).encode(EMPLOYER_EMERGENCY_WITHDRAW_DISCRIMINATOR); }
+export function getExecuteEmergencyWithdrawDiscriminatorBytes() { return fixEncoderSize(getBytesEncoder(), 8).encode(EXECUTE_EMERGENCY_WITHDRAW_DISCRIMINATOR); }
 
-export type EmployerEmergencyWithdrawInstruction<TProgram extends string = typeof CASCADE_PROGRAM_ADDRESS, TAccountEmployer extends string | AccountMeta<string> = string, TAccountStream extends string | AccountMeta<string> = string, TAccountMint extends string | AccountMeta<string> = string, TAccountVault extends string | AccountMeta<string> = string, TAccountEmployerTokenAccount extends string | AccountMeta<string> = string, TAccountTokenProgram extends string | AccountMeta<string> = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA", TRemainingAccounts extends readonly AccountMeta<string>[] = []> =
+export type ExecuteEmergencyWithdrawInstruction<TProgram extends string = typeof CASCADE_PROGRAM_ADDRESS, TAccountEmployer extends string | AccountMeta<string> = string, TAccountStream extends string | AccountMeta<string> = string, TAccountMint extends string | AccountMeta<string> = string, TAccountVault extends string | AccountMeta<string> = string, TAccountEmployerTokenAccount extends string | AccountMeta<string> = string, TAccountTokenProgram extends string | AccountMeta<string> = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA", TRemainingAccounts extends readonly AccountMeta<string>[] = []> =
 Instruction<TProgram> & InstructionWithData<ReadonlyUint8Array> & InstructionWithAccounts<[TAccountEmployer extends string ? WritableSignerAccount<TAccountEmployer> & AccountSignerMeta<TAccountEmployer> : TAccountEmployer, TAccountStream extends string ? WritableAccount<TAccountStream> : TAccountStream, TAccountMint extends string ? ReadonlyAccount<TAccountMint> : TAccountMint, TAccountVault extends string ? WritableAccount<TAccountVault> : TAccountVault, TAccountEmployerTokenAccount extends string ? WritableAccount<TAccountEmployerTokenAccount> : TAccountEmployerTokenAccount, TAccountTokenProgram extends string ? ReadonlyAccount<TAccountTokenProgram> : TAccountTokenProgram, ...TRemainingAccounts]>;
 
-export type EmployerEmergencyWithdrawInstructionData = { discriminator: ReadonlyUint8Array;  };
+export type ExecuteEmergencyWithdrawInstructionData = { discriminator: ReadonlyUint8Array;  };
 
-export type EmployerEmergencyWithdrawInstructionDataArgs = {  };
+export type ExecuteEmergencyWithdrawInstructionDataArgs = {  };
 
-export function getEmployerEmergencyWithdrawInstructionDataEncoder(): FixedSizeEncoder<EmployerEmergencyWithdrawInstructionDataArgs> {
-    return transformEncoder(getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]), (value) => ({ ...value, discriminator: EMPLOYER_EMERGENCY_WITHDRAW_DISCRIMINATOR }));
+export function getExecuteEmergencyWithdrawInstructionDataEncoder(): FixedSizeEncoder<ExecuteEmergencyWithdrawInstructionDataArgs> {
+    return transformEncoder(getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]), (value) => ({ ...value, discriminator: EXECUTE_EMERGENCY_WITHDRAW_DISCRIMINATOR }));
 }
 
-export function getEmployerEmergencyWithdrawInstructionDataDecoder(): FixedSizeDecoder<EmployerEmergencyWithdrawInstructionData> {
+export function getExecuteEmergencyWithdrawInstructionDataDecoder(): FixedSizeDecoder<ExecuteEmergencyWithdrawInstructionData> {
     return getStructDecoder([['discriminator', fixDecoderSize(getBytesDecoder(), 8)]]);
 }
 
-export function getEmployerEmergencyWithdrawInstructionDataCodec(): FixedSizeCodec<EmployerEmergencyWithdrawInstructionDataArgs, EmployerEmergencyWithdrawInstructionData> {
-    return combineCodec(getEmployerEmergencyWithdrawInstructionDataEncoder(), getEmployerEmergencyWithdrawInstructionDataDecoder());
+export function getExecuteEmergencyWithdrawInstructionDataCodec(): FixedSizeCodec<ExecuteEmergencyWithdrawInstructionDataArgs, ExecuteEmergencyWithdrawInstructionData> {
+    return combineCodec(getExecuteEmergencyWithdrawInstructionDataEncoder(), getExecuteEmergencyWithdrawInstructionDataDecoder());
 }
 
-export type EmployerEmergencyWithdrawInput<TAccountEmployer extends string = string, TAccountStream extends string = string, TAccountMint extends string = string, TAccountVault extends string = string, TAccountEmployerTokenAccount extends string = string, TAccountTokenProgram extends string = string> =  {
+export type ExecuteEmergencyWithdrawInput<TAccountEmployer extends string = string, TAccountStream extends string = string, TAccountMint extends string = string, TAccountVault extends string = string, TAccountEmployerTokenAccount extends string = string, TAccountTokenProgram extends string = string> =  {
   employer: TransactionSigner<TAccountEmployer>;
 stream: Address<TAccountStream>;
 mint: Address<TAccountMint>;
@@ -43,7 +43,7 @@ employerTokenAccount: Address<TAccountEmployerTokenAccount>;
 tokenProgram?: Address<TAccountTokenProgram>;
 }
 
-export function getEmployerEmergencyWithdrawInstruction<TAccountEmployer extends string, TAccountStream extends string, TAccountMint extends string, TAccountVault extends string, TAccountEmployerTokenAccount extends string, TAccountTokenProgram extends string, TProgramAddress extends Address = typeof CASCADE_PROGRAM_ADDRESS>(input: EmployerEmergencyWithdrawInput<TAccountEmployer, TAccountStream, TAccountMint, TAccountVault, TAccountEmployerTokenAccount, TAccountTokenProgram>, config?: { programAddress?: TProgramAddress } ): EmployerEmergencyWithdrawInstruction<TProgramAddress, TAccountEmployer, TAccountStream, TAccountMint, TAccountVault, TAccountEmployerTokenAccount, TAccountTokenProgram> {
+export function getExecuteEmergencyWithdrawInstruction<TAccountEmployer extends string, TAccountStream extends string, TAccountMint extends string, TAccountVault extends string, TAccountEmployerTokenAccount extends string, TAccountTokenProgram extends string, TProgramAddress extends Address = typeof CASCADE_PROGRAM_ADDRESS>(input: ExecuteEmergencyWithdrawInput<TAccountEmployer, TAccountStream, TAccountMint, TAccountVault, TAccountEmployerTokenAccount, TAccountTokenProgram>, config?: { programAddress?: TProgramAddress } ): ExecuteEmergencyWithdrawInstruction<TProgramAddress, TAccountEmployer, TAccountStream, TAccountMint, TAccountVault, TAccountEmployerTokenAccount, TAccountTokenProgram> {
   // Program address.
 const programAddress = config?.programAddress ?? CASCADE_PROGRAM_ADDRESS;
 
@@ -58,10 +58,10 @@ accounts.tokenProgram.value = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as A
 }
 
 const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
-return Object.freeze({ accounts: [getAccountMeta("employer", accounts.employer), getAccountMeta("stream", accounts.stream), getAccountMeta("mint", accounts.mint), getAccountMeta("vault", accounts.vault), getAccountMeta("employerTokenAccount", accounts.employerTokenAccount), getAccountMeta("tokenProgram", accounts.tokenProgram)], data: getEmployerEmergencyWithdrawInstructionDataEncoder().encode({}), programAddress } as EmployerEmergencyWithdrawInstruction<TProgramAddress, TAccountEmployer, TAccountStream, TAccountMint, TAccountVault, TAccountEmployerTokenAccount, TAccountTokenProgram>);
+return Object.freeze({ accounts: [getAccountMeta("employer", accounts.employer), getAccountMeta("stream", accounts.stream), getAccountMeta("mint", accounts.mint), getAccountMeta("vault", accounts.vault), getAccountMeta("employerTokenAccount", accounts.employerTokenAccount), getAccountMeta("tokenProgram", accounts.tokenProgram)], data: getExecuteEmergencyWithdrawInstructionDataEncoder().encode({}), programAddress } as ExecuteEmergencyWithdrawInstruction<TProgramAddress, TAccountEmployer, TAccountStream, TAccountMint, TAccountVault, TAccountEmployerTokenAccount, TAccountTokenProgram>);
 }
 
-export type ParsedEmployerEmergencyWithdrawInstruction<TProgram extends string = typeof CASCADE_PROGRAM_ADDRESS, TAccountMetas extends readonly AccountMeta[] = readonly AccountMeta[]> = { programAddress: Address<TProgram>;
+export type ParsedExecuteEmergencyWithdrawInstruction<TProgram extends string = typeof CASCADE_PROGRAM_ADDRESS, TAccountMetas extends readonly AccountMeta[] = readonly AccountMeta[]> = { programAddress: Address<TProgram>;
 accounts: {
 employer: TAccountMetas[0];
 stream: TAccountMetas[1];
@@ -70,9 +70,9 @@ vault: TAccountMetas[3];
 employerTokenAccount: TAccountMetas[4];
 tokenProgram: TAccountMetas[5];
 };
-data: EmployerEmergencyWithdrawInstructionData; };
+data: ExecuteEmergencyWithdrawInstructionData; };
 
-export function parseEmployerEmergencyWithdrawInstruction<TProgram extends string, TAccountMetas extends readonly AccountMeta[]>(instruction: Instruction<TProgram> & InstructionWithAccounts<TAccountMetas> & InstructionWithData<ReadonlyUint8Array>): ParsedEmployerEmergencyWithdrawInstruction<TProgram, TAccountMetas> {
+export function parseExecuteEmergencyWithdrawInstruction<TProgram extends string, TAccountMetas extends readonly AccountMeta[]>(instruction: Instruction<TProgram> & InstructionWithAccounts<TAccountMetas> & InstructionWithData<ReadonlyUint8Array>): ParsedExecuteEmergencyWithdrawInstruction<TProgram, TAccountMetas> {
   if (instruction.accounts.length < 6) {
   throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS, { actualAccountMetas: instruction.accounts.length, expectedAccountMetas: 6 });
 }
@@ -82,5 +82,5 @@ const getNextAccount = () => {
   accountIndex += 1;
   return accountMeta;
 }
-  return { programAddress: instruction.programAddress, accounts: { employer: getNextAccount(), stream: getNextAccount(), mint: getNextAccount(), vault: getNextAccount(), employerTokenAccount: getNextAccount(), tokenProgram: getNextAccount() }, data: getEmployerEmergencyWithdrawInstructionDataDecoder().decode(instruction.data) };
+  return { programAddress: instruction.programAddress, accounts: { employer: getNextAccount(), stream: getNextAccount(), mint: getNextAccount(), vault: getNextAccount(), employerTokenAccount: getNextAccount(), tokenProgram: getNextAccount() }, data: getExecuteEmergencyWithdrawInstructionDataDecoder().decode(instruction.data) };
 }
\ No newline at end of file
@@ -6,9 +6,71 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptConfigAdmin';
+export * from './acceptEmployerTransfer';
+export * from './acceptStream';
+export * from './addAllowedMint';
+export * from './addOrganizationMember';
+export * from './advance';
+export * from './announceEmergencyWithdraw';
+export * from './approveExpenseClaim';
+export * from './approveMilestone';
+export * from './approveMultisigProposal';
+export * from './awardBonus';
+export * from './cancelEmployeeRecovery';
+export * from './cancelMilestone';
+export * from './cancelMultisigProposal';
+export * from './clockIn';
+export * from './clockOut';
+export * from './closeShiftRecord';
 export * from './closeStream';
+export * from './collectProtocolFees';
+export * from './completeEmployeeRecovery';
+export * from './createMultisig';
+export * from './createOrganization';
 export * from './createStream';
-export * from './employerEmergencyWithdraw';
+export * from './executeEmergencyWithdraw';
+export * from './executeMultisigProposal';
+export * from './executeOrganizationInstruction';
+export * from './fundMilestone';
+export * from './initializeConfig';
+export * from './initializeFeeVault';
+export * from './initiateEmployeeRecovery';
+export * from './issueGarnishmentOrder';
+export * from './proposeConfigAdmin';
+export * from './proposeEmployerTransfer';
+export * from './proposeMultisigInstruction';
 export * from './refreshActivity';
+export * from './registerSessionKey';
+export * from './rejectExpenseClaim';
+export * from './rejectMilestone';
+export * from './releaseGarnishmentOrder';
+export * from './removeAllowedMint';
+export * from './removeOrganizationMember';
+export * from './revokeSessionKey';
+export * from './rotateEmployee';
+export * from './setAccrualMode';
+export * from './setAdvanceLimit';
+export * from './setDeductions';
+export * from './setFeeSchedule';
+export * from './setGarnishmentIssuer';
+export * from './setMaxShiftLength';
+export * from './setMultisigSigners';
+export * from './setPausedOperations';
+export * from './setPayMultipliers';
+export * from './setPayoutDestination';
+export * from './setPayoutSplits';
+export * from './setStreamKind';
+export * from './setTimesheetApprover';
+export * from './setWeeklySchedule';
+export * from './setWithholdingRule';
+export * from './submitExpenseClaim';
+export * from './submitMilestone';
+export * from './submitTimesheet';
 export * from './topUpStream';
-export * from './withdraw';
\ No newline at end of file
+export * from './updateConfigGuardian';
+export * from './updateInactivityThreshold';
+export * from './updateOrganizationMember';
+export * from './voidShift';
+export * from './withdraw';
+export * from './withdrawEmployerTreasury';
\ No newline at end of file
@@ -15,8 +15,8 @@ export const REFRESH_ACTIVITY_DISCRIMINATOR = new Uint8Array([55, 172, 115, 3, 2
 
 export function getRefreshActivityDiscriminatorBytes() { return fixEncoderSize(getBytesEncoder(), 8).encode(REFRESH_ACTIVITY_DISCRIMINATOR); }
 
-export type RefreshActivityInstruction<TProgram extends string = typeof CASCADE_PROGRAM_ADDRESS, TAccountEmployee extends string | AccountMeta<string> = string, TAccountStream extends string | AccountMeta<string> = string, TRemainingAccounts extends readonly AccountMeta<string>[] = []> =
-Instruction<TProgram> & InstructionWithData<ReadonlyUint8Array> & InstructionWithAccounts<[TAccountEmployee extends string ? WritableSignerAccount<TAccountEmployee> & AccountSignerMeta<TAccountEmployee> : TAccountEmployee, TAccountStream extends string ? WritableAccount<TAccountStream> : TAccountStream, ...TRemainingAccounts]>;
+export type RefreshActivityInstruction<TProgram extends string = typeof CASCADE_PROGRAM_ADDRESS, TAccountAuthority extends string | AccountMeta<string> = string, TAccountStream extends string | AccountMeta<string> = string, TRemainingAccounts extends readonly AccountMeta<string>[] = []> =
+Instruction<TProgram> & InstructionWithData<ReadonlyUint8Array> & InstructionWithAccounts<[TAccountAuthority extends string ? WritableSignerAccount<TAccountAuthority> & AccountSignerMeta<TAccountAuthority> : TAccountAuthority, TAccountStream extends string ? WritableAccount<TAccountStream> : TAccountStream, ...TRemainingAccounts]>;
 
 export type RefreshActivityInstructionData = { discriminator: ReadonlyUint8Array;  };
 
@@ -34,29 +34,31 @@ export function getRefreshActivityInstructionDataCodec(): FixedSizeCodec<Refresh
     return combineCodec(getRefreshActivityInstructionDataEncoder(), getRefreshActivityInstructionDataDecoder());
 }
 
-export type RefreshActivityInput<TAccountEmployee extends string = string, TAccountStream extends string = string> =  {
-  employee: TransactionSigner<TAccountEmployee>;
+export type RefreshActivityInput<TAccountAuthority extends string = string, TAccountStream extends string = string> =  {
+  /** The employee, or an unexpired session key registered by the employee. */
+authority: TransactionSigner<TAccountAuthority>;
 stream: Address<TAccountStream>;
 }
 
-export function getRefreshActivityInstruction<TAccountEmployee extends string, TAccountStream extends string, TProgramAddress extends Address = typeof CASCADE_PROGRAM_ADDRESS>(input: RefreshActivityInput<TAccountEmployee, TAccountStream>, config?: { programAddress?: TProgramAddress } ): RefreshActivityInstruction<TProgramAddress, TAccountEmployee, TAccountStream> {
+export function getRefreshActivityInstruction<TAccountAuthority extends string, TAccountStream extends string, TProgramAddress extends Address = typeof CASCADE_PROGRAM_ADDRESS>(input: RefreshActivityInput<TAccountAuthority, TAccountStream>, config?: { programAddress?: TProgramAddress } ): RefreshActivityInstruction<TProgramAddress, TAccountAuthority, TAccountStream> {
   // Program address.
 const programAddress = config?.programAddress ?? CASCADE_PROGRAM_ADDRESS;
 
  // Original accounts.
-const originalAccounts = { employee: { value: input.employee ?? null, isWritable: true }, stream: { value: input.stream ?? null, isWritable: true } }
+const originalAccounts = { authority: { value: input.authority ?? null, isWritable: true }, stream: { value: input.stream ?? null, isWritable: true } }
 const accounts = originalAccounts as Record<keyof typeof originalAccounts, ResolvedInstructionAccount>;
 
 
 
 
 const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
-return Object.freeze({ accounts: [getAccountMeta("employee", accounts.employee), getAccountMeta("stream", accounts.stream)], data: getRefreshActivityInstructionDataEncoder().encode({}), programAddress } as RefreshActivityInstruction<TProgramAddress, TAccountEmployee, TAccountStream>);
+return Object.freeze({ accounts: [getAccountMeta("authority", accounts.authority), getAccountMeta("stream", accounts.stream)], data: getRefreshActivityInstructionDataEncoder().encode({}), programAddress } as RefreshActivityInstruction<TProgramAddress, TAccountAuthority, TAccountStream>);
 }
 
 export type ParsedRefreshActivityInstruction<TProgram extends string = typeof CASCADE_PROGRAM_ADDRESS, TAccountMetas extends readonly AccountMeta[] = readonly AccountMeta[]> = { programAddress: Address<TProgram>;
 accounts: {
-employee: TAccountMetas[0];
+/** The employee, or an unexpired session key registered by the employee. */
+authority: TAccountMetas[0];
 stream: TAccountMetas[1];
 };
 data: RefreshActivityInstructionData; };
@@ -71,5 +73,5 @@ const getNextAccount = () => {
   accountIndex += 1;
   return accountMeta;
 }
-  return { programAddress: instruction.programAddress, accounts: { employee: getNextAccount(), stream: getNextAccount() }, data: getRefreshActivityInstructionDataDecoder().decode(instruction.data) };
+  return { programAddress: instruction.programAddress, accounts: { authority: getNextAccount(), stream: getNextAccount() }, data: getRefreshActivityInstructionDataDecoder().decode(instruction.data) };
 }
\ No newline at end of file
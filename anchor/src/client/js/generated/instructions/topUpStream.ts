@@ -7,16 +7,16 @@
  */
 
 import { SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS, SolanaError } from '@solana/errors';
-import { getAccountMetaFactory, type ResolvedInstructionAccount } from '@solana/program-client-core';
-import { combineCodec, fixDecoderSize, fixEncoderSize, getBytesDecoder, getBytesEncoder, getStructDecoder, getStructEncoder, getU64Decoder, getU64Encoder, transformEncoder, type AccountMeta, type AccountSignerMeta, type Address, type FixedSizeCodec, type FixedSizeDecoder, type FixedSizeEncoder, type Instruction, type InstructionWithAccounts, type InstructionWithData, type ReadonlyAccount, type ReadonlyUint8Array, type TransactionSigner, type WritableAccount, type WritableSignerAccount } from 'gill';
+import { getAccountMetaFactory, getAddressFromResolvedInstructionAccount, type ResolvedInstructionAccount } from '@solana/program-client-core';
+import { combineCodec, fixDecoderSize, fixEncoderSize, getAddressEncoder, getBytesDecoder, getBytesEncoder, getProgramDerivedAddress, getStructDecoder, getStructEncoder, getU64Decoder, getU64Encoder, transformEncoder, type AccountMeta, type AccountSignerMeta, type Address, type FixedSizeCodec, type FixedSizeDecoder, type FixedSizeEncoder, type Instruction, type InstructionWithAccounts, type InstructionWithData, type ReadonlyAccount, type ReadonlyUint8Array, type TransactionSigner, type WritableAccount, type WritableSignerAccount } from 'gill';
 import { CASCADE_PROGRAM_ADDRESS } from '../programs';
 
 export const TOP_UP_STREAM_DISCRIMINATOR = new Uint8Array([12, 244, 26, 215, 160, 204, 9, 151]);
 
 export function getTopUpStreamDiscriminatorBytes() { return fixEncoderSize(getBytesEncoder(), 8).encode(TOP_UP_STREAM_DISCRIMINATOR); }
 
-export type TopUpStreamInstruction<TProgram extends string = typeof CASCADE_PROGRAM_ADDRESS, TAccountEmployer extends string | AccountMeta<string> = string, TAccountStream extends string | AccountMeta<string> = string, TAccountMint extends string | AccountMeta<string> = string, TAccountVault extends string | AccountMeta<string> = string, TAccountEmployerTokenAccount extends string | AccountMeta<string> = string, TAccountTokenProgram extends string | AccountMeta<string> = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA", TRemainingAccounts extends readonly AccountMeta<string>[] = []> =
-Instruction<TProgram> & InstructionWithData<ReadonlyUint8Array> & InstructionWithAccounts<[TAccountEmployer extends string ? WritableSignerAccount<TAccountEmployer> & AccountSignerMeta<TAccountEmployer> : TAccountEmployer, TAccountStream extends string ? WritableAccount<TAccountStream> : TAccountStream, TAccountMint extends string ? ReadonlyAccount<TAccountMint> : TAccountMint, TAccountVault extends string ? WritableAccount<TAccountVault> : TAccountVault, TAccountEmployerTokenAccount extends string ? WritableAccount<TAccountEmployerTokenAccount> : TAccountEmployerTokenAccount, TAccountTokenProgram extends string ? ReadonlyAccount<TAccountTokenProgram> : TAccountTokenProgram, ...TRemainingAccounts]>;
+export type TopUpStreamInstruction<TProgram extends string = typeof CASCADE_PROGRAM_ADDRESS, TAccountEmployer extends string | AccountMeta<string> = string, TAccountStream extends string | AccountMeta<string> = string, TAccountMint extends string | AccountMeta<string> = string, TAccountConfig extends string | AccountMeta<string> = string, TAccountVault extends string | AccountMeta<string> = string, TAccountEmployerTokenAccount extends string | AccountMeta<string> = string, TAccountFeeVault extends string | AccountMeta<string> = string, TAccountTokenProgram extends string | AccountMeta<string> = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA", TRemainingAccounts extends readonly AccountMeta<string>[] = []> =
+Instruction<TProgram> & InstructionWithData<ReadonlyUint8Array> & InstructionWithAccounts<[TAccountEmployer extends string ? WritableSignerAccount<TAccountEmployer> & AccountSignerMeta<TAccountEmployer> : TAccountEmployer, TAccountStream extends string ? WritableAccount<TAccountStream> : TAccountStream, TAccountMint extends string ? ReadonlyAccount<TAccountMint> : TAccountMint, TAccountConfig extends string ? ReadonlyAccount<TAccountConfig> : TAccountConfig, TAccountVault extends string ? WritableAccount<TAccountVault> : TAccountVault, TAccountEmployerTokenAccount extends string ? WritableAccount<TAccountEmployerTokenAccount> : TAccountEmployerTokenAccount, TAccountFeeVault extends string ? WritableAccount<TAccountFeeVault> : TAccountFeeVault, TAccountTokenProgram extends string ? ReadonlyAccount<TAccountTokenProgram> : TAccountTokenProgram, ...TRemainingAccounts]>;
 
 export type TopUpStreamInstructionData = { discriminator: ReadonlyUint8Array; additionalAmount: bigint;  };
 
@@ -34,22 +34,25 @@ export function getTopUpStreamInstructionDataCodec(): FixedSizeCodec<TopUpStream
     return combineCodec(getTopUpStreamInstructionDataEncoder(), getTopUpStreamInstructionDataDecoder());
 }
 
-export type TopUpStreamInput<TAccountEmployer extends string = string, TAccountStream extends string = string, TAccountMint extends string = string, TAccountVault extends string = string, TAccountEmployerTokenAccount extends string = string, TAccountTokenProgram extends string = string> =  {
+export type TopUpStreamAsyncInput<TAccountEmployer extends string = string, TAccountStream extends string = string, TAccountMint extends string = string, TAccountConfig extends string = string, TAccountVault extends string = string, TAccountEmployerTokenAccount extends string = string, TAccountFeeVault extends string = string, TAccountTokenProgram extends string = string> =  {
   employer: TransactionSigner<TAccountEmployer>;
 stream: Address<TAccountStream>;
 mint: Address<TAccountMint>;
+config?: Address<TAccountConfig>;
 vault: Address<TAccountVault>;
 employerTokenAccount: Address<TAccountEmployerTokenAccount>;
+/** The mint's fee vault. Required whenever a non-zero protocol fee applies. */
+feeVault?: Address<TAccountFeeVault>;
 tokenProgram?: Address<TAccountTokenProgram>;
 additionalAmount: TopUpStreamInstructionDataArgs["additionalAmount"];
 }
 
-export function getTopUpStreamInstruction<TAccountEmployer extends string, TAccountStream extends string, TAccountMint extends string, TAccountVault extends string, TAccountEmployerTokenAccount extends string, TAccountTokenProgram extends string, TProgramAddress extends Address = typeof CASCADE_PROGRAM_ADDRESS>(input: TopUpStreamInput<TAccountEmployer, TAccountStream, TAccountMint, TAccountVault, TAccountEmployerTokenAccount, TAccountTokenProgram>, config?: { programAddress?: TProgramAddress } ): TopUpStreamInstruction<TProgramAddress, TAccountEmployer, TAccountStream, TAccountMint, TAccountVault, TAccountEmployerTokenAccount, TAccountTokenProgram> {
+export async function getTopUpStreamInstructionAsync<TAccountEmployer extends string, TAccountStream extends string, TAccountMint extends string, TAccountConfig extends string, TAccountVault extends string, TAccountEmployerTokenAccount extends string, TAccountFeeVault extends string, TAccountTokenProgram extends string, TProgramAddress extends Address = typeof CASCADE_PROGRAM_ADDRESS>(input: TopUpStreamAsyncInput<TAccountEmployer, TAccountStream, TAccountMint, TAccountConfig, TAccountVault, TAccountEmployerTokenAccount, TAccountFeeVault, TAccountTokenProgram>, config?: { programAddress?: TProgramAddress } ): Promise<TopUpStreamInstruction<TProgramAddress, TAccountEmployer, TAccountStream, TAccountMint, TAccountConfig, TAccountVault, TAccountEmployerTokenAccount, TAccountFeeVault, TAccountTokenProgram>> {
   // Program address.
 const programAddress = config?.programAddress ?? CASCADE_PROGRAM_ADDRESS;
 
  // Original accounts.
-const originalAccounts = { employer: { value: input.employer ?? null, isWritable: true }, stream: { value: input.stream ?? null, isWritable: true }, mint: { value: input.mint ?? null, isWritable: false }, vault: { value: input.vault ?? null, isWritable: true }, employerTokenAccount: { value: input.employerTokenAccount ?? null, isWritable: true }, tokenProgram: { value: input.tokenProgram ?? null, isWritable: false } }
+const originalAccounts = { employer: { value: input.employer ?? null, isWritable: true }, stream: { value: input.stream ?? null, isWritable: true }, mint: { value: input.mint ?? null, isWritable: false }, config: { value: input.config ?? null, isWritable: false }, vault: { value: input.vault ?? null, isWritable: true }, employerTokenAccount: { value: input.employerTokenAccount ?? null, isWritable: true }, feeVault: { value: input.feeVault ?? null, isWritable: true }, tokenProgram: { value: input.tokenProgram ?? null, isWritable: false } }
 const accounts = originalAccounts as Record<keyof typeof originalAccounts, ResolvedInstructionAccount>;
 
 
@@ -58,12 +61,53 @@ const args = { ...input,  };
 
 
 // Resolve default values.
+if (!accounts.config.value) {
+accounts.config.value = await getProgramDerivedAddress({ programAddress, seeds: [getBytesEncoder().encode(new Uint8Array([99, 111, 110, 102, 105, 103]))] });
+}
+if (!accounts.feeVault.value) {
+accounts.feeVault.value = await getProgramDerivedAddress({ programAddress, seeds: [getBytesEncoder().encode(new Uint8Array([102, 101, 101, 95, 118, 97, 117, 108, 116])), getAddressEncoder().encode(getAddressFromResolvedInstructionAccount("mint", accounts.mint.value))] });
+}
 if (!accounts.tokenProgram.value) {
 accounts.tokenProgram.value = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
 }
 
 const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
-return Object.freeze({ accounts: [getAccountMeta("employer", accounts.employer), getAccountMeta("stream", accounts.stream), getAccountMeta("mint", accounts.mint), getAccountMeta("vault", accounts.vault), getAccountMeta("employerTokenAccount", accounts.employerTokenAccount), getAccountMeta("tokenProgram", accounts.tokenProgram)], data: getTopUpStreamInstructionDataEncoder().encode(args as TopUpStreamInstructionDataArgs), programAddress } as TopUpStreamInstruction<TProgramAddress, TAccountEmployer, TAccountStream, TAccountMint, TAccountVault, TAccountEmployerTokenAccount, TAccountTokenProgram>);
+return Object.freeze({ accounts: [getAccountMeta("employer", accounts.employer), getAccountMeta("stream", accounts.stream), getAccountMeta("mint", accounts.mint), getAccountMeta("config", accounts.config), getAccountMeta("vault", accounts.vault), getAccountMeta("employerTokenAccount", accounts.employerTokenAccount), getAccountMeta("feeVault", accounts.feeVault), getAccountMeta("tokenProgram", accounts.tokenProgram)], data: getTopUpStreamInstructionDataEncoder().encode(args as TopUpStreamInstructionDataArgs), programAddress } as TopUpStreamInstruction<TProgramAddress, TAccountEmployer, TAccountStream, TAccountMint, TAccountConfig, TAccountVault, TAccountEmployerTokenAccount, TAccountFeeVault, TAccountTokenProgram>);
+}
+
+export type TopUpStreamInput<TAccountEmployer extends string = string, TAccountStream extends string = string, TAccountMint extends string = string, TAccountConfig extends string = string, TAccountVault extends string = string, TAccountEmployerTokenAccount extends string = string, TAccountFeeVault extends string = string, TAccountTokenProgram extends string = string> =  {
+  employer: TransactionSigner<TAccountEmployer>;
+stream: Address<TAccountStream>;
+mint: Address<TAccountMint>;
+config: Address<TAccountConfig>;
+vault: Address<TAccountVault>;
+employerTokenAccount: Address<TAccountEmployerTokenAccount>;
+/** The mint's fee vault. Required whenever a non-zero protocol fee applies. */
+feeVault?: Address<TAccountFeeVault>;
+tokenProgram?: Address<TAccountTokenProgram>;
+additionalAmount: TopUpStreamInstructionDataArgs["additionalAmount"];
+}
+
+export function getTopUpStreamInstruction<TAccountEmployer extends string, TAccountStream extends string, TAccountMint extends string, TAccountConfig extends string, TAccountVault extends string, TAccountEmployerTokenAccount extends string, TAccountFeeVault extends string, TAccountTokenProgram extends string, TProgramAddress extends Address = typeof CASCADE_PROGRAM_ADDRESS>(input: TopUpStreamInput<TAccountEmployer, TAccountStream, TAccountMint, TAccountConfig, TAccountVault, TAccountEmployerTokenAccount, TAccountFeeVault, TAccountTokenProgram>, config?: { programAddress?: TProgramAddress } ): TopUpStreamInstruction<TProgramAddress, TAccountEmployer, TAccountStream, TAccountMint, TAccountConfig, TAccountVault, TAccountEmployerTokenAccount, TAccountFeeVault, TAccountTokenProgram> {
+  // Program address.
+const programAddress = config?.programAddress ?? CASCADE_PROGRAM_ADDRESS;
+
+ // Original accounts.
+const originalAccounts = { employer: { value: input.employer ?? null, isWritable: true }, stream: { value: input.stream ?? null, isWritable: true }, mint: { value: input.mint ?? null, isWritable: false }, config: { value: input.config ?? null, isWritable: false }, vault: { value: input.vault ?? null, isWritable: true }, employerTokenAccount: { value: input.employerTokenAccount ?? null, isWritable: true }, feeVault: { value: input.feeVault ?? null, isWritable: true }, tokenProgram: { value: input.tokenProgram ?? null, isWritable: false } }
+const accounts = originalAccounts as Record<keyof typeof originalAccounts, ResolvedInstructionAccount>;
+
+
+// Original args.
+const args = { ...input,  };
+
+
+// Resolve default values.
+if (!accounts.tokenProgram.value) {
+accounts.tokenProgram.value = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+}
+
+const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+return Object.freeze({ accounts: [getAccountMeta("employer", accounts.employer), getAccountMeta("stream", accounts.stream), getAccountMeta("mint", accounts.mint), getAccountMeta("config", accounts.config), getAccountMeta("vault", accounts.vault), getAccountMeta("employerTokenAccount", accounts.employerTokenAccount), getAccountMeta("feeVault", accounts.feeVault), getAccountMeta("tokenProgram", accounts.tokenProgram)], data: getTopUpStreamInstructionDataEncoder().encode(args as TopUpStreamInstructionDataArgs), programAddress } as TopUpStreamInstruction<TProgramAddress, TAccountEmployer, TAccountStream, TAccountMint, TAccountConfig, TAccountVault, TAccountEmployerTokenAccount, TAccountFeeVault, TAccountTokenProgram>);
 }
 
 export type ParsedTopUpStreamInstruction<TProgram extends string = typeof CASCADE_PROGRAM_ADDRESS, TAccountMetas extends readonly AccountMeta[] = readonly AccountMeta[]> = { programAddress: Address<TProgram>;
@@ -71,15 +115,18 @@ accounts: {
 employer: TAccountMetas[0];
 stream: TAccountMetas[1];
 mint: TAccountMetas[2];
-vault: TAccountMetas[3];
-employerTokenAccount: TAccountMetas[4];
-tokenProgram: TAccountMetas[5];
+config: TAccountMetas[3];
+vault: TAccountMetas[4];
+employerTokenAccount: TAccountMetas[5];
+/** The mint's fee vault. Required whenever a non-zero protocol fee applies. */
+feeVault?: TAccountMetas[6] | undefined;
+tokenProgram: TAccountMetas[7];
 };
 data: TopUpStreamInstructionData; };
 
 export function parseTopUpStreamInstruction<TProgram extends string, TAccountMetas extends readonly AccountMeta[]>(instruction: Instruction<TProgram> & InstructionWithAccounts<TAccountMetas> & InstructionWithData<ReadonlyUint8Array>): ParsedTopUpStreamInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
-  throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS, { actualAccountMetas: instruction.accounts.length, expectedAccountMetas: 6 });
+  if (instruction.accounts.length < 8) {
+  throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS, { actualAccountMetas: instruction.accounts.length, expectedAccountMetas: 8 });
 }
 let accountIndex = 0;
 const getNextAccount = () => {
@@ -87,5 +134,9 @@ const getNextAccount = () => {
   accountIndex += 1;
   return accountMeta;
 }
-  return { programAddress: instruction.programAddress, accounts: { employer: getNextAccount(), stream: getNextAccount(), mint: getNextAccount(), vault: getNextAccount(), employerTokenAccount: getNextAccount(), tokenProgram: getNextAccount() }, data: getTopUpStreamInstructionDataDecoder().decode(instruction.data) };
+const getNextOptionalAccount = () => {
+  const accountMeta = getNextAccount();
+  return accountMeta.address === CASCADE_PROGRAM_ADDRESS ? undefined : accountMeta;
+};
+  return { programAddress: instruction.programAddress, accounts: { employer: getNextAccount(), stream: getNextAccount(), mint: getNextAccount(), config: getNextAccount(), vault: getNextAccount(), employerTokenAccount: getNextAccount(), feeVault: getNextOptionalAccount(), tokenProgram: getNextAccount() }, data: getTopUpStreamInstructionDataDecoder().decode(instruction.data) };
 }
\ No newline at end of file
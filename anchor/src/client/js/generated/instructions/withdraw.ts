@@ -7,16 +7,16 @@
  */
 
 import { SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS, SolanaError } from '@solana/errors';
-import { getAccountMetaFactory, type ResolvedInstructionAccount } from '@solana/program-client-core';
-import { combineCodec, fixDecoderSize, fixEncoderSize, getBytesDecoder, getBytesEncoder, getStructDecoder, getStructEncoder, getU64Decoder, getU64Encoder, transformEncoder, type AccountMeta, type AccountSignerMeta, type Address, type FixedSizeCodec, type FixedSizeDecoder, type FixedSizeEncoder, type Instruction, type InstructionWithAccounts, type InstructionWithData, type ReadonlyAccount, type ReadonlyUint8Array, type TransactionSigner, type WritableAccount, type WritableSignerAccount } from 'gill';
+import { getAccountMetaFactory, getAddressFromResolvedInstructionAccount, type ResolvedInstructionAccount } from '@solana/program-client-core';
+import { combineCodec, fixDecoderSize, fixEncoderSize, getAddressEncoder, getBytesDecoder, getBytesEncoder, getProgramDerivedAddress, getStructDecoder, getStructEncoder, getU64Decoder, getU64Encoder, transformEncoder, type AccountMeta, type AccountSignerMeta, type Address, type FixedSizeCodec, type FixedSizeDecoder, type FixedSizeEncoder, type Instruction, type InstructionWithAccounts, type InstructionWithData, type ReadonlyAccount, type ReadonlyUint8Array, type TransactionSigner, type WritableAccount, type WritableSignerAccount } from 'gill';
 import { CASCADE_PROGRAM_ADDRESS } from '../programs';
 
 export const WITHDRAW_DISCRIMINATOR = new Uint8Array([183, 18, 70, 156, 148, 109, 161, 34]);
 
 export function getWithdrawDiscriminatorBytes() { return fixEncoderSize(getBytesEncoder(), 8).encode(WITHDRAW_DISCRIMINATOR); }
 
-export type WithdrawInstruction<TProgram extends string = typeof CASCADE_PROGRAM_ADDRESS, TAccountEmployee extends string | AccountMeta<string> = string, TAccountStream extends string | AccountMeta<string> = string, TAccountMint extends string | AccountMeta<string> = string, TAccountVault extends string | AccountMeta<string> = string, TAccountEmployeeTokenAccount extends string | AccountMeta<string> = string, TAccountTokenProgram extends string | AccountMeta<string> = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA", TRemainingAccounts extends readonly AccountMeta<string>[] = []> =
-Instruction<TProgram> & InstructionWithData<ReadonlyUint8Array> & InstructionWithAccounts<[TAccountEmployee extends string ? WritableSignerAccount<TAccountEmployee> & AccountSignerMeta<TAccountEmployee> : TAccountEmployee, TAccountStream extends string ? WritableAccount<TAccountStream> : TAccountStream, TAccountMint extends string ? ReadonlyAccount<TAccountMint> : TAccountMint, TAccountVault extends string ? WritableAccount<TAccountVault> : TAccountVault, TAccountEmployeeTokenAccount extends string ? WritableAccount<TAccountEmployeeTokenAccount> : TAccountEmployeeTokenAccount, TAccountTokenProgram extends string ? ReadonlyAccount<TAccountTokenProgram> : TAccountTokenProgram, ...TRemainingAccounts]>;
+export type WithdrawInstruction<TProgram extends string = typeof CASCADE_PROGRAM_ADDRESS, TAccountEmployee extends string | AccountMeta<string> = string, TAccountStream extends string | AccountMeta<string> = string, TAccountMint extends string | AccountMeta<string> = string, TAccountConfig extends string | AccountMeta<string> = string, TAccountVault extends string | AccountMeta<string> = string, TAccountEmployeeTokenAccount extends string | AccountMeta<string> = string, TAccountGarnishmentOrder extends string | AccountMeta<string> = string, TAccountFeeVault extends string | AccountMeta<string> = string, TAccountTokenProgram extends string | AccountMeta<string> = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA", TRemainingAccounts extends readonly AccountMeta<string>[] = []> =
+Instruction<TProgram> & InstructionWithData<ReadonlyUint8Array> & InstructionWithAccounts<[TAccountEmployee extends string ? WritableSignerAccount<TAccountEmployee> & AccountSignerMeta<TAccountEmployee> : TAccountEmployee, TAccountStream extends string ? WritableAccount<TAccountStream> : TAccountStream, TAccountMint extends string ? ReadonlyAccount<TAccountMint> : TAccountMint, TAccountConfig extends string ? ReadonlyAccount<TAccountConfig> : TAccountConfig, TAccountVault extends string ? WritableAccount<TAccountVault> : TAccountVault, TAccountEmployeeTokenAccount extends string ? WritableAccount<TAccountEmployeeTokenAccount> : TAccountEmployeeTokenAccount, TAccountGarnishmentOrder extends string ? WritableAccount<TAccountGarnishmentOrder> : TAccountGarnishmentOrder, TAccountFeeVault extends string ? WritableAccount<TAccountFeeVault> : TAccountFeeVault, TAccountTokenProgram extends string ? ReadonlyAccount<TAccountTokenProgram> : TAccountTokenProgram, ...TRemainingAccounts]>;
 
 export type WithdrawInstructionData = { discriminator: ReadonlyUint8Array; amount: bigint;  };
 
@@ -34,22 +34,31 @@ export function getWithdrawInstructionDataCodec(): FixedSizeCodec<WithdrawInstru
     return combineCodec(getWithdrawInstructionDataEncoder(), getWithdrawInstructionDataDecoder());
 }
 
-export type WithdrawInput<TAccountEmployee extends string = string, TAccountStream extends string = string, TAccountMint extends string = string, TAccountVault extends string = string, TAccountEmployeeTokenAccount extends string = string, TAccountTokenProgram extends string = string> =  {
+export type WithdrawAsyncInput<TAccountEmployee extends string = string, TAccountStream extends string = string, TAccountMint extends string = string, TAccountConfig extends string = string, TAccountVault extends string = string, TAccountEmployeeTokenAccount extends string = string, TAccountGarnishmentOrder extends string = string, TAccountFeeVault extends string = string, TAccountTokenProgram extends string = string> =  {
   employee: TransactionSigner<TAccountEmployee>;
 stream: Address<TAccountStream>;
 mint: Address<TAccountMint>;
+config?: Address<TAccountConfig>;
 vault: Address<TAccountVault>;
+/**
+ * The stream's payout destination if one is configured, otherwise any
+ * token account owned by the employee.
+ */
 employeeTokenAccount: Address<TAccountEmployeeTokenAccount>;
+/** Required when the stream has an active garnishment order. */
+garnishmentOrder?: Address<TAccountGarnishmentOrder>;
+/** The mint's fee vault. Required whenever a non-zero protocol fee applies. */
+feeVault?: Address<TAccountFeeVault>;
 tokenProgram?: Address<TAccountTokenProgram>;
 amount: WithdrawInstructionDataArgs["amount"];
 }
 
-export function getWithdrawInstruction<TAccountEmployee extends string, TAccountStream extends string, TAccountMint extends string, TAccountVault extends string, TAccountEmployeeTokenAccount extends string, TAccountTokenProgram extends string, TProgramAddress extends Address = typeof CASCADE_PROGRAM_ADDRESS>(input: WithdrawInput<TAccountEmployee, TAccountStream, TAccountMint, TAccountVault, TAccountEmployeeTokenAccount, TAccountTokenProgram>, config?: { programAddress?: TProgramAddress } ): WithdrawInstruction<TProgramAddress, TAccountEmployee, TAccountStream, TAccountMint, TAccountVault, TAccountEmployeeTokenAccount, TAccountTokenProgram> {
+export async function getWithdrawInstructionAsync<TAccountEmployee extends string, TAccountStream extends string, TAccountMint extends string, TAccountConfig extends string, TAccountVault extends string, TAccountEmployeeTokenAccount extends string, TAccountGarnishmentOrder extends string, TAccountFeeVault extends string, TAccountTokenProgram extends string, TProgramAddress extends Address = typeof CASCADE_PROGRAM_ADDRESS>(input: WithdrawAsyncInput<TAccountEmployee, TAccountStream, TAccountMint, TAccountConfig, TAccountVault, TAccountEmployeeTokenAccount, TAccountGarnishmentOrder, TAccountFeeVault, TAccountTokenProgram>, config?: { programAddress?: TProgramAddress } ): Promise<WithdrawInstruction<TProgramAddress, TAccountEmployee, TAccountStream, TAccountMint, TAccountConfig, TAccountVault, TAccountEmployeeTokenAccount, TAccountGarnishmentOrder, TAccountFeeVault, TAccountTokenProgram>> {
   // Program address.
 const programAddress = config?.programAddress ?? CASCADE_PROGRAM_ADDRESS;
 
  // Original accounts.
-const originalAccounts = { employee: { value: input.employee ?? null, isWritable: true }, stream: { value: input.stream ?? null, isWritable: true }, mint: { value: input.mint ?? null, isWritable: false }, vault: { value: input.vault ?? null, isWritable: true }, employeeTokenAccount: { value: input.employeeTokenAccount ?? null, isWritable: true }, tokenProgram: { value: input.tokenProgram ?? null, isWritable: false } }
+const originalAccounts = { employee: { value: input.employee ?? null, isWritable: true }, stream: { value: input.stream ?? null, isWritable: true }, mint: { value: input.mint ?? null, isWritable: false }, config: { value: input.config ?? null, isWritable: false }, vault: { value: input.vault ?? null, isWritable: true }, employeeTokenAccount: { value: input.employeeTokenAccount ?? null, isWritable: true }, garnishmentOrder: { value: input.garnishmentOrder ?? null, isWritable: true }, feeVault: { value: input.feeVault ?? null, isWritable: true }, tokenProgram: { value: input.tokenProgram ?? null, isWritable: false } }
 const accounts = originalAccounts as Record<keyof typeof originalAccounts, ResolvedInstructionAccount>;
 
 
@@ -58,12 +67,59 @@ const args = { ...input,  };
 
 
 // Resolve default values.
+if (!accounts.config.value) {
+accounts.config.value = await getProgramDerivedAddress({ programAddress, seeds: [getBytesEncoder().encode(new Uint8Array([99, 111, 110, 102, 105, 103]))] });
+}
+if (!accounts.feeVault.value) {
+accounts.feeVault.value = await getProgramDerivedAddress({ programAddress, seeds: [getBytesEncoder().encode(new Uint8Array([102, 101, 101, 95, 118, 97, 117, 108, 116])), getAddressEncoder().encode(getAddressFromResolvedInstructionAccount("mint", accounts.mint.value))] });
+}
 if (!accounts.tokenProgram.value) {
 accounts.tokenProgram.value = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
 }
 
 const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
-return Object.freeze({ accounts: [getAccountMeta("employee", accounts.employee), getAccountMeta("stream", accounts.stream), getAccountMeta("mint", accounts.mint), getAccountMeta("vault", accounts.vault), getAccountMeta("employeeTokenAccount", accounts.employeeTokenAccount), getAccountMeta("tokenProgram", accounts.tokenProgram)], data: getWithdrawInstructionDataEncoder().encode(args as WithdrawInstructionDataArgs), programAddress } as WithdrawInstruction<TProgramAddress, TAccountEmployee, TAccountStream, TAccountMint, TAccountVault, TAccountEmployeeTokenAccount, TAccountTokenProgram>);
+return Object.freeze({ accounts: [getAccountMeta("employee", accounts.employee), getAccountMeta("stream", accounts.stream), getAccountMeta("mint", accounts.mint), getAccountMeta("config", accounts.config), getAccountMeta("vault", accounts.vault), getAccountMeta("employeeTokenAccount", accounts.employeeTokenAccount), getAccountMeta("garnishmentOrder", accounts.garnishmentOrder), getAccountMeta("feeVault", accounts.feeVault), getAccountMeta("tokenProgram", accounts.tokenProgram)], data: getWithdrawInstructionDataEncoder().encode(args as WithdrawInstructionDataArgs), programAddress } as WithdrawInstruction<TProgramAddress, TAccountEmployee, TAccountStream, TAccountMint, TAccountConfig, TAccountVault, TAccountEmployeeTokenAccount, TAccountGarnishmentOrder, TAccountFeeVault, TAccountTokenProgram>);
+}
+
+export type WithdrawInput<TAccountEmployee extends string = string, TAccountStream extends string = string, TAccountMint extends string = string, TAccountConfig extends string = string, TAccountVault extends string = string, TAccountEmployeeTokenAccount extends string = string, TAccountGarnishmentOrder extends string = string, TAccountFeeVault extends string = string, TAccountTokenProgram extends string = string> =  {
+  employee: TransactionSigner<TAccountEmployee>;
+stream: Address<TAccountStream>;
+mint: Address<TAccountMint>;
+config: Address<TAccountConfig>;
+vault: Address<TAccountVault>;
+/**
+ * The stream's payout destination if one is configured, otherwise any
+ * token account owned by the employee.
+ */
+employeeTokenAccount: Address<TAccountEmployeeTokenAccount>;
+/** Required when the stream has an active garnishment order. */
+garnishmentOrder?: Address<TAccountGarnishmentOrder>;
+/** The mint's fee vault. Required whenever a non-zero protocol fee applies. */
+feeVault?: Address<TAccountFeeVault>;
+tokenProgram?: Address<TAccountTokenProgram>;
+amount: WithdrawInstructionDataArgs["amount"];
+}
+
+export function getWithdrawInstruction<TAccountEmployee extends string, TAccountStream extends string, TAccountMint extends string, TAccountConfig extends string, TAccountVault extends string, TAccountEmployeeTokenAccount extends string, TAccountGarnishmentOrder extends string, TAccountFeeVault extends string, TAccountTokenProgram extends string, TProgramAddress extends Address = typeof CASCADE_PROGRAM_ADDRESS>(input: WithdrawInput<TAccountEmployee, TAccountStream, TAccountMint, TAccountConfig, TAccountVault, TAccountEmployeeTokenAccount, TAccountGarnishmentOrder, TAccountFeeVault, TAccountTokenProgram>, config?: { programAddress?: TProgramAddress } ): WithdrawInstruction<TProgramAddress, TAccountEmployee, TAccountStream, TAccountMint, TAccountConfig, TAccountVault, TAccountEmployeeTokenAccount, TAccountGarnishmentOrder, TAccountFeeVault, TAccountTokenProgram> {
+  // Program address.
+const programAddress = config?.programAddress ?? CASCADE_PROGRAM_ADDRESS;
+
+ // Original accounts.
+const originalAccounts = { employee: { value: input.employee ?? null, isWritable: true }, stream: { value: input.stream ?? null, isWritable: true }, mint: { value: input.mint ?? null, isWritable: false }, config: { value: input.config ?? null, isWritable: false }, vault: { value: input.vault ?? null, isWritable: true }, employeeTokenAccount: { value: input.employeeTokenAccount ?? null, isWritable: true }, garnishmentOrder: { value: input.garnishmentOrder ?? null, isWritable: true }, feeVault: { value: input.feeVault ?? null, isWritable: true }, tokenProgram: { value: input.tokenProgram ?? null, isWritable: false } }
+const accounts = originalAccounts as Record<keyof typeof originalAccounts, ResolvedInstructionAccount>;
+
+
+// Original args.
+const args = { ...input,  };
+
+
+// Resolve default values.
+if (!accounts.tokenProgram.value) {
+accounts.tokenProgram.value = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+}
+
+const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+return Object.freeze({ accounts: [getAccountMeta("employee", accounts.employee), getAccountMeta("stream", accounts.stream), getAccountMeta("mint", accounts.mint), getAccountMeta("config", accounts.config), getAccountMeta("vault", accounts.vault), getAccountMeta("employeeTokenAccount", accounts.employeeTokenAccount), getAccountMeta("garnishmentOrder", accounts.garnishmentOrder), getAccountMeta("feeVault", accounts.feeVault), getAccountMeta("tokenProgram", accounts.tokenProgram)], data: getWithdrawInstructionDataEncoder().encode(args as WithdrawInstructionDataArgs), programAddress } as WithdrawInstruction<TProgramAddress, TAccountEmployee, TAccountStream, TAccountMint, TAccountConfig, TAccountVault, TAccountEmployeeTokenAccount, TAccountGarnishmentOrder, TAccountFeeVault, TAccountTokenProgram>);
 }
 
 export type ParsedWithdrawInstruction<TProgram extends string = typeof CASCADE_PROGRAM_ADDRESS, TAccountMetas extends readonly AccountMeta[] = readonly AccountMeta[]> = { programAddress: Address<TProgram>;
@@ -71,15 +127,24 @@ accounts: {
 employee: TAccountMetas[0];
 stream: TAccountMetas[1];
 mint: TAccountMetas[2];
-vault: TAccountMetas[3];
-employeeTokenAccount: TAccountMetas[4];
-tokenProgram: TAccountMetas[5];
+config: TAccountMetas[3];
+vault: TAccountMetas[4];
+/**
+ * The stream's payout destination if one is configured, otherwise any
+ * token account owned by the employee.
+ */
+employeeTokenAccount: TAccountMetas[5];
+/** Required when the stream has an active garnishment order. */
+garnishmentOrder?: TAccountMetas[6] | undefined;
+/** The mint's fee vault. Required whenever a non-zero protocol fee applies. */
+feeVault?: TAccountMetas[7] | undefined;
+tokenProgram: TAccountMetas[8];
 };
 data: WithdrawInstructionData; };
 
 export function parseWithdrawInstruction<TProgram extends string, TAccountMetas extends readonly AccountMeta[]>(instruction: Instruction<TProgram> & InstructionWithAccounts<TAccountMetas> & InstructionWithData<ReadonlyUint8Array>): ParsedWithdrawInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
-  throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS, { actualAccountMetas: instruction.accounts.length, expectedAccountMetas: 6 });
+  if (instruction.accounts.length < 9) {
+  throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS, { actualAccountMetas: instruction.accounts.length, expectedAccountMetas: 9 });
 }
 let accountIndex = 0;
 const getNextAccount = () => {
@@ -87,5 +152,9 @@ const getNextAccount = () => {
   accountIndex += 1;
   return accountMeta;
 }
-  return { programAddress: instruction.programAddress, accounts: { employee: getNextAccount(), stream: getNextAccount(), mint: getNextAccount(), vault: getNextAccount(), employeeTokenAccount: getNextAccount(), tokenProgram: getNextAccount() }, data: getWithdrawInstructionDataDecoder().decode(instruction.data) };
+const getNextOptionalAccount = () => {
+  const accountMeta = getNextAccount();
+  return accountMeta.address === CASCADE_PROGRAM_ADDRESS ? undefined : accountMeta;
+};
+  return { programAddress: instruction.programAddress, accounts: { employee: getNextAccount(), stream: getNextAccount(), mint: getNextAccount(), config: getNextAccount(), vault: getNextAccount(), employeeTokenAccount: getNextAccount(), garnishmentOrder: getNextOptionalAccount(), feeVault: getNextOptionalAccount(), tokenProgram: getNextAccount() }, data: getWithdrawInstructionDataDecoder().decode(instruction.data) };
 }
\ No newline at end of file
@@ -11,39 +11,173 @@ import { type ClientWithRpc, type ClientWithTransactionPlanning, type ClientWith
 import { addSelfFetchFunctions, addSelfPlanAndSendFunctions, type SelfFetchFunctions, type SelfPlanAndSendFunctions } from '@solana/program-client-core';
 import { type GetAccountInfoApi, type GetMultipleAccountsApi } from '@solana/rpc-api';
 import { assertIsInstructionWithAccounts, containsBytes, fixEncoderSize, getBytesEncoder, type Address, type Instruction, type InstructionWithData, type ReadonlyUint8Array } from 'gill';
-import { getPaymentStreamCodec, type PaymentStream, type PaymentStreamArgs } from '../accounts';
-import { getCloseStreamInstructionAsync, getCreateStreamInstructionAsync, getEmployerEmergencyWithdrawInstruction, getRefreshActivityInstruction, getTopUpStreamInstruction, getWithdrawInstruction, parseCloseStreamInstruction, parseCreateStreamInstruction, parseEmployerEmergencyWithdrawInstruction, parseRefreshActivityInstruction, parseTopUpStreamInstruction, parseWithdrawInstruction, type CloseStreamAsyncInput, type CreateStreamAsyncInput, type EmployerEmergencyWithdrawInput, type ParsedCloseStreamInstruction, type ParsedCreateStreamInstruction, type ParsedEmployerEmergencyWithdrawInstruction, type ParsedRefreshActivityInstruction, type ParsedTopUpStreamInstruction, type ParsedWithdrawInstruction, type RefreshActivityInput, type TopUpStreamInput, type WithdrawInput } from '../instructions';
+import { getExpenseClaimCodec, getGarnishmentOrderCodec, getMilestoneCodec, getMultisigCodec, getMultisigProposalCodec, getOrganizationCodec, getOrganizationMemberCodec, getPaymentStreamCodec, getProgramConfigCodec, getShiftRecordCodec, getTimesheetPeriodCodec, type ExpenseClaim, type ExpenseClaimArgs, type GarnishmentOrder, type GarnishmentOrderArgs, type Milestone, type MilestoneArgs, type Multisig, type MultisigArgs, type MultisigProposal, type MultisigProposalArgs, type Organization, type OrganizationArgs, type OrganizationMember, type OrganizationMemberArgs, type PaymentStream, type PaymentStreamArgs, type ProgramConfig, type ProgramConfigArgs, type ShiftRecord, type ShiftRecordArgs, type TimesheetPeriod, type TimesheetPeriodArgs } from '../accounts';
+import { getAcceptConfigAdminInstructionAsync, getAcceptEmployerTransferInstruction, getAcceptStreamInstruction, getAddAllowedMintInstructionAsync, getAddOrganizationMemberInstructionAsync, getAdvanceInstructionAsync, getAnnounceEmergencyWithdrawInstruction, getApproveExpenseClaimInstructionAsync, getApproveMilestoneInstruction, getApproveMultisigProposalInstruction, getAwardBonusInstructionAsync, getCancelEmployeeRecoveryInstruction, getCancelMilestoneInstruction, getCancelMultisigProposalInstruction, getClockInInstruction, getClockOutInstruction, getCloseShiftRecordInstruction, getCloseStreamInstructionAsync, getCollectProtocolFeesInstructionAsync, getCompleteEmployeeRecoveryInstruction, getCreateMultisigInstructionAsync, getCreateOrganizationInstructionAsync, getCreateStreamInstructionAsync, getExecuteEmergencyWithdrawInstruction, getExecuteMultisigProposalInstruction, getExecuteOrganizationInstructionInstructionAsync, getFundMilestoneInstructionAsync, getInitializeConfigInstructionAsync, getInitializeFeeVaultInstructionAsync, getInitiateEmployeeRecoveryInstructionAsync, getIssueGarnishmentOrderInstruction, getProposeConfigAdminInstructionAsync, getProposeEmployerTransferInstruction, getProposeMultisigInstructionInstruction, getRefreshActivityInstruction, getRegisterSessionKeyInstruction, getRejectExpenseClaimInstruction, getRejectMilestoneInstruction, getReleaseGarnishmentOrderInstruction, getRemoveAllowedMintInstructionAsync, getRemoveOrganizationMemberInstructionAsync, getRevokeSessionKeyInstruction, getRotateEmployeeInstruction, getSetAccrualModeInstruction, getSetAdvanceLimitInstruction, getSetDeductionsInstruction, getSetFeeScheduleInstructionAsync, getSetGarnishmentIssuerInstruction, getSetMaxShiftLengthInstruction, getSetMultisigSignersInstruction, getSetPausedOperationsInstructionAsync, getSetPayMultipliersInstruction, getSetPayoutDestinationInstruction, getSetPayoutSplitsInstruction, getSetStreamKindInstruction, getSetTimesheetApproverInstruction, getSetWeeklyScheduleInstruction, getSetWithholdingRuleInstruction, getSubmitExpenseClaimInstruction, getSubmitMilestoneInstruction, getSubmitTimesheetInstructionAsync, getTopUpStreamInstructionAsync, getUpdateConfigGuardianInstructionAsync, getUpdateInactivityThresholdInstruction, getUpdateOrganizationMemberInstructionAsync, getVoidShiftInstruction, getWithdrawEmployerTreasuryInstruction, getWithdrawInstructionAsync, parseAcceptConfigAdminInstruction, parseAcceptEmployerTransferInstruction, parseAcceptStreamInstruction, parseAddAllowedMintInstruction, parseAddOrganizationMemberInstruction, parseAdvanceInstruction, parseAnnounceEmergencyWithdrawInstruction, parseApproveExpenseClaimInstruction, parseApproveMilestoneInstruction, parseApproveMultisigProposalInstruction, parseAwardBonusInstruction, parseCancelEmployeeRecoveryInstruction, parseCancelMilestoneInstruction, parseCancelMultisigProposalInstruction, parseClockInInstruction, parseClockOutInstruction, parseCloseShiftRecordInstruction, parseCloseStreamInstruction, parseCollectProtocolFeesInstruction, parseCompleteEmployeeRecoveryInstruction, parseCreateMultisigInstruction, parseCreateOrganizationInstruction, parseCreateStreamInstruction, parseExecuteEmergencyWithdrawInstruction, parseExecuteMultisigProposalInstruction, parseExecuteOrganizationInstructionInstruction, parseFundMilestoneInstruction, parseInitializeConfigInstruction, parseInitializeFeeVaultInstruction, parseInitiateEmployeeRecoveryInstruction, parseIssueGarnishmentOrderInstruction, parseProposeConfigAdminInstruction, parseProposeEmployerTransferInstruction, parseProposeMultisigInstructionInstruction, parseRefreshActivityInstruction, parseRegisterSessionKeyInstruction, parseRejectExpenseClaimInstruction, parseRejectMilestoneInstruction, parseReleaseGarnishmentOrderInstruction, parseRemoveAllowedMintInstruction, parseRemoveOrganizationMemberInstruction, parseRevokeSessionKeyInstruction, parseRotateEmployeeInstruction, parseSetAccrualModeInstruction, parseSetAdvanceLimitInstruction, parseSetDeductionsInstruction, parseSetFeeScheduleInstruction, parseSetGarnishmentIssuerInstruction, parseSetMaxShiftLengthInstruction, parseSetMultisigSignersInstruction, parseSetPausedOperationsInstruction, parseSetPayMultipliersInstruction, parseSetPayoutDestinationInstruction, parseSetPayoutSplitsInstruction, parseSetStreamKindInstruction, parseSetTimesheetApproverInstruction, parseSetWeeklyScheduleInstruction, parseSetWithholdingRuleInstruction, parseSubmitExpenseClaimInstruction, parseSubmitMilestoneInstruction, parseSubmitTimesheetInstruction, parseTopUpStreamInstruction, parseUpdateConfigGuardianInstruction, parseUpdateInactivityThresholdInstruction, parseUpdateOrganizationMemberInstruction, parseVoidShiftInstruction, parseWithdrawEmployerTreasuryInstruction, parseWithdrawInstruction, type AcceptConfigAdminAsyncInput, type AcceptEmployerTransferInput, type AcceptStreamInput, type AddAllowedMintAsyncInput, type AddOrganizationMemberAsyncInput, type AdvanceAsyncInput, type AnnounceEmergencyWithdrawInput, type ApproveExpenseClaimAsyncInput, type ApproveMilestoneInput, type ApproveMultisigProposalInput, type AwardBonusAsyncInput, type CancelEmployeeRecoveryInput, type CancelMilestoneInput, type CancelMultisigProposalInput, type ClockInInput, type ClockOutInput, type CloseShiftRecordInput, type CloseStreamAsyncInput, type CollectProtocolFeesAsyncInput, type CompleteEmployeeRecoveryInput, type CreateMultisigAsyncInput, type CreateOrganizationAsyncInput, type CreateStreamAsyncInput, type ExecuteEmergencyWithdrawInput, type ExecuteMultisigProposalInput, type ExecuteOrganizationInstructionAsyncInput, type FundMilestoneAsyncInput, type InitializeConfigAsyncInput, type InitializeFeeVaultAsyncInput, type InitiateEmployeeRecoveryAsyncInput, type IssueGarnishmentOrderInput, type ParsedAcceptConfigAdminInstruction, type ParsedAcceptEmployerTransferInstruction, type ParsedAcceptStreamInstruction, type ParsedAddAllowedMintInstruction, type ParsedAddOrganizationMemberInstruction, type ParsedAdvanceInstruction, type ParsedAnnounceEmergencyWithdrawInstruction, type ParsedApproveExpenseClaimInstruction, type ParsedApproveMilestoneInstruction, type ParsedApproveMultisigProposalInstruction, type ParsedAwardBonusInstruction, type ParsedCancelEmployeeRecoveryInstruction, type ParsedCancelMilestoneInstruction, type ParsedCancelMultisigProposalInstruction, type ParsedClockInInstruction, type ParsedClockOutInstruction, type ParsedCloseShiftRecordInstruction, type ParsedCloseStreamInstruction, type ParsedCollectProtocolFeesInstruction, type ParsedCompleteEmployeeRecoveryInstruction, type ParsedCreateMultisigInstruction, type ParsedCreateOrganizationInstruction, type ParsedCreateStreamInstruction, type ParsedExecuteEmergencyWithdrawInstruction, type ParsedExecuteMultisigProposalInstruction, type ParsedExecuteOrganizationInstructionInstruction, type ParsedFundMilestoneInstruction, type ParsedInitializeConfigInstruction, type ParsedInitializeFeeVaultInstruction, type ParsedInitiateEmployeeRecoveryInstruction, type ParsedIssueGarnishmentOrderInstruction, type ParsedProposeConfigAdminInstruction, type ParsedProposeEmployerTransferInstruction, type ParsedProposeMultisigInstructionInstruction, type ParsedRefreshActivityInstruction, type ParsedRegisterSessionKeyInstruction, type ParsedRejectExpenseClaimInstruction, type ParsedRejectMilestoneInstruction, type ParsedReleaseGarnishmentOrderInstruction, type ParsedRemoveAllowedMintInstruction, type ParsedRemoveOrganizationMemberInstruction, type ParsedRevokeSessionKeyInstruction, type ParsedRotateEmployeeInstruction, type ParsedSetAccrualModeInstruction, type ParsedSetAdvanceLimitInstruction, type ParsedSetDeductionsInstruction, type ParsedSetFeeScheduleInstruction, type ParsedSetGarnishmentIssuerInstruction, type ParsedSetMaxShiftLengthInstruction, type ParsedSetMultisigSignersInstruction, type ParsedSetPausedOperationsInstruction, type ParsedSetPayMultipliersInstruction, type ParsedSetPayoutDestinationInstruction, type ParsedSetPayoutSplitsInstruction, type ParsedSetStreamKindInstruction, type ParsedSetTimesheetApproverInstruction, type ParsedSetWeeklyScheduleInstruction, type ParsedSetWithholdingRuleInstruction, type ParsedSubmitExpenseClaimInstruction, type ParsedSubmitMilestoneInstruction, type ParsedSubmitTimesheetInstruction, type ParsedTopUpStreamInstruction, type ParsedUpdateConfigGuardianInstruction, type ParsedUpdateInactivityThresholdInstruction, type ParsedUpdateOrganizationMemberInstruction, type ParsedVoidShiftInstruction, type ParsedWithdrawEmployerTreasuryInstruction, type ParsedWithdrawInstruction, type ProposeConfigAdminAsyncInput, type ProposeEmployerTransferInput, type ProposeMultisigInstructionInput, type RefreshActivityInput, type RegisterSessionKeyInput, type RejectExpenseClaimInput, type RejectMilestoneInput, type ReleaseGarnishmentOrderInput, type RemoveAllowedMintAsyncInput, type RemoveOrganizationMemberAsyncInput, type RevokeSessionKeyInput, type RotateEmployeeInput, type SetAccrualModeInput, type SetAdvanceLimitInput, type SetDeductionsInput, type SetFeeScheduleAsyncInput, type SetGarnishmentIssuerInput, type SetMaxShiftLengthInput, type SetMultisigSignersInput, type SetPausedOperationsAsyncInput, type SetPayMultipliersInput, type SetPayoutDestinationInput, type SetPayoutSplitsInput, type SetStreamKindInput, type SetTimesheetApproverInput, type SetWeeklyScheduleInput, type SetWithholdingRuleInput, type SubmitExpenseClaimInput, type SubmitMilestoneInput, type SubmitTimesheetAsyncInput, type TopUpStreamAsyncInput, type UpdateConfigGuardianAsyncInput, type UpdateInactivityThresholdInput, type UpdateOrganizationMemberAsyncInput, type VoidShiftInput, type WithdrawAsyncInput, type WithdrawEmployerTreasuryInput } from '../instructions';
 
 export const CASCADE_PROGRAM_ADDRESS = 'FiE8MasF8sQEsruhk5FGxwR25DvQDS4nfji3h2bvVRoi' as Address<'FiE8MasF8sQEsruhk5FGxwR25DvQDS4nfji3h2bvVRoi'>;
 
-export enum CascadeAccount { PaymentStream }
+export enum CascadeAccount { ExpenseClaim, GarnishmentOrder, Milestone, Multisig, MultisigProposal, Organization, OrganizationMember, PaymentStream, ProgramConfig, ShiftRecord, TimesheetPeriod }
 
 export function identifyCascadeAccount(account: { data: ReadonlyUint8Array } | ReadonlyUint8Array): CascadeAccount {
     const data = 'data' in account ? account.data : account;
-    if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([124, 85, 193, 22, 93, 1, 143, 75])), 0)) { return CascadeAccount.PaymentStream; }
+    if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([221, 182, 206, 180, 106, 112, 81, 154])), 0)) { return CascadeAccount.ExpenseClaim; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([231, 135, 251, 172, 251, 88, 157, 28])), 0)) { return CascadeAccount.GarnishmentOrder; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([38, 210, 239, 177, 85, 184, 10, 44])), 0)) { return CascadeAccount.Milestone; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([224, 116, 121, 186, 68, 161, 79, 236])), 0)) { return CascadeAccount.Multisig; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([13, 15, 144, 55, 252, 164, 83, 208])), 0)) { return CascadeAccount.MultisigProposal; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([145, 38, 152, 251, 91, 57, 118, 160])), 0)) { return CascadeAccount.Organization; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([118, 166, 202, 83, 75, 10, 209, 243])), 0)) { return CascadeAccount.OrganizationMember; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([124, 85, 193, 22, 93, 1, 143, 75])), 0)) { return CascadeAccount.PaymentStream; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([196, 210, 90, 231, 144, 149, 140, 63])), 0)) { return CascadeAccount.ProgramConfig; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([188, 108, 87, 221, 168, 36, 144, 145])), 0)) { return CascadeAccount.ShiftRecord; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([98, 48, 119, 133, 112, 124, 180, 184])), 0)) { return CascadeAccount.TimesheetPeriod; }
     throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__FAILED_TO_IDENTIFY_ACCOUNT, { accountData: data, programName: "cascade" });
 }
 
-export enum CascadeInstruction { CloseStream, CreateStream, EmployerEmergencyWithdraw, RefreshActivity, TopUpStream, Withdraw }
+export enum CascadeInstruction { AcceptConfigAdmin, AcceptEmployerTransfer, AcceptStream, AddAllowedMint, AddOrganizationMember, Advance, AnnounceEmergencyWithdraw, ApproveExpenseClaim, ApproveMilestone, ApproveMultisigProposal, AwardBonus, CancelEmployeeRecovery, CancelMilestone, CancelMultisigProposal, ClockIn, ClockOut, CloseShiftRecord, CloseStream, CollectProtocolFees, CompleteEmployeeRecovery, CreateMultisig, CreateOrganization, CreateStream, ExecuteEmergencyWithdraw, ExecuteMultisigProposal, ExecuteOrganizationInstruction, FundMilestone, InitializeConfig, InitializeFeeVault, InitiateEmployeeRecovery, IssueGarnishmentOrder, ProposeConfigAdmin, ProposeEmployerTransfer, ProposeMultisigInstruction, RefreshActivity, RegisterSessionKey, RejectExpenseClaim, RejectMilestone, ReleaseGarnishmentOrder, RemoveAllowedMint, RemoveOrganizationMember, RevokeSessionKey, RotateEmployee, SetAccrualMode, SetAdvanceLimit, SetDeductions, SetFeeSchedule, SetGarnishmentIssuer, SetMaxShiftLength, SetMultisigSigners, SetPausedOperations, SetPayMultipliers, SetPayoutDestination, SetPayoutSplits, SetStreamKind, SetTimesheetApprover, SetWeeklySchedule, SetWithholdingRule, SubmitExpenseClaim, SubmitMilestone, SubmitTimesheet, TopUpStream, UpdateConfigGuardian, UpdateInactivityThreshold, UpdateOrganizationMember, VoidShift, Withdraw, WithdrawEmployerTreasury }
 
 export function identifyCascadeInstruction(instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array): CascadeInstruction {
     const data = 'data' in instruction ? instruction.data : instruction;
-    if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([255, 241, 196, 212, 95, 93, 160, 89])), 0)) { return CascadeInstruction.CloseStream; }
+    if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([166, 251, 87, 11, 190, 105, 44, 30])), 0)) { return CascadeInstruction.AcceptConfigAdmin; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([161, 66, 165, 241, 179, 17, 108, 99])), 0)) { return CascadeInstruction.AcceptEmployerTransfer; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([103, 150, 130, 111, 186, 78, 166, 62])), 0)) { return CascadeInstruction.AcceptStream; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([114, 83, 166, 247, 86, 17, 220, 147])), 0)) { return CascadeInstruction.AddAllowedMint; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([83, 161, 233, 161, 125, 223, 191, 151])), 0)) { return CascadeInstruction.AddOrganizationMember; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([7, 56, 108, 201, 36, 20, 57, 89])), 0)) { return CascadeInstruction.Advance; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([50, 118, 150, 242, 39, 83, 214, 186])), 0)) { return CascadeInstruction.AnnounceEmergencyWithdraw; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([235, 90, 110, 210, 8, 14, 17, 23])), 0)) { return CascadeInstruction.ApproveExpenseClaim; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([145, 85, 92, 60, 50, 130, 219, 106])), 0)) { return CascadeInstruction.ApproveMilestone; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([184, 98, 42, 168, 76, 170, 136, 6])), 0)) { return CascadeInstruction.ApproveMultisigProposal; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([45, 31, 191, 65, 35, 13, 167, 161])), 0)) { return CascadeInstruction.AwardBonus; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([1, 243, 76, 207, 9, 181, 239, 113])), 0)) { return CascadeInstruction.CancelEmployeeRecovery; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([106, 24, 203, 155, 226, 62, 27, 15])), 0)) { return CascadeInstruction.CancelMilestone; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([255, 169, 54, 66, 241, 230, 176, 33])), 0)) { return CascadeInstruction.CancelMultisigProposal; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([206, 150, 221, 145, 122, 124, 77, 89])), 0)) { return CascadeInstruction.ClockIn; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([45, 156, 240, 66, 181, 57, 211, 196])), 0)) { return CascadeInstruction.ClockOut; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([8, 6, 100, 5, 45, 188, 217, 44])), 0)) { return CascadeInstruction.CloseShiftRecord; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([255, 241, 196, 212, 95, 93, 160, 89])), 0)) { return CascadeInstruction.CloseStream; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([22, 67, 23, 98, 150, 178, 70, 220])), 0)) { return CascadeInstruction.CollectProtocolFees; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([18, 225, 186, 80, 206, 196, 44, 2])), 0)) { return CascadeInstruction.CompleteEmployeeRecovery; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([148, 146, 240, 10, 226, 215, 167, 174])), 0)) { return CascadeInstruction.CreateMultisig; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([60, 173, 177, 39, 122, 23, 68, 185])), 0)) { return CascadeInstruction.CreateOrganization; }
 if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([71, 188, 111, 127, 108, 40, 229, 158])), 0)) { return CascadeInstruction.CreateStream; }
-if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([53, 243, 175, 174, 220, 22, 246, 211])), 0)) { return CascadeInstruction.EmployerEmergencyWithdraw; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([229, 189, 185, 75, 199, 173, 142, 132])), 0)) { return CascadeInstruction.ExecuteEmergencyWithdraw; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([171, 4, 72, 183, 80, 109, 173, 27])), 0)) { return CascadeInstruction.ExecuteMultisigProposal; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([80, 197, 155, 71, 217, 176, 219, 113])), 0)) { return CascadeInstruction.ExecuteOrganizationInstruction; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([104, 130, 72, 76, 84, 58, 37, 181])), 0)) { return CascadeInstruction.FundMilestone; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([208, 127, 21, 1, 194, 190, 196, 70])), 0)) { return CascadeInstruction.InitializeConfig; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([185, 140, 228, 234, 79, 203, 252, 50])), 0)) { return CascadeInstruction.InitializeFeeVault; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([117, 140, 27, 11, 178, 223, 108, 166])), 0)) { return CascadeInstruction.InitiateEmployeeRecovery; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([245, 240, 62, 215, 221, 194, 70, 128])), 0)) { return CascadeInstruction.IssueGarnishmentOrder; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([149, 46, 216, 17, 58, 232, 159, 5])), 0)) { return CascadeInstruction.ProposeConfigAdmin; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([86, 139, 191, 166, 191, 105, 255, 235])), 0)) { return CascadeInstruction.ProposeEmployerTransfer; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([41, 40, 247, 117, 225, 104, 25, 251])), 0)) { return CascadeInstruction.ProposeMultisigInstruction; }
 if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([55, 172, 115, 3, 200, 89, 189, 250])), 0)) { return CascadeInstruction.RefreshActivity; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([69, 94, 60, 44, 49, 199, 183, 233])), 0)) { return CascadeInstruction.RegisterSessionKey; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([118, 186, 49, 13, 176, 234, 28, 30])), 0)) { return CascadeInstruction.RejectExpenseClaim; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([243, 48, 66, 165, 237, 41, 116, 249])), 0)) { return CascadeInstruction.RejectMilestone; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([244, 202, 173, 250, 109, 136, 136, 108])), 0)) { return CascadeInstruction.ReleaseGarnishmentOrder; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([53, 133, 46, 51, 25, 228, 27, 73])), 0)) { return CascadeInstruction.RemoveAllowedMint; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([166, 165, 117, 138, 177, 184, 150, 159])), 0)) { return CascadeInstruction.RemoveOrganizationMember; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([81, 192, 32, 110, 104, 116, 144, 151])), 0)) { return CascadeInstruction.RevokeSessionKey; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([85, 76, 16, 231, 59, 131, 186, 89])), 0)) { return CascadeInstruction.RotateEmployee; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([166, 236, 247, 72, 1, 94, 176, 23])), 0)) { return CascadeInstruction.SetAccrualMode; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([176, 12, 212, 155, 131, 95, 158, 78])), 0)) { return CascadeInstruction.SetAdvanceLimit; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([225, 7, 249, 57, 229, 230, 16, 156])), 0)) { return CascadeInstruction.SetDeductions; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([239, 37, 205, 178, 164, 47, 23, 13])), 0)) { return CascadeInstruction.SetFeeSchedule; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([186, 42, 236, 32, 250, 163, 51, 245])), 0)) { return CascadeInstruction.SetGarnishmentIssuer; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([243, 148, 163, 97, 189, 211, 124, 88])), 0)) { return CascadeInstruction.SetMaxShiftLength; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([53, 59, 97, 183, 115, 206, 235, 141])), 0)) { return CascadeInstruction.SetMultisigSigners; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([50, 155, 176, 211, 171, 52, 49, 0])), 0)) { return CascadeInstruction.SetPausedOperations; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([156, 199, 160, 112, 230, 149, 57, 153])), 0)) { return CascadeInstruction.SetPayMultipliers; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([30, 216, 56, 11, 130, 82, 4, 155])), 0)) { return CascadeInstruction.SetPayoutDestination; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([234, 97, 151, 125, 164, 32, 118, 158])), 0)) { return CascadeInstruction.SetPayoutSplits; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([60, 228, 225, 179, 56, 233, 114, 53])), 0)) { return CascadeInstruction.SetStreamKind; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([81, 220, 188, 8, 137, 87, 214, 245])), 0)) { return CascadeInstruction.SetTimesheetApprover; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([131, 200, 81, 222, 222, 250, 201, 145])), 0)) { return CascadeInstruction.SetWeeklySchedule; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([253, 192, 134, 70, 156, 253, 191, 160])), 0)) { return CascadeInstruction.SetWithholdingRule; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([208, 125, 254, 30, 235, 178, 104, 48])), 0)) { return CascadeInstruction.SubmitExpenseClaim; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([35, 96, 220, 215, 102, 83, 139, 52])), 0)) { return CascadeInstruction.SubmitMilestone; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([211, 100, 153, 57, 64, 181, 205, 57])), 0)) { return CascadeInstruction.SubmitTimesheet; }
 if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([12, 244, 26, 215, 160, 204, 9, 151])), 0)) { return CascadeInstruction.TopUpStream; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([66, 235, 179, 226, 103, 50, 202, 5])), 0)) { return CascadeInstruction.UpdateConfigGuardian; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([206, 255, 80, 86, 105, 43, 101, 21])), 0)) { return CascadeInstruction.UpdateInactivityThreshold; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([19, 35, 171, 122, 0, 149, 13, 111])), 0)) { return CascadeInstruction.UpdateOrganizationMember; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([68, 185, 18, 95, 74, 254, 253, 0])), 0)) { return CascadeInstruction.VoidShift; }
 if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([183, 18, 70, 156, 148, 109, 161, 34])), 0)) { return CascadeInstruction.Withdraw; }
+if (containsBytes(data, fixEncoderSize(getBytesEncoder(), 8).encode(new Uint8Array([220, 97, 66, 136, 111, 202, 18, 72])), 0)) { return CascadeInstruction.WithdrawEmployerTreasury; }
     throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__FAILED_TO_IDENTIFY_INSTRUCTION, { instructionData: data, programName: "cascade" });
 }
 
 export type ParsedCascadeInstruction<TProgram extends string = 'FiE8MasF8sQEsruhk5FGxwR25DvQDS4nfji3h2bvVRoi'> =
+| { instructionType: CascadeInstruction.AcceptConfigAdmin } & ParsedAcceptConfigAdminInstruction<TProgram>
+| { instructionType: CascadeInstruction.AcceptEmployerTransfer } & ParsedAcceptEmployerTransferInstruction<TProgram>
+| { instructionType: CascadeInstruction.AcceptStream } & ParsedAcceptStreamInstruction<TProgram>
+| { instructionType: CascadeInstruction.AddAllowedMint } & ParsedAddAllowedMintInstruction<TProgram>
+| { instructionType: CascadeInstruction.AddOrganizationMember } & ParsedAddOrganizationMemberInstruction<TProgram>
+| { instructionType: CascadeInstruction.Advance } & ParsedAdvanceInstruction<TProgram>
+| { instructionType: CascadeInstruction.AnnounceEmergencyWithdraw } & ParsedAnnounceEmergencyWithdrawInstruction<TProgram>
+| { instructionType: CascadeInstruction.ApproveExpenseClaim } & ParsedApproveExpenseClaimInstruction<TProgram>
+| { instructionType: CascadeInstruction.ApproveMilestone } & ParsedApproveMilestoneInstruction<TProgram>
+| { instructionType: CascadeInstruction.ApproveMultisigProposal } & ParsedApproveMultisigProposalInstruction<TProgram>
+| { instructionType: CascadeInstruction.AwardBonus } & ParsedAwardBonusInstruction<TProgram>
+| { instructionType: CascadeInstruction.CancelEmployeeRecovery } & ParsedCancelEmployeeRecoveryInstruction<TProgram>
+| { instructionType: CascadeInstruction.CancelMilestone } & ParsedCancelMilestoneInstruction<TProgram>
+| { instructionType: CascadeInstruction.CancelMultisigProposal } & ParsedCancelMultisigProposalInstruction<TProgram>
+| { instructionType: CascadeInstruction.ClockIn } & ParsedClockInInstruction<TProgram>
+| { instructionType: CascadeInstruction.ClockOut } & ParsedClockOutInstruction<TProgram>
+| { instructionType: CascadeInstruction.CloseShiftRecord } & ParsedCloseShiftRecordInstruction<TProgram>
 | { instructionType: CascadeInstruction.CloseStream } & ParsedCloseStreamInstruction<TProgram>
+| { instructionType: CascadeInstruction.CollectProtocolFees } & ParsedCollectProtocolFeesInstruction<TProgram>
+| { instructionType: CascadeInstruction.CompleteEmployeeRecovery } & ParsedCompleteEmployeeRecoveryInstruction<TProgram>
+| { instructionType: CascadeInstruction.CreateMultisig } & ParsedCreateMultisigInstruction<TProgram>
+| { instructionType: CascadeInstruction.CreateOrganization } & ParsedCreateOrganizationInstruction<TProgram>
 | { instructionType: CascadeInstruction.CreateStream } & ParsedCreateStreamInstruction<TProgram>
-| { instructionType: CascadeInstruction.EmployerEmergencyWithdraw } & ParsedEmployerEmergencyWithdrawInstruction<TProgram>
+| { instructionType: CascadeInstruction.ExecuteEmergencyWithdraw } & ParsedExecuteEmergencyWithdrawInstruction<TProgram>
+| { instructionType: CascadeInstruction.ExecuteMultisigProposal } & ParsedExecuteMultisigProposalInstruction<TProgram>
+| { instructionType: CascadeInstruction.ExecuteOrganizationInstruction } & ParsedExecuteOrganizationInstructionInstruction<TProgram>
+| { instructionType: CascadeInstruction.FundMilestone } & ParsedFundMilestoneInstruction<TProgram>
+| { instructionType: CascadeInstruction.InitializeConfig } & ParsedInitializeConfigInstruction<TProgram>
+| { instructionType: CascadeInstruction.InitializeFeeVault } & ParsedInitializeFeeVaultInstruction<TProgram>
+| { instructionType: CascadeInstruction.InitiateEmployeeRecovery } & ParsedInitiateEmployeeRecoveryInstruction<TProgram>
+| { instructionType: CascadeInstruction.IssueGarnishmentOrder } & ParsedIssueGarnishmentOrderInstruction<TProgram>
+| { instructionType: CascadeInstruction.ProposeConfigAdmin } & ParsedProposeConfigAdminInstruction<TProgram>
+| { instructionType: CascadeInstruction.ProposeEmployerTransfer } & ParsedProposeEmployerTransferInstruction<TProgram>
+| { instructionType: CascadeInstruction.ProposeMultisigInstruction } & ParsedProposeMultisigInstructionInstruction<TProgram>
 | { instructionType: CascadeInstruction.RefreshActivity } & ParsedRefreshActivityInstruction<TProgram>
+| { instructionType: CascadeInstruction.RegisterSessionKey } & ParsedRegisterSessionKeyInstruction<TProgram>
+| { instructionType: CascadeInstruction.RejectExpenseClaim } & ParsedRejectExpenseClaimInstruction<TProgram>
+| { instructionType: CascadeInstruction.RejectMilestone } & ParsedRejectMilestoneInstruction<TProgram>
+| { instructionType: CascadeInstruction.ReleaseGarnishmentOrder } & ParsedReleaseGarnishmentOrderInstruction<TProgram>
+| { instructionType: CascadeInstruction.RemoveAllowedMint } & ParsedRemoveAllowedMintInstruction<TProgram>
+| { instructionType: CascadeInstruction.RemoveOrganizationMember } & ParsedRemoveOrganizationMemberInstruction<TProgram>
+| { instructionType: CascadeInstruction.RevokeSessionKey } & ParsedRevokeSessionKeyInstruction<TProgram>
+| { instructionType: CascadeInstruction.RotateEmployee } & ParsedRotateEmployeeInstruction<TProgram>
+| { instructionType: CascadeInstruction.SetAccrualMode } & ParsedSetAccrualModeInstruction<TProgram>
+| { instructionType: CascadeInstruction.SetAdvanceLimit } & ParsedSetAdvanceLimitInstruction<TProgram>
+| { instructionType: CascadeInstruction.SetDeductions } & ParsedSetDeductionsInstruction<TProgram>
+| { instructionType: CascadeInstruction.SetFeeSchedule } & ParsedSetFeeScheduleInstruction<TProgram>
+| { instructionType: CascadeInstruction.SetGarnishmentIssuer } & ParsedSetGarnishmentIssuerInstruction<TProgram>
+| { instructionType: CascadeInstruction.SetMaxShiftLength } & ParsedSetMaxShiftLengthInstruction<TProgram>
+| { instructionType: CascadeInstruction.SetMultisigSigners } & ParsedSetMultisigSignersInstruction<TProgram>
+| { instructionType: CascadeInstruction.SetPausedOperations } & ParsedSetPausedOperationsInstruction<TProgram>
+| { instructionType: CascadeInstruction.SetPayMultipliers } & ParsedSetPayMultipliersInstruction<TProgram>
+| { instructionType: CascadeInstruction.SetPayoutDestination } & ParsedSetPayoutDestinationInstruction<TProgram>
+| { instructionType: CascadeInstruction.SetPayoutSplits } & ParsedSetPayoutSplitsInstruction<TProgram>
+| { instructionType: CascadeInstruction.SetStreamKind } & ParsedSetStreamKindInstruction<TProgram>
+| { instructionType: CascadeInstruction.SetTimesheetApprover } & ParsedSetTimesheetApproverInstruction<TProgram>
+| { instructionType: CascadeInstruction.SetWeeklySchedule } & ParsedSetWeeklyScheduleInstruction<TProgram>
+| { instructionType: CascadeInstruction.SetWithholdingRule } & ParsedSetWithholdingRuleInstruction<TProgram>
+| { instructionType: CascadeInstruction.SubmitExpenseClaim } & ParsedSubmitExpenseClaimInstruction<TProgram>
+| { instructionType: CascadeInstruction.SubmitMilestone } & ParsedSubmitMilestoneInstruction<TProgram>
+| { instructionType: CascadeInstruction.SubmitTimesheet } & ParsedSubmitTimesheetInstruction<TProgram>
 | { instructionType: CascadeInstruction.TopUpStream } & ParsedTopUpStreamInstruction<TProgram>
+| { instructionType: CascadeInstruction.UpdateConfigGuardian } & ParsedUpdateConfigGuardianInstruction<TProgram>
+| { instructionType: CascadeInstruction.UpdateInactivityThreshold } & ParsedUpdateInactivityThresholdInstruction<TProgram>
+| { instructionType: CascadeInstruction.UpdateOrganizationMember } & ParsedUpdateOrganizationMemberInstruction<TProgram>
+| { instructionType: CascadeInstruction.VoidShift } & ParsedVoidShiftInstruction<TProgram>
 | { instructionType: CascadeInstruction.Withdraw } & ParsedWithdrawInstruction<TProgram>
+| { instructionType: CascadeInstruction.WithdrawEmployerTreasury } & ParsedWithdrawEmployerTreasuryInstruction<TProgram>
 
 
         export function parseCascadeInstruction<TProgram extends string>(
@@ -52,32 +186,156 @@ export type ParsedCascadeInstruction<TProgram extends string = 'FiE8MasF8sQEsruh
         ): ParsedCascadeInstruction<TProgram> {
             const instructionType = identifyCascadeInstruction(instruction);
             switch (instructionType) {
-                case CascadeInstruction.CloseStream: { assertIsInstructionWithAccounts(instruction);
+                case CascadeInstruction.AcceptConfigAdmin: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.AcceptConfigAdmin, ...parseAcceptConfigAdminInstruction(instruction) }; }
+case CascadeInstruction.AcceptEmployerTransfer: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.AcceptEmployerTransfer, ...parseAcceptEmployerTransferInstruction(instruction) }; }
+case CascadeInstruction.AcceptStream: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.AcceptStream, ...parseAcceptStreamInstruction(instruction) }; }
+case CascadeInstruction.AddAllowedMint: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.AddAllowedMint, ...parseAddAllowedMintInstruction(instruction) }; }
+case CascadeInstruction.AddOrganizationMember: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.AddOrganizationMember, ...parseAddOrganizationMemberInstruction(instruction) }; }
+case CascadeInstruction.Advance: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.Advance, ...parseAdvanceInstruction(instruction) }; }
+case CascadeInstruction.AnnounceEmergencyWithdraw: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.AnnounceEmergencyWithdraw, ...parseAnnounceEmergencyWithdrawInstruction(instruction) }; }
+case CascadeInstruction.ApproveExpenseClaim: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.ApproveExpenseClaim, ...parseApproveExpenseClaimInstruction(instruction) }; }
+case CascadeInstruction.ApproveMilestone: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.ApproveMilestone, ...parseApproveMilestoneInstruction(instruction) }; }
+case CascadeInstruction.ApproveMultisigProposal: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.ApproveMultisigProposal, ...parseApproveMultisigProposalInstruction(instruction) }; }
+case CascadeInstruction.AwardBonus: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.AwardBonus, ...parseAwardBonusInstruction(instruction) }; }
+case CascadeInstruction.CancelEmployeeRecovery: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.CancelEmployeeRecovery, ...parseCancelEmployeeRecoveryInstruction(instruction) }; }
+case CascadeInstruction.CancelMilestone: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.CancelMilestone, ...parseCancelMilestoneInstruction(instruction) }; }
+case CascadeInstruction.CancelMultisigProposal: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.CancelMultisigProposal, ...parseCancelMultisigProposalInstruction(instruction) }; }
+case CascadeInstruction.ClockIn: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.ClockIn, ...parseClockInInstruction(instruction) }; }
+case CascadeInstruction.ClockOut: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.ClockOut, ...parseClockOutInstruction(instruction) }; }
+case CascadeInstruction.CloseShiftRecord: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.CloseShiftRecord, ...parseCloseShiftRecordInstruction(instruction) }; }
+case CascadeInstruction.CloseStream: { assertIsInstructionWithAccounts(instruction);
 return { instructionType: CascadeInstruction.CloseStream, ...parseCloseStreamInstruction(instruction) }; }
+case CascadeInstruction.CollectProtocolFees: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.CollectProtocolFees, ...parseCollectProtocolFeesInstruction(instruction) }; }
+case CascadeInstruction.CompleteEmployeeRecovery: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.CompleteEmployeeRecovery, ...parseCompleteEmployeeRecoveryInstruction(instruction) }; }
+case CascadeInstruction.CreateMultisig: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.CreateMultisig, ...parseCreateMultisigInstruction(instruction) }; }
+case CascadeInstruction.CreateOrganization: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.CreateOrganization, ...parseCreateOrganizationInstruction(instruction) }; }
 case CascadeInstruction.CreateStream: { assertIsInstructionWithAccounts(instruction);
 return { instructionType: CascadeInstruction.CreateStream, ...parseCreateStreamInstruction(instruction) }; }
-case CascadeInstruction.EmployerEmergencyWithdraw: { assertIsInstructionWithAccounts(instruction);
-return { instructionType: CascadeInstruction.EmployerEmergencyWithdraw, ...parseEmployerEmergencyWithdrawInstruction(instruction) }; }
+case CascadeInstruction.ExecuteEmergencyWithdraw: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.ExecuteEmergencyWithdraw, ...parseExecuteEmergencyWithdrawInstruction(instruction) }; }
+case CascadeInstruction.ExecuteMultisigProposal: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.ExecuteMultisigProposal, ...parseExecuteMultisigProposalInstruction(instruction) }; }
+case CascadeInstruction.ExecuteOrganizationInstruction: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.ExecuteOrganizationInstruction, ...parseExecuteOrganizationInstructionInstruction(instruction) }; }
+case CascadeInstruction.FundMilestone: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.FundMilestone, ...parseFundMilestoneInstruction(instruction) }; }
+case CascadeInstruction.InitializeConfig: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.InitializeConfig, ...parseInitializeConfigInstruction(instruction) }; }
+case CascadeInstruction.InitializeFeeVault: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.InitializeFeeVault, ...parseInitializeFeeVaultInstruction(instruction) }; }
+case CascadeInstruction.InitiateEmployeeRecovery: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.InitiateEmployeeRecovery, ...parseInitiateEmployeeRecoveryInstruction(instruction) }; }
+case CascadeInstruction.IssueGarnishmentOrder: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.IssueGarnishmentOrder, ...parseIssueGarnishmentOrderInstruction(instruction) }; }
+case CascadeInstruction.ProposeConfigAdmin: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.ProposeConfigAdmin, ...parseProposeConfigAdminInstruction(instruction) }; }
+case CascadeInstruction.ProposeEmployerTransfer: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.ProposeEmployerTransfer, ...parseProposeEmployerTransferInstruction(instruction) }; }
+case CascadeInstruction.ProposeMultisigInstruction: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.ProposeMultisigInstruction, ...parseProposeMultisigInstructionInstruction(instruction) }; }
 case CascadeInstruction.RefreshActivity: { assertIsInstructionWithAccounts(instruction);
 return { instructionType: CascadeInstruction.RefreshActivity, ...parseRefreshActivityInstruction(instruction) }; }
+case CascadeInstruction.RegisterSessionKey: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.RegisterSessionKey, ...parseRegisterSessionKeyInstruction(instruction) }; }
+case CascadeInstruction.RejectExpenseClaim: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.RejectExpenseClaim, ...parseRejectExpenseClaimInstruction(instruction) }; }
+case CascadeInstruction.RejectMilestone: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.RejectMilestone, ...parseRejectMilestoneInstruction(instruction) }; }
+case CascadeInstruction.ReleaseGarnishmentOrder: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.ReleaseGarnishmentOrder, ...parseReleaseGarnishmentOrderInstruction(instruction) }; }
+case CascadeInstruction.RemoveAllowedMint: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.RemoveAllowedMint, ...parseRemoveAllowedMintInstruction(instruction) }; }
+case CascadeInstruction.RemoveOrganizationMember: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.RemoveOrganizationMember, ...parseRemoveOrganizationMemberInstruction(instruction) }; }
+case CascadeInstruction.RevokeSessionKey: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.RevokeSessionKey, ...parseRevokeSessionKeyInstruction(instruction) }; }
+case CascadeInstruction.RotateEmployee: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.RotateEmployee, ...parseRotateEmployeeInstruction(instruction) }; }
+case CascadeInstruction.SetAccrualMode: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SetAccrualMode, ...parseSetAccrualModeInstruction(instruction) }; }
+case CascadeInstruction.SetAdvanceLimit: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SetAdvanceLimit, ...parseSetAdvanceLimitInstruction(instruction) }; }
+case CascadeInstruction.SetDeductions: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SetDeductions, ...parseSetDeductionsInstruction(instruction) }; }
+case CascadeInstruction.SetFeeSchedule: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SetFeeSchedule, ...parseSetFeeScheduleInstruction(instruction) }; }
+case CascadeInstruction.SetGarnishmentIssuer: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SetGarnishmentIssuer, ...parseSetGarnishmentIssuerInstruction(instruction) }; }
+case CascadeInstruction.SetMaxShiftLength: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SetMaxShiftLength, ...parseSetMaxShiftLengthInstruction(instruction) }; }
+case CascadeInstruction.SetMultisigSigners: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SetMultisigSigners, ...parseSetMultisigSignersInstruction(instruction) }; }
+case CascadeInstruction.SetPausedOperations: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SetPausedOperations, ...parseSetPausedOperationsInstruction(instruction) }; }
+case CascadeInstruction.SetPayMultipliers: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SetPayMultipliers, ...parseSetPayMultipliersInstruction(instruction) }; }
+case CascadeInstruction.SetPayoutDestination: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SetPayoutDestination, ...parseSetPayoutDestinationInstruction(instruction) }; }
+case CascadeInstruction.SetPayoutSplits: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SetPayoutSplits, ...parseSetPayoutSplitsInstruction(instruction) }; }
+case CascadeInstruction.SetStreamKind: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SetStreamKind, ...parseSetStreamKindInstruction(instruction) }; }
+case CascadeInstruction.SetTimesheetApprover: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SetTimesheetApprover, ...parseSetTimesheetApproverInstruction(instruction) }; }
+case CascadeInstruction.SetWeeklySchedule: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SetWeeklySchedule, ...parseSetWeeklyScheduleInstruction(instruction) }; }
+case CascadeInstruction.SetWithholdingRule: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SetWithholdingRule, ...parseSetWithholdingRuleInstruction(instruction) }; }
+case CascadeInstruction.SubmitExpenseClaim: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SubmitExpenseClaim, ...parseSubmitExpenseClaimInstruction(instruction) }; }
+case CascadeInstruction.SubmitMilestone: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SubmitMilestone, ...parseSubmitMilestoneInstruction(instruction) }; }
+case CascadeInstruction.SubmitTimesheet: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.SubmitTimesheet, ...parseSubmitTimesheetInstruction(instruction) }; }
 case CascadeInstruction.TopUpStream: { assertIsInstructionWithAccounts(instruction);
 return { instructionType: CascadeInstruction.TopUpStream, ...parseTopUpStreamInstruction(instruction) }; }
+case CascadeInstruction.UpdateConfigGuardian: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.UpdateConfigGuardian, ...parseUpdateConfigGuardianInstruction(instruction) }; }
+case CascadeInstruction.UpdateInactivityThreshold: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.UpdateInactivityThreshold, ...parseUpdateInactivityThresholdInstruction(instruction) }; }
+case CascadeInstruction.UpdateOrganizationMember: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.UpdateOrganizationMember, ...parseUpdateOrganizationMemberInstruction(instruction) }; }
+case CascadeInstruction.VoidShift: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.VoidShift, ...parseVoidShiftInstruction(instruction) }; }
 case CascadeInstruction.Withdraw: { assertIsInstructionWithAccounts(instruction);
 return { instructionType: CascadeInstruction.Withdraw, ...parseWithdrawInstruction(instruction) }; }
+case CascadeInstruction.WithdrawEmployerTreasury: { assertIsInstructionWithAccounts(instruction);
+return { instructionType: CascadeInstruction.WithdrawEmployerTreasury, ...parseWithdrawEmployerTreasuryInstruction(instruction) }; }
                 default: throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__UNRECOGNIZED_INSTRUCTION_TYPE, { instructionType: instructionType as string, programName: "cascade" });
             }
         }
 
 export type CascadePlugin = { accounts: CascadePluginAccounts; instructions: CascadePluginInstructions; }
 
-export type CascadePluginAccounts = { paymentStream: ReturnType<typeof getPaymentStreamCodec> & SelfFetchFunctions<PaymentStreamArgs, PaymentStream>; }
+export type CascadePluginAccounts = { expenseClaim: ReturnType<typeof getExpenseClaimCodec> & SelfFetchFunctions<ExpenseClaimArgs, ExpenseClaim>; garnishmentOrder: ReturnType<typeof getGarnishmentOrderCodec> & SelfFetchFunctions<GarnishmentOrderArgs, GarnishmentOrder>; milestone: ReturnType<typeof getMilestoneCodec> & SelfFetchFunctions<MilestoneArgs, Milestone>; multisig: ReturnType<typeof getMultisigCodec> & SelfFetchFunctions<MultisigArgs, Multisig>; multisigProposal: ReturnType<typeof getMultisigProposalCodec> & SelfFetchFunctions<MultisigProposalArgs, MultisigProposal>; organization: ReturnType<typeof getOrganizationCodec> & SelfFetchFunctions<OrganizationArgs, Organization>; organizationMember: ReturnType<typeof getOrganizationMemberCodec> & SelfFetchFunctions<OrganizationMemberArgs, OrganizationMember>; paymentStream: ReturnType<typeof getPaymentStreamCodec> & SelfFetchFunctions<PaymentStreamArgs, PaymentStream>; programConfig: ReturnType<typeof getProgramConfigCodec> & SelfFetchFunctions<ProgramConfigArgs, ProgramConfig>; shiftRecord: ReturnType<typeof getShiftRecordCodec> & SelfFetchFunctions<ShiftRecordArgs, ShiftRecord>; timesheetPeriod: ReturnType<typeof getTimesheetPeriodCodec> & SelfFetchFunctions<TimesheetPeriodArgs, TimesheetPeriod>; }
 
-export type CascadePluginInstructions = { closeStream: (input: CloseStreamAsyncInput) => ReturnType<typeof getCloseStreamInstructionAsync> & SelfPlanAndSendFunctions; createStream: (input: CreateStreamAsyncInput) => ReturnType<typeof getCreateStreamInstructionAsync> & SelfPlanAndSendFunctions; employerEmergencyWithdraw: (input: EmployerEmergencyWithdrawInput) => ReturnType<typeof getEmployerEmergencyWithdrawInstruction> & SelfPlanAndSendFunctions; refreshActivity: (input: RefreshActivityInput) => ReturnType<typeof getRefreshActivityInstruction> & SelfPlanAndSendFunctions; topUpStream: (input: TopUpStreamInput) => ReturnType<typeof getTopUpStreamInstruction> & SelfPlanAndSendFunctions; withdraw: (input: WithdrawInput) => ReturnType<typeof getWithdrawInstruction> & SelfPlanAndSendFunctions; }
+export type CascadePluginInstructions = { acceptConfigAdmin: (input: AcceptConfigAdminAsyncInput) => ReturnType<typeof getAcceptConfigAdminInstructionAsync> & SelfPlanAndSendFunctions; acceptEmployerTransfer: (input: AcceptEmployerTransferInput) => ReturnType<typeof getAcceptEmployerTransferInstruction> & SelfPlanAndSendFunctions; acceptStream: (input: AcceptStreamInput) => ReturnType<typeof getAcceptStreamInstruction> & SelfPlanAndSendFunctions; addAllowedMint: (input: AddAllowedMintAsyncInput) => ReturnType<typeof getAddAllowedMintInstructionAsync> & SelfPlanAndSendFunctions; addOrganizationMember: (input: AddOrganizationMemberAsyncInput) => ReturnType<typeof getAddOrganizationMemberInstructionAsync> & SelfPlanAndSendFunctions; advance: (input: AdvanceAsyncInput) => ReturnType<typeof getAdvanceInstructionAsync> & SelfPlanAndSendFunctions; announceEmergencyWithdraw: (input: AnnounceEmergencyWithdrawInput) => ReturnType<typeof getAnnounceEmergencyWithdrawInstruction> & SelfPlanAndSendFunctions; approveExpenseClaim: (input: ApproveExpenseClaimAsyncInput) => ReturnType<typeof getApproveExpenseClaimInstructionAsync> & SelfPlanAndSendFunctions; approveMilestone: (input: ApproveMilestoneInput) => ReturnType<typeof getApproveMilestoneInstruction> & SelfPlanAndSendFunctions; approveMultisigProposal: (input: ApproveMultisigProposalInput) => ReturnType<typeof getApproveMultisigProposalInstruction> & SelfPlanAndSendFunctions; awardBonus: (input: AwardBonusAsyncInput) => ReturnType<typeof getAwardBonusInstructionAsync> & SelfPlanAndSendFunctions; cancelEmployeeRecovery: (input: CancelEmployeeRecoveryInput) => ReturnType<typeof getCancelEmployeeRecoveryInstruction> & SelfPlanAndSendFunctions; cancelMilestone: (input: CancelMilestoneInput) => ReturnType<typeof getCancelMilestoneInstruction> & SelfPlanAndSendFunctions; cancelMultisigProposal: (input: CancelMultisigProposalInput) => ReturnType<typeof getCancelMultisigProposalInstruction> & SelfPlanAndSendFunctions; clockIn: (input: ClockInInput) => ReturnType<typeof getClockInInstruction> & SelfPlanAndSendFunctions; clockOut: (input: ClockOutInput) => ReturnType<typeof getClockOutInstruction> & SelfPlanAndSendFunctions; closeShiftRecord: (input: CloseShiftRecordInput) => ReturnType<typeof getCloseShiftRecordInstruction> & SelfPlanAndSendFunctions; closeStream: (input: CloseStreamAsyncInput) => ReturnType<typeof getCloseStreamInstructionAsync> & SelfPlanAndSendFunctions; collectProtocolFees: (input: CollectProtocolFeesAsyncInput) => ReturnType<typeof getCollectProtocolFeesInstructionAsync> & SelfPlanAndSendFunctions; completeEmployeeRecovery: (input: CompleteEmployeeRecoveryInput) => ReturnType<typeof getCompleteEmployeeRecoveryInstruction> & SelfPlanAndSendFunctions; createMultisig: (input: CreateMultisigAsyncInput) => ReturnType<typeof getCreateMultisigInstructionAsync> & SelfPlanAndSendFunctions; createOrganization: (input: CreateOrganizationAsyncInput) => ReturnType<typeof getCreateOrganizationInstructionAsync> & SelfPlanAndSendFunctions; createStream: (input: CreateStreamAsyncInput) => ReturnType<typeof getCreateStreamInstructionAsync> & SelfPlanAndSendFunctions; executeEmergencyWithdraw: (input: ExecuteEmergencyWithdrawInput) => ReturnType<typeof getExecuteEmergencyWithdrawInstruction> & SelfPlanAndSendFunctions; executeMultisigProposal: (input: ExecuteMultisigProposalInput) => ReturnType<typeof getExecuteMultisigProposalInstruction> & SelfPlanAndSendFunctions; executeOrganizationInstruction: (input: ExecuteOrganizationInstructionAsyncInput) => ReturnType<typeof getExecuteOrganizationInstructionInstructionAsync> & SelfPlanAndSendFunctions; fundMilestone: (input: FundMilestoneAsyncInput) => ReturnType<typeof getFundMilestoneInstructionAsync> & SelfPlanAndSendFunctions; initializeConfig: (input: InitializeConfigAsyncInput) => ReturnType<typeof getInitializeConfigInstructionAsync> & SelfPlanAndSendFunctions; initializeFeeVault: (input: InitializeFeeVaultAsyncInput) => ReturnType<typeof getInitializeFeeVaultInstructionAsync> & SelfPlanAndSendFunctions; initiateEmployeeRecovery: (input: InitiateEmployeeRecoveryAsyncInput) => ReturnType<typeof getInitiateEmployeeRecoveryInstructionAsync> & SelfPlanAndSendFunctions; issueGarnishmentOrder: (input: IssueGarnishmentOrderInput) => ReturnType<typeof getIssueGarnishmentOrderInstruction> & SelfPlanAndSendFunctions; proposeConfigAdmin: (input: ProposeConfigAdminAsyncInput) => ReturnType<typeof getProposeConfigAdminInstructionAsync> & SelfPlanAndSendFunctions; proposeEmployerTransfer: (input: ProposeEmployerTransferInput) => ReturnType<typeof getProposeEmployerTransferInstruction> & SelfPlanAndSendFunctions; proposeMultisigInstruction: (input: ProposeMultisigInstructionInput) => ReturnType<typeof getProposeMultisigInstructionInstruction> & SelfPlanAndSendFunctions; refreshActivity: (input: RefreshActivityInput) => ReturnType<typeof getRefreshActivityInstruction> & SelfPlanAndSendFunctions; registerSessionKey: (input: RegisterSessionKeyInput) => ReturnType<typeof getRegisterSessionKeyInstruction> & SelfPlanAndSendFunctions; rejectExpenseClaim: (input: RejectExpenseClaimInput) => ReturnType<typeof getRejectExpenseClaimInstruction> & SelfPlanAndSendFunctions; rejectMilestone: (input: RejectMilestoneInput) => ReturnType<typeof getRejectMilestoneInstruction> & SelfPlanAndSendFunctions; releaseGarnishmentOrder: (input: ReleaseGarnishmentOrderInput) => ReturnType<typeof getReleaseGarnishmentOrderInstruction> & SelfPlanAndSendFunctions; removeAllowedMint: (input: RemoveAllowedMintAsyncInput) => ReturnType<typeof getRemoveAllowedMintInstructionAsync> & SelfPlanAndSendFunctions; removeOrganizationMember: (input: RemoveOrganizationMemberAsyncInput) => ReturnType<typeof getRemoveOrganizationMemberInstructionAsync> & SelfPlanAndSendFunctions; revokeSessionKey: (input: RevokeSessionKeyInput) => ReturnType<typeof getRevokeSessionKeyInstruction> & SelfPlanAndSendFunctions; rotateEmployee: (input: RotateEmployeeInput) => ReturnType<typeof getRotateEmployeeInstruction> & SelfPlanAndSendFunctions; setAccrualMode: (input: SetAccrualModeInput) => ReturnType<typeof getSetAccrualModeInstruction> & SelfPlanAndSendFunctions; setAdvanceLimit: (input: SetAdvanceLimitInput) => ReturnType<typeof getSetAdvanceLimitInstruction> & SelfPlanAndSendFunctions; setDeductions: (input: SetDeductionsInput) => ReturnType<typeof getSetDeductionsInstruction> & SelfPlanAndSendFunctions; setFeeSchedule: (input: SetFeeScheduleAsyncInput) => ReturnType<typeof getSetFeeScheduleInstructionAsync> & SelfPlanAndSendFunctions; setGarnishmentIssuer: (input: SetGarnishmentIssuerInput) => ReturnType<typeof getSetGarnishmentIssuerInstruction> & SelfPlanAndSendFunctions; setMaxShiftLength: (input: SetMaxShiftLengthInput) => ReturnType<typeof getSetMaxShiftLengthInstruction> & SelfPlanAndSendFunctions; setMultisigSigners: (input: SetMultisigSignersInput) => ReturnType<typeof getSetMultisigSignersInstruction> & SelfPlanAndSendFunctions; setPausedOperations: (input: SetPausedOperationsAsyncInput) => ReturnType<typeof getSetPausedOperationsInstructionAsync> & SelfPlanAndSendFunctions; setPayMultipliers: (input: SetPayMultipliersInput) => ReturnType<typeof getSetPayMultipliersInstruction> & SelfPlanAndSendFunctions; setPayoutDestination: (input: SetPayoutDestinationInput) => ReturnType<typeof getSetPayoutDestinationInstruction> & SelfPlanAndSendFunctions; setPayoutSplits: (input: SetPayoutSplitsInput) => ReturnType<typeof getSetPayoutSplitsInstruction> & SelfPlanAndSendFunctions; setStreamKind: (input: SetStreamKindInput) => ReturnType<typeof getSetStreamKindInstruction> & SelfPlanAndSendFunctions; setTimesheetApprover: (input: SetTimesheetApproverInput) => ReturnType<typeof getSetTimesheetApproverInstruction> & SelfPlanAndSendFunctions; setWeeklySchedule: (input: SetWeeklyScheduleInput) => ReturnType<typeof getSetWeeklyScheduleInstruction> & SelfPlanAndSendFunctions; setWithholdingRule: (input: SetWithholdingRuleInput) => ReturnType<typeof getSetWithholdingRuleInstruction> & SelfPlanAndSendFunctions; submitExpenseClaim: (input: SubmitExpenseClaimInput) => ReturnType<typeof getSubmitExpenseClaimInstruction> & SelfPlanAndSendFunctions; submitMilestone: (input: SubmitMilestoneInput) => ReturnType<typeof getSubmitMilestoneInstruction> & SelfPlanAndSendFunctions; submitTimesheet: (input: SubmitTimesheetAsyncInput) => ReturnType<typeof getSubmitTimesheetInstructionAsync> & SelfPlanAndSendFunctions; topUpStream: (input: TopUpStreamAsyncInput) => ReturnType<typeof getTopUpStreamInstructionAsync> & SelfPlanAndSendFunctions; updateConfigGuardian: (input: UpdateConfigGuardianAsyncInput) => ReturnType<typeof getUpdateConfigGuardianInstructionAsync> & SelfPlanAndSendFunctions; updateInactivityThreshold: (input: UpdateInactivityThresholdInput) => ReturnType<typeof getUpdateInactivityThresholdInstruction> & SelfPlanAndSendFunctions; updateOrganizationMember: (input: UpdateOrganizationMemberAsyncInput) => ReturnType<typeof getUpdateOrganizationMemberInstructionAsync> & SelfPlanAndSendFunctions; voidShift: (input: VoidShiftInput) => ReturnType<typeof getVoidShiftInstruction> & SelfPlanAndSendFunctions; withdraw: (input: WithdrawAsyncInput) => ReturnType<typeof getWithdrawInstructionAsync> & SelfPlanAndSendFunctions; withdrawEmployerTreasury: (input: WithdrawEmployerTreasuryInput) => ReturnType<typeof getWithdrawEmployerTreasuryInstruction> & SelfPlanAndSendFunctions; }
 
 export type CascadePluginRequirements = ClientWithRpc<GetAccountInfoApi & GetMultipleAccountsApi> & ClientWithTransactionPlanning & ClientWithTransactionSending
 
 export function cascadeProgram() {
     return <T extends CascadePluginRequirements>(client: T) => {
-        return { ...client, cascade: <CascadePlugin>{ accounts: { paymentStream: addSelfFetchFunctions(client, getPaymentStreamCodec()) }, instructions: { closeStream: input => addSelfPlanAndSendFunctions(client, getCloseStreamInstructionAsync(input)), createStream: input => addSelfPlanAndSendFunctions(client, getCreateStreamInstructionAsync(input)), employerEmergencyWithdraw: input => addSelfPlanAndSendFunctions(client, getEmployerEmergencyWithdrawInstruction(input)), refreshActivity: input => addSelfPlanAndSendFunctions(client, getRefreshActivityInstruction(input)), topUpStream: input => addSelfPlanAndSendFunctions(client, getTopUpStreamInstruction(input)), withdraw: input => addSelfPlanAndSendFunctions(client, getWithdrawInstruction(input)) } } };
+        return { ...client, cascade: <CascadePlugin>{ accounts: { expenseClaim: addSelfFetchFunctions(client, getExpenseClaimCodec()), garnishmentOrder: addSelfFetchFunctions(client, getGarnishmentOrderCodec()), milestone: addSelfFetchFunctions(client, getMilestoneCodec()), multisig: addSelfFetchFunctions(client, getMultisigCodec()), multisigProposal: addSelfFetchFunctions(client, getMultisigProposalCodec()), organization: addSelfFetchFunctions(client, getOrganizationCodec()), organizationMember: addSelfFetchFunctions(client, getOrganizationMemberCodec()), paymentStream: addSelfFetchFunctions(client, getPaymentStreamCodec()), programConfig: addSelfFetchFunctions(client, getProgramConfigCodec()), shiftRecord: addSelfFetchFunctions(client, getShiftRecordCodec()), timesheetPeriod: addSelfFetchFunctions(client, getTimesheetPeriodCodec()) }, instructions: { acceptConfigAdmin: input => addSelfPlanAndSendFunctions(client, getAcceptConfigAdminInstructionAsync(input)), acceptEmployerTransfer: input => addSelfPlanAndSendFunctions(client, getAcceptEmployerTransferInstruction(input)), acceptStream: input => addSelfPlanAndSendFunctions(client, getAcceptStreamInstruction(input)), addAllowedMint: input => addSelfPlanAndSendFunctions(client, getAddAllowedMintInstructionAsync(input)), addOrganizationMember: input => addSelfPlanAndSendFunctions(client, getAddOrganizationMemberInstructionAsync(input)), advance: input => addSelfPlanAndSendFunctions(client, getAdvanceInstructionAsync(input)), announceEmergencyWithdraw: input => addSelfPlanAndSendFunctions(client, getAnnounceEmergencyWithdrawInstruction(input)), approveExpenseClaim: input => addSelfPlanAndSendFunctions(client, getApproveExpenseClaimInstructionAsync(input)), approveMilestone: input => addSelfPlanAndSendFunctions(client, getApproveMilestoneInstruction(input)), approveMultisigProposal: input => addSelfPlanAndSendFunctions(client, getApproveMultisigProposalInstruction(input)), awardBonus: input => addSelfPlanAndSendFunctions(client, getAwardBonusInstructionAsync(input)), cancelEmployeeRecovery: input => addSelfPlanAndSendFunctions(client, getCancelEmployeeRecoveryInstruction(input)), cancelMilestone: input => addSelfPlanAndSendFunctions(client, getCancelMilestoneInstruction(input)), cancelMultisigProposal: input => addSelfPlanAndSendFunctions(client, getCancelMultisigProposalInstruction(input)), clockIn: input => addSelfPlanAndSendFunctions(client, getClockInInstruction(input)), clockOut: input => addSelfPlanAndSendFunctions(client, getClockOutInstruction(input)), closeShiftRecord: input => addSelfPlanAndSendFunctions(client, getCloseShiftRecordInstruction(input)), closeStream: input => addSelfPlanAndSendFunctions(client, getCloseStreamInstructionAsync(input)), collectProtocolFees: input => addSelfPlanAndSendFunctions(client, getCollectProtocolFeesInstructionAsync(input)), completeEmployeeRecovery: input => addSelfPlanAndSendFunctions(client, getCompleteEmployeeRecoveryInstruction(input)), createMultisig: input => addSelfPlanAndSendFunctions(client, getCreateMultisigInstructionAsync(input)), createOrganization: input => addSelfPlanAndSendFunctions(client, getCreateOrganizationInstructionAsync(input)), createStream: input => addSelfPlanAndSendFunctions(client, getCreateStreamInstructionAsync(input)), executeEmergencyWithdraw: input => addSelfPlanAndSendFunctions(client, getExecuteEmergencyWithdrawInstruction(input)), executeMultisigProposal: input => addSelfPlanAndSendFunctions(client, getExecuteMultisigProposalInstruction(input)), executeOrganizationInstruction: input => addSelfPlanAndSendFunctions(client, getExecuteOrganizationInstructionInstructionAsync(input)), fundMilestone: input => addSelfPlanAndSendFunctions(client, getFundMilestoneInstructionAsync(input)), initializeConfig: input => addSelfPlanAndSendFunctions(client, getInitializeConfigInstructionAsync(input)), initializeFeeVault: input => addSelfPlanAndSendFunctions(client, getInitializeFeeVaultInstructionAsync(input)), initiateEmployeeRecovery: input => addSelfPlanAndSendFunctions(client, getInitiateEmployeeRecoveryInstructionAsync(input)), issueGarnishmentOrder: input => addSelfPlanAndSendFunctions(client, getIssueGarnishmentOrderInstruction(input)), proposeConfigAdmin: input => addSelfPlanAndSendFunctions(client, getProposeConfigAdminInstructionAsync(input)), proposeEmployerTransfer: input => addSelfPlanAndSendFunctions(client, getProposeEmployerTransferInstruction(input)), proposeMultisigInstruction: input => addSelfPlanAndSendFunctions(client, getProposeMultisigInstructionInstruction(input)), refreshActivity: input => addSelfPlanAndSendFunctions(client, getRefreshActivityInstruction(input)), registerSessionKey: input => addSelfPlanAndSendFunctions(client, getRegisterSessionKeyInstruction(input)), rejectExpenseClaim: input => addSelfPlanAndSendFunctions(client, getRejectExpenseClaimInstruction(input)), rejectMilestone: input => addSelfPlanAndSendFunctions(client, getRejectMilestoneInstruction(input)), releaseGarnishmentOrder: input => addSelfPlanAndSendFunctions(client, getReleaseGarnishmentOrderInstruction(input)), removeAllowedMint: input => addSelfPlanAndSendFunctions(client, getRemoveAllowedMintInstructionAsync(input)), removeOrganizationMember: input => addSelfPlanAndSendFunctions(client, getRemoveOrganizationMemberInstructionAsync(input)), revokeSessionKey: input => addSelfPlanAndSendFunctions(client, getRevokeSessionKeyInstruction(input)), rotateEmployee: input => addSelfPlanAndSendFunctions(client, getRotateEmployeeInstruction(input)), setAccrualMode: input => addSelfPlanAndSendFunctions(client, getSetAccrualModeInstruction(input)), setAdvanceLimit: input => addSelfPlanAndSendFunctions(client, getSetAdvanceLimitInstruction(input)), setDeductions: input => addSelfPlanAndSendFunctions(client, getSetDeductionsInstruction(input)), setFeeSchedule: input => addSelfPlanAndSendFunctions(client, getSetFeeScheduleInstructionAsync(input)), setGarnishmentIssuer: input => addSelfPlanAndSendFunctions(client, getSetGarnishmentIssuerInstruction(input)), setMaxShiftLength: input => addSelfPlanAndSendFunctions(client, getSetMaxShiftLengthInstruction(input)), setMultisigSigners: input => addSelfPlanAndSendFunctions(client, getSetMultisigSignersInstruction(input)), setPausedOperations: input => addSelfPlanAndSendFunctions(client, getSetPausedOperationsInstructionAsync(input)), setPayMultipliers: input => addSelfPlanAndSendFunctions(client, getSetPayMultipliersInstruction(input)), setPayoutDestination: input => addSelfPlanAndSendFunctions(client, getSetPayoutDestinationInstruction(input)), setPayoutSplits: input => addSelfPlanAndSendFunctions(client, getSetPayoutSplitsInstruction(input)), setStreamKind: input => addSelfPlanAndSendFunctions(client, getSetStreamKindInstruction(input)), setTimesheetApprover: input => addSelfPlanAndSendFunctions(client, getSetTimesheetApproverInstruction(input)), setWeeklySchedule: input => addSelfPlanAndSendFunctions(client, getSetWeeklyScheduleInstruction(input)), setWithholdingRule: input => addSelfPlanAndSendFunctions(client, getSetWithholdingRuleInstruction(input)), submitExpenseClaim: input => addSelfPlanAndSendFunctions(client, getSubmitExpenseClaimInstruction(input)), submitMilestone: input => addSelfPlanAndSendFunctions(client, getSubmitMilestoneInstruction(input)), submitTimesheet: input => addSelfPlanAndSendFunctions(client, getSubmitTimesheetInstructionAsync(input)), topUpStream: input => addSelfPlanAndSendFunctions(client, getTopUpStreamInstructionAsync(input)), updateConfigGuardian: input => addSelfPlanAndSendFunctions(client, getUpdateConfigGuardianInstructionAsync(input)), updateInactivityThreshold: input => addSelfPlanAndSendFunctions(client, getUpdateInactivityThresholdInstruction(input)), updateOrganizationMember: input => addSelfPlanAndSendFunctions(client, getUpdateOrganizationMemberInstructionAsync(input)), voidShift: input => addSelfPlanAndSendFunctions(client, getVoidShiftInstruction(input)), withdraw: input => addSelfPlanAndSendFunctions(client, getWithdrawInstructionAsync(input)), withdrawEmployerTreasury: input => addSelfPlanAndSendFunctions(client, getWithdrawEmployerTreasuryInstruction(input)) } } };
     };
 }
\ No newline at end of file
@@ -1,14 +1,18 @@
 import {
+  address,
   airdropFactory,
   createSolanaClient,
   createTransaction,
   generateKeyPairSigner,
+  getAddressEncoder,
   getMinimumBalanceForRentExemption,
+  getProgramDerivedAddress,
   lamports,
   type Address,
   type Instruction,
   type TransactionSigner,
 } from 'gill';
+import { loadKeypairSignerFromFile } from 'gill/node';
 import { getCreateAccountInstruction } from 'gill/programs';
 import {
   fetchMaybeToken,
@@ -22,15 +26,21 @@ import {
 import { beforeAll, describe, expect, test } from 'vitest';
 
 import {
+  CASCADE_PROGRAM_ADDRESS,
+  fetchMaybeProgramConfig,
   fetchPaymentStream,
+  getAddAllowedMintInstructionAsync,
   getCloseStreamInstruction,
   getCreateStreamInstructionAsync,
-  getEmployerEmergencyWithdrawInstruction,
-  getTopUpStreamInstruction,
-  getWithdrawInstruction,
+  getAnnounceEmergencyWithdrawInstruction,
+  getExecuteEmergencyWithdrawInstruction,
+  getInitializeConfigInstructionAsync,
+  getTopUpStreamInstructionAsync,
+  getWithdrawInstructionAsync,
+  streamKind,
 } from '@project/anchor';
 
-import { derivePaymentStream, deriveVault } from '@/features/streams/client/utils/derive-cascade-pdas';
+import { derivePaymentStream, deriveVault, resolveFeeVault } from '@/features/streams/client/utils/derive-cascade-pdas';
 
 const LOCALNET_RPC_URL =
   process.env.ANCHOR_PROVIDER_URL ?? process.env.CASCADE_SOLANA_LOCALNET_RPC ?? 'http://127.0.0.1:8899';
@@ -41,6 +51,9 @@ const shouldRunLocalnetIntegration =
     (process.env.ANCHOR_PROVIDER_URL.includes('127.0.0.1') || process.env.ANCHOR_PROVIDER_URL.includes('localhost')),
   );
 
+const BPF_LOADER_UPGRADEABLE_ADDRESS = address('BPFLoaderUpgradeab1e11111111111111111111111');
+const INACTIVITY_THRESHOLD_SECONDS = 30 * 24 * 60 * 60;
+
 type LocalnetFixture = {
   client: ReturnType<typeof createSolanaClient>;
   employer: TransactionSigner;
@@ -73,6 +86,8 @@ const INVALID_TOKEN_ACCOUNT_PATTERN =
 const STREAM_STILL_ACTIVE_PATTERN = /StreamStillActive|Stream is still active and cannot be closed/i;
 const EMPLOYEE_STILL_ACTIVE_PATTERN =
   /EmployeeStillActive|Employee is still active, cannot perform emergency withdrawal/i;
+const EMERGENCY_WITHDRAW_NOT_ANNOUNCED_PATTERN =
+  /EmergencyWithdrawNotAnnounced|No emergency withdrawal has been announced/i;
 
 type FailureDetails = {
   codes: Array<number>;
@@ -199,6 +214,29 @@ async function createMint(
   return mint.address;
 }
 
+/**
+ * Adds `mint` to the program's allowlist, claiming the config first if needed.
+ * `anchor test` deploys with `ANCHOR_WALLET`, so that keypair is the upgrade
+ * authority allowed to become config admin.
+ */
+async function allowMint(client: ReturnType<typeof createSolanaClient>, mint: Address) {
+  const admin = await loadKeypairSignerFromFile(process.env.ANCHOR_WALLET);
+  const [config] = await getProgramDerivedAddress({ programAddress: CASCADE_PROGRAM_ADDRESS, seeds: ['config'] });
+  const instructions: Instruction[] = [];
+
+  const existingConfig = await fetchMaybeProgramConfig(client.rpc, config);
+  if (!existingConfig.exists) {
+    const [programData] = await getProgramDerivedAddress({
+      programAddress: BPF_LOADER_UPGRADEABLE_ADDRESS,
+      seeds: [getAddressEncoder().encode(CASCADE_PROGRAM_ADDRESS)],
+    });
+    instructions.push(await getInitializeConfigInstructionAsync({ admin, programData }));
+  }
+  instructions.push(await getAddAllowedMintInstructionAsync({ admin, mint }));
+
+  await sendInstructions(client, admin, instructions);
+}
+
 async function mintToOwner(
   client: ReturnType<typeof createSolanaClient>,
   authority: TransactionSigner,
@@ -285,6 +323,7 @@ describe.runIf(shouldRunLocalnetIntegration)('cascade localnet integration: acco
 
     const supportedMint = await createMint(client, employer, { decimals: 6 });
     const unsupportedMint = await createMint(client, employer, { decimals: 9 });
+    await allowMint(client, supportedMint);
 
     const employerAtaForSupportedMint = await mintToOwner(
       client,
@@ -312,13 +351,18 @@ describe.runIf(shouldRunLocalnetIntegration)('cascade localnet integration: acco
     const [vault] = await deriveVault(stream);
     const createStreamInstruction = await getCreateStreamInstructionAsync({
       employer,
+      payer: employer,
       employee: employee.address,
       mint: supportedMint,
       employerTokenAccount: employerAtaForSupportedMint,
       stream,
       vault,
+      feeVault: await resolveFeeVault(client.rpc, supportedMint),
       hourlyRate: 1_000_000n,
       totalDeposit: 10_000_000n,
+      inactivityThresholdSeconds: INACTIVITY_THRESHOLD_SECONDS,
+      requireAcceptance: false,
+      kind: streamKind('Hourly'),
     });
     await sendInstructions(client, employer, createStreamInstruction);
 
@@ -344,13 +388,18 @@ describe.runIf(shouldRunLocalnetIntegration)('cascade localnet integration: acco
 
     const instruction = await getCreateStreamInstructionAsync({
       employer: fixture.employer,
+      payer: fixture.employer,
       employee: employeeForUnsupportedMint.address,
       mint: fixture.unsupportedMint,
       employerTokenAccount: fixture.employerAtaForUnsupportedMint,
       stream,
       vault,
+      feeVault: await resolveFeeVault(fixture.client.rpc, fixture.unsupportedMint),
       hourlyRate: 1_000_000n,
       totalDeposit: 5_000_000n,
+      inactivityThresholdSeconds: INACTIVITY_THRESHOLD_SECONDS,
+      requireAcceptance: false,
+      kind: streamKind('Hourly'),
     });
 
     const failure = await captureFailureDetails(sendInstructions(fixture.client, fixture.employer, instruction));
@@ -358,12 +407,13 @@ describe.runIf(shouldRunLocalnetIntegration)('cascade localnet integration: acco
   });
 
   test('top_up_stream rejects token account not owned by employer', async () => {
-    const instruction = getTopUpStreamInstruction({
+    const instruction = await getTopUpStreamInstructionAsync({
       employer: fixture.employer,
       stream: fixture.stream,
       mint: fixture.supportedMint,
       vault: fixture.vault,
       employerTokenAccount: fixture.attackerAtaForSupportedMint,
+      feeVault: await resolveFeeVault(fixture.client.rpc, fixture.supportedMint),
       additionalAmount: 1n,
     });
 
@@ -372,12 +422,13 @@ describe.runIf(shouldRunLocalnetIntegration)('cascade localnet integration: acco
   });
 
   test('withdraw rejects destination token account not owned by employee', async () => {
-    const instruction = getWithdrawInstruction({
+    const instruction = await getWithdrawInstructionAsync({
       employee: fixture.employee,
       stream: fixture.stream,
       mint: fixture.supportedMint,
       vault: fixture.vault,
       employeeTokenAccount: fixture.attackerAtaForSupportedMint,
+      feeVault: await resolveFeeVault(fixture.client.rpc, fixture.supportedMint),
       amount: 0n,
     });
 
@@ -399,8 +450,18 @@ describe.runIf(shouldRunLocalnetIntegration)('cascade localnet integration: acco
     expect(failure.details).not.toMatch(STREAM_STILL_ACTIVE_PATTERN);
   });
 
-  test('employer_emergency_withdraw enforces destination ownership constraint before inactivity check', async () => {
-    const instruction = getEmployerEmergencyWithdrawInstruction({
+  test('announce_emergency_withdraw rejects a stream whose employee is still active', async () => {
+    const instruction = getAnnounceEmergencyWithdrawInstruction({
+      employer: fixture.employer,
+      stream: fixture.stream,
+    });
+
+    const failure = await captureFailureDetails(sendInstructions(fixture.client, fixture.employer, instruction));
+    assertFailureMatches(failure, EMPLOYEE_STILL_ACTIVE_PATTERN, 'employee still active failure');
+  });
+
+  test('execute_emergency_withdraw enforces destination ownership constraint before announcement check', async () => {
+    const instruction = getExecuteEmergencyWithdrawInstruction({
       employer: fixture.employer,
       stream: fixture.stream,
       mint: fixture.supportedMint,
@@ -410,7 +471,7 @@ describe.runIf(shouldRunLocalnetIntegration)('cascade localnet integration: acco
 
     const failure = await captureFailureDetails(sendInstructions(fixture.client, fixture.employer, instruction));
     assertFailureMatches(failure, INVALID_TOKEN_ACCOUNT_PATTERN, 'invalid token account failure');
-    expect(failure.details).not.toMatch(EMPLOYEE_STILL_ACTIVE_PATTERN);
+    expect(failure.details).not.toMatch(EMERGENCY_WITHDRAW_NOT_ANNOUNCED_PATTERN);
   });
 });
 
@@ -438,6 +499,7 @@ describe.runIf(shouldRunLocalnetIntegration)('cascade localnet integration: stre
     ]);
 
     const mint = await createMint(client, employer, { decimals: 6 });
+    await allowMint(client, mint);
     const employerTokenAccount = await mintToOwner(client, employer, mint, employer.address, 250_000_000n);
     const employeeTokenAccount = await getAssociatedTokenAccountAddress(mint, employee.address, TOKEN_PROGRAM_ADDRESS);
     const createEmployeeAtaInstruction = getCreateAssociatedTokenIdempotentInstruction({
@@ -453,13 +515,18 @@ describe.runIf(shouldRunLocalnetIntegration)('cascade localnet integration: stre
     const [vault] = await deriveVault(stream);
     const createStreamInstruction = await getCreateStreamInstructionAsync({
       employer,
+      payer: employer,
       employee: employee.address,
       mint,
       employerTokenAccount,
       stream,
       vault,
+      feeVault: await resolveFeeVault(client.rpc, mint),
       hourlyRate: 1_000_000n,
       totalDeposit: 20_000_000n,
+      inactivityThresholdSeconds: INACTIVITY_THRESHOLD_SECONDS,
+      requireAcceptance: false,
+      kind: streamKind('Hourly'),
     });
     await sendInstructions(client, employer, createStreamInstruction);
 
@@ -480,6 +547,7 @@ describe.runIf(shouldRunLocalnetIntegration)('cascade localnet integration: stre
     const maxTopUpAmount = 900_000;
     const minTopUpAmount = 1_000;
     const nextRandom = createDeterministicRandom(0xcafe42);
+    const feeVault = await resolveFeeVault(fixture.client.rpc, fixture.mint);
 
     await assertStreamAndVaultInvariants(fixture);
 
@@ -488,22 +556,24 @@ describe.runIf(shouldRunLocalnetIntegration)('cascade localnet integration: stre
 
       if (runTopUp) {
         const additionalAmount = BigInt(nextRandom() % maxTopUpAmount) + BigInt(minTopUpAmount);
-        const topUpInstruction = getTopUpStreamInstruction({
+        const topUpInstruction = await getTopUpStreamInstructionAsync({
           employer: fixture.employer,
           stream: fixture.stream,
           mint: fixture.mint,
           vault: fixture.vault,
           employerTokenAccount: fixture.employerTokenAccount,
+          feeVault,
           additionalAmount,
         });
         await sendInstructions(fixture.client, fixture.employer, topUpInstruction);
       } else {
-        const withdrawInstruction = getWithdrawInstruction({
+        const withdrawInstruction = await getWithdrawInstructionAsync({
           employee: fixture.employee,
           stream: fixture.stream,
           mint: fixture.mint,
           vault: fixture.vault,
           employeeTokenAccount: fixture.employeeTokenAccount,
+          feeVault,
           amount: 0n,
         });
         await sendInstructions(fixture.client, fixture.employee, withdrawInstruction);
@@ -5,11 +5,17 @@ import {
   CASCADE_PROGRAM_ADDRESS,
   getCloseStreamInstruction,
   getCreateStreamInstructionAsync,
+  streamKind,
   type CloseStreamInstruction,
   type CreateStreamInstruction,
 } from '@project/anchor';
 
-import { derivePaymentStream, deriveVault, toBaseUnits } from '@/features/streams/client/utils/derive-cascade-pdas';
+import {
+  deriveFeeVault,
+  derivePaymentStream,
+  deriveVault,
+  toBaseUnits,
+} from '@/features/streams/client/utils/derive-cascade-pdas';
 
 function getAccountAddress(accountMeta: { address?: Address } | Address): Address {
   if (typeof accountMeta === 'string') {
@@ -24,7 +30,7 @@ function getAccountAddress(accountMeta: { address?: Address } | Address): Addres
 }
 
 describe('cascade instruction builders', () => {
-  test('derives stream and vault PDAs consistently for createStream', async () => {
+  test('derives stream, vault and fee vault PDAs consistently for createStream', async () => {
     const employer = await generateKeyPairSigner();
     const employee = await generateKeyPairSigner();
     const mint = (await generateKeyPairSigner()).address;
@@ -32,21 +38,28 @@ describe('cascade instruction builders', () => {
 
     const [expectedStream] = await derivePaymentStream(employer.address, employee.address);
     const [expectedVault] = await deriveVault(expectedStream);
+    const [expectedFeeVault] = await deriveFeeVault(mint);
 
     const instruction = (await getCreateStreamInstructionAsync({
       employer,
+      payer: employer,
       employee: employee.address,
       mint,
       employerTokenAccount,
       hourlyRate: toBaseUnits(12.5, 6),
       totalDeposit: toBaseUnits(500, 6),
+      inactivityThresholdSeconds: 30 * 24 * 60 * 60,
+      requireAcceptance: false,
+      kind: streamKind('Hourly'),
     })) as CreateStreamInstruction;
 
-    const streamAccount = getAccountAddress(instruction.accounts[3]);
-    const vaultAccount = getAccountAddress(instruction.accounts[4]);
+    const streamAccount = getAccountAddress(instruction.accounts[5]);
+    const vaultAccount = getAccountAddress(instruction.accounts[6]);
+    const feeVaultAccount = getAccountAddress(instruction.accounts[8]);
 
     expect(streamAccount).toBe(expectedStream);
     expect(vaultAccount).toBe(expectedVault);
+    expect(feeVaultAccount).toBe(expectedFeeVault);
     expect(instruction.programAddress).toBe(CASCADE_PROGRAM_ADDRESS);
   });
 
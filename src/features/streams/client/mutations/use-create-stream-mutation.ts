@@ -4,7 +4,7 @@ import { useWalletUiGill } from '@wallet-ui/react-gill';
 import type { Address } from 'gill';
 import { toast } from 'sonner';
 
-import { fetchMaybePaymentStream, getCreateStreamInstructionAsync } from '@project/anchor';
+import { fetchMaybePaymentStream, getCreateStreamInstructionAsync, streamKind } from '@project/anchor';
 
 import { toastTx } from '@/components/toast-tx';
 import { triggerAlertGeneration } from '@/features/alerts/server/actions/workflows';
@@ -13,7 +13,13 @@ import { useInvalidateDashboardStreamsQuery } from '@/features/streams/client/qu
 import { createStreamRecord } from '@/features/streams/server/actions/employer-streams';
 
 import { useInvalidatePaymentStreamQuery } from '../queries/use-invalidate-payment-stream-query';
-import { derivePaymentStream, deriveVault, getErrorMessage, toBaseUnits } from '../utils/derive-cascade-pdas';
+import {
+  derivePaymentStream,
+  deriveVault,
+  getErrorMessage,
+  resolveFeeVault,
+  toBaseUnits,
+} from '../utils/derive-cascade-pdas';
 import { fetchAndValidateMintDecimals } from '../utils/mint-decimals';
 import { useWalletUiSignAndSendWithFallback } from '../utils/use-wallet-ui-sign-and-send-with-fallback';
 
@@ -24,9 +30,14 @@ export type CreateStreamInput = {
   employerTokenAccount: Address;
   hourlyRate: number | bigint;
   totalDeposit: number | bigint;
+  inactivityThresholdSeconds?: number;
+  requireAcceptance?: boolean;
   cluster?: 'devnet' | 'testnet' | 'mainnet' | 'localnet' | 'custom';
 };
 
+// Matches the fixed window streams used before the threshold became configurable.
+const DEFAULT_INACTIVITY_THRESHOLD_SECONDS = 30 * 24 * 60 * 60;
+
 export function useCreateStreamMutation({ account }: { account: UiWalletAccount }) {
   const signer = useWalletUiSigner({ account });
   const client = useWalletUiGill();
@@ -48,16 +59,22 @@ export function useCreateStreamMutation({ account }: { account: UiWalletAccount
         }
 
         const mintDecimals = await fetchAndValidateMintDecimals(client.rpc, input.mint);
+        const feeVault = await resolveFeeVault(client.rpc, input.mint);
 
         const instruction = await getCreateStreamInstructionAsync({
           employer: signer,
+          payer: signer,
           employee: input.employee,
           mint: input.mint,
           stream: streamPda,
           vault: vaultPda,
           employerTokenAccount: input.employerTokenAccount,
+          feeVault,
           hourlyRate: toBaseUnits(input.hourlyRate, mintDecimals),
           totalDeposit: toBaseUnits(input.totalDeposit, mintDecimals),
+          inactivityThresholdSeconds: input.inactivityThresholdSeconds ?? DEFAULT_INACTIVITY_THRESHOLD_SECONDS,
+          requireAcceptance: input.requireAcceptance ?? false,
+          kind: streamKind('Hourly'),
         });
 
         // Validate instruction before signing
@@ -1,10 +1,14 @@
 import { useMutation } from '@tanstack/react-query';
 import { UiWalletAccount, useWalletUiSigner } from '@wallet-ui/react';
 import { useWalletUiGill } from '@wallet-ui/react-gill';
-import type { Address } from 'gill';
+import { unwrapOption, type Address } from 'gill';
 import { toast } from 'sonner';
 
-import { fetchMaybePaymentStream, getEmployerEmergencyWithdrawInstruction } from '@project/anchor';
+import {
+  fetchMaybePaymentStream,
+  getAnnounceEmergencyWithdrawInstruction,
+  getExecuteEmergencyWithdrawInstruction,
+} from '@project/anchor';
 
 import { toastTx } from '@/components/toast-tx';
 import { createActivityLog } from '@/features/organization/server/actions/activity-log';
@@ -20,6 +24,11 @@ export type EmergencyWithdrawInput = {
   vault?: Address;
 };
 
+// Mirrors EMERGENCY_WITHDRAW_NOTICE_SECONDS in the program.
+const EMERGENCY_WITHDRAW_NOTICE_SECONDS = 7n * 24n * 60n * 60n;
+
+export type EmergencyWithdrawStep = 'announced' | 'executed';
+
 export function useEmergencyWithdrawMutation({ account }: { account: UiWalletAccount }) {
   const signer = useWalletUiSigner({ account });
   const client = useWalletUiGill();
@@ -38,21 +47,42 @@ export function useEmergencyWithdrawMutation({ account }: { account: UiWalletAcc
           throw new Error('Stream not found on-chain for the connected cluster.');
         }
 
-        const instruction = getEmployerEmergencyWithdrawInstruction({
-          employer: signer,
-          stream: streamAddress,
-          mint: streamAccount.data.mint,
-          vault: vaultAddress,
-          employerTokenAccount: input.employerTokenAccount,
-        });
-
-        if (!instruction) {
-          throw new Error('Failed to create emergency withdraw instruction');
+        // The withdrawal is two-step: announce first, then execute once the
+        // notice window has passed without the employee acting.
+        const announcedAt = unwrapOption(streamAccount.data.emergencyWithdrawAnnouncedAt);
+        let step: EmergencyWithdrawStep;
+        let instruction;
+
+        if (announcedAt === null) {
+          step = 'announced';
+          instruction = getAnnounceEmergencyWithdrawInstruction({
+            employer: signer,
+            stream: streamAddress,
+          });
+        } else {
+          const executableAt = announcedAt + EMERGENCY_WITHDRAW_NOTICE_SECONDS;
+          if (BigInt(Math.floor(Date.now() / 1000)) < executableAt) {
+            throw new Error(
+              `Emergency withdrawal was announced. It can be executed after ${new Date(
+                Number(executableAt) * 1000,
+              ).toLocaleString()} if the employee stays inactive.`,
+            );
+          }
+
+          step = 'executed';
+          instruction = getExecuteEmergencyWithdrawInstruction({
+            employer: signer,
+            stream: streamAddress,
+            mint: streamAccount.data.mint,
+            vault: vaultAddress,
+            employerTokenAccount: input.employerTokenAccount,
+          });
         }
 
         console.debug('Emergency withdraw instruction created:', {
           stream: streamAddress,
           employee: input.employee,
+          step,
         });
 
         const signature = await signAndSend(instruction, signer);
@@ -64,6 +94,7 @@ export function useEmergencyWithdrawMutation({ account }: { account: UiWalletAcc
         return {
           signature,
           streamAddress,
+          step,
           input,
         };
       } catch (signError) {
@@ -76,15 +107,18 @@ export function useEmergencyWithdrawMutation({ account }: { account: UiWalletAcc
       }
     },
     onSuccess: async (result) => {
-      const { signature, streamAddress, input } = result;
+      const { signature, streamAddress, step, input } = result;
 
-      toastTx(signature, 'Emergency withdrawal submitted');
+      toastTx(signature, step === 'announced' ? 'Emergency withdrawal announced' : 'Emergency withdrawal submitted');
 
-      // Log successful emergency withdrawal
+      // Log successful emergency withdrawal step
       try {
         await createActivityLog({
-          title: 'Emergency withdrawal executed',
-          description: `Employer withdrew funds from stream (emergency clawback)`,
+          title: step === 'announced' ? 'Emergency withdrawal announced' : 'Emergency withdrawal executed',
+          description:
+            step === 'announced'
+              ? 'Employer announced an emergency withdrawal; it can be executed after the 7-day notice window'
+              : `Employer withdrew funds from stream (emergency clawback)`,
           activityType: 'stream_emergency_withdraw',
           actorType: 'employer',
           actorAddress: signer.address,
@@ -92,6 +126,7 @@ export function useEmergencyWithdrawMutation({ account }: { account: UiWalletAcc
           metadata: {
             streamAddress,
             employee: input.employee,
+            step,
             signature,
           },
         });
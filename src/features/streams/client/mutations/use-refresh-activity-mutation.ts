@@ -54,7 +54,7 @@ export function useRefreshActivityMutation({ account }: { account: UiWalletAccou
             : (await derivePaymentStream(employerAddress, employeeAddressResolved))[0];
 
         const instruction = getRefreshActivityInstruction({
-          employee: signer,
+          authority: signer,
           stream: streamAddress,
         });
 
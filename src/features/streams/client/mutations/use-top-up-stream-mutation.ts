@@ -4,7 +4,7 @@ import { useWalletUiGill } from '@wallet-ui/react-gill';
 import type { Address } from 'gill';
 import { toast } from 'sonner';
 
-import { fetchMaybePaymentStream, getTopUpStreamInstruction } from '@project/anchor';
+import { fetchMaybePaymentStream, getTopUpStreamInstructionAsync } from '@project/anchor';
 
 import { toastTx } from '@/components/toast-tx';
 import { createActivityLog } from '@/features/organization/server/actions/activity-log';
@@ -12,7 +12,13 @@ import { useInvalidateDashboardStreamsQuery } from '@/features/streams/client/qu
 import { recordStreamTopUp } from '@/features/streams/server/actions/employer-streams';
 
 import { useInvalidatePaymentStreamQuery } from '../queries/use-invalidate-payment-stream-query';
-import { derivePaymentStream, deriveVault, getErrorMessage, toBaseUnits } from '../utils/derive-cascade-pdas';
+import {
+  derivePaymentStream,
+  deriveVault,
+  getErrorMessage,
+  resolveFeeVault,
+  toBaseUnits,
+} from '../utils/derive-cascade-pdas';
 import { fetchAndValidateMintDecimals } from '../utils/mint-decimals';
 import { useWalletUiSignAndSendWithFallback } from '../utils/use-wallet-ui-sign-and-send-with-fallback';
 
@@ -45,13 +51,15 @@ export function useTopUpStreamMutation({ account }: { account: UiWalletAccount }
         }
 
         const mintDecimals = await fetchAndValidateMintDecimals(client.rpc, streamAccount.data.mint);
+        const feeVault = await resolveFeeVault(client.rpc, streamAccount.data.mint);
 
-        const instruction = getTopUpStreamInstruction({
+        const instruction = await getTopUpStreamInstructionAsync({
           employer: signer,
           stream: streamAddress,
           mint: streamAccount.data.mint,
           vault: vaultAddress,
           employerTokenAccount: input.employerTokenAccount,
+          feeVault,
           additionalAmount: toBaseUnits(input.additionalAmount, mintDecimals),
         });
 
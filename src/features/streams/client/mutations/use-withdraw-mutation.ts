@@ -1,11 +1,11 @@
 import { useMutation, useQueryClient } from '@tanstack/react-query';
 import { UiWalletAccount, useWalletUiSigner } from '@wallet-ui/react';
 import { useWalletUiGill } from '@wallet-ui/react-gill';
-import { address as toAddress, type Address, type Signature } from 'gill';
+import { address as toAddress, unwrapOption, type Address, type Signature } from 'gill';
 import { getAssociatedTokenAccountAddress, getCreateAssociatedTokenIdempotentInstruction } from 'gill/programs/token';
 import { toast } from 'sonner';
 
-import { fetchMaybePaymentStream, getWithdrawInstruction } from '@project/anchor';
+import { fetchMaybePaymentStream, getWithdrawInstructionAsync } from '@project/anchor';
 
 import { toastTx } from '@/components/toast-tx';
 import { EMPLOYEE_DASHBOARD_OVERVIEW_QUERY_KEY } from '@/features/employees/client/queries/use-employee-dashboard-overview-query';
@@ -16,7 +16,13 @@ import { recordEmployeeWithdrawal } from '@/features/streams/server/actions/empl
 import type { EmployeeWithdrawal } from '@/features/streams/server/actions/employee-withdrawal-history';
 
 import { useInvalidatePaymentStreamQuery } from '../queries/use-invalidate-payment-stream-query';
-import { derivePaymentStream, deriveVault, getErrorMessage, toBaseUnits } from '../utils/derive-cascade-pdas';
+import {
+  derivePaymentStream,
+  deriveVault,
+  getErrorMessage,
+  resolveFeeVault,
+  toBaseUnits,
+} from '../utils/derive-cascade-pdas';
 import { fetchAndValidateMintDecimals } from '../utils/mint-decimals';
 import { useWalletUiSignAndSendWithFallback } from '../utils/use-wallet-ui-sign-and-send-with-fallback';
 
@@ -185,12 +191,16 @@ export function useWithdrawMutation({ account }: { account: UiWalletAccount }) {
             : input.employeeTokenAccount
           : await getAssociatedTokenAccountAddress(mintAddress, employeeAddressResolved);
 
-        const withdrawInstruction = getWithdrawInstruction({
+        const feeVault = await resolveFeeVault(client.rpc, mintAddress);
+
+        const withdrawInstruction = await getWithdrawInstructionAsync({
           employee: signer,
           stream: streamAddress,
           mint: mintAddress,
           vault: vaultAddress,
           employeeTokenAccount,
+          garnishmentOrder: unwrapOption(streamAccount.data.activeGarnishment) ?? undefined,
+          feeVault,
           amount: requestedAmountBaseUnits,
         });
 
@@ -1,9 +1,18 @@
-import { address, getAddressEncoder, getBytesEncoder, getProgramDerivedAddress, type Address } from 'gill';
+import {
+  address,
+  fetchEncodedAccount,
+  getAddressEncoder,
+  getBytesEncoder,
+  getProgramDerivedAddress,
+  type Address,
+  type SolanaClient,
+} from 'gill';
 
 import { CASCADE_PROGRAM_ADDRESS } from '@project/anchor';
 
 const streamSeed = getBytesEncoder().encode(new Uint8Array([115, 116, 114, 101, 97, 109])); // "stream"
 const vaultSeed = getBytesEncoder().encode(new Uint8Array([118, 97, 117, 108, 116])); // "vault"
+const feeVaultSeed = getBytesEncoder().encode(new Uint8Array([102, 101, 101, 95, 118, 97, 117, 108, 116])); // "fee_vault"
 
 type AddressLike = Address | string;
 
@@ -108,3 +117,24 @@ export async function deriveVault(stream: AddressLike) {
     seeds: [vaultSeed, getAddressEncoder().encode(streamAddress)],
   });
 }
+
+export async function deriveFeeVault(mint: AddressLike) {
+  const mintAddress = ensureAddress(mint);
+
+  return getProgramDerivedAddress({
+    programAddress: CASCADE_PROGRAM_ADDRESS,
+    seeds: [feeVaultSeed, getAddressEncoder().encode(mintAddress)],
+  });
+}
+
+/**
+ * Resolves the optional `feeVault` account for deposits and withdrawals.
+ * Mints without an initialized fee vault get the program address, which the
+ * program reads as "no fee vault".
+ */
+export async function resolveFeeVault(rpc: SolanaClient['rpc'], mint: AddressLike): Promise<Address> {
+  const [feeVault] = await deriveFeeVault(mint);
+  const feeVaultAccount = await fetchEncodedAccount(rpc, feeVault);
+
+  return feeVaultAccount.exists ? feeVault : CASCADE_PROGRAM_ADDRESS;
+}
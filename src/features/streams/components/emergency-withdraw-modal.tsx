@@ -174,7 +174,10 @@ function EmergencyWithdrawModalContent({
         vault: address(stream.vaultAddress),
       });
 
-      toastTx(result.signature, 'Emergency withdrawal processed');
+      toastTx(
+        result.signature,
+        result.step === 'announced' ? 'Emergency withdrawal announced' : 'Emergency withdrawal processed',
+      );
       resetForm();
       onClose();
     } catch (error) {
@@ -227,6 +230,8 @@ function EmergencyWithdrawModalContent({
             <div className="rounded-lg border border-amber-500/20 bg-amber-500/10 p-4">
               <p className="text-sm text-amber-700 dark:text-amber-400">
                 <strong>Note:</strong> Emergency withdrawal requires the employee to be inactive for at least 30 days.
+                The first submission announces the withdrawal; funds can be reclaimed 7 days later if the employee stays
+                inactive.
                 Current inactivity: {inactivityDays} days.
               </p>
             </div>